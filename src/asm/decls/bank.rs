@@ -7,6 +7,12 @@ pub fn collect(
     decls: &mut asm::ItemDecls)
     -> Result<(), ()>
 {
+    // Banks declared within a `#repeat` are only
+    // known after it gets expanded
+    let has_pending_repeats = ast.nodes
+        .iter()
+        .any(|n| matches!(n, asm::AstAny::DirectiveRepeat(_)));
+
     for any_node in &mut ast.nodes
     {
         let asm::AstAny::DirectiveBank(ref mut node) = any_node
@@ -16,8 +22,28 @@ pub fn collect(
         {
             continue;
         }
+
+        if let Some(ref var_name) = node.name_index_var
+        {
+            report.error_span(
+                format!(
+                    "unknown `#repeat` variable `{}` in bank name",
+                    var_name),
+                node.name_span);
+
+            return Err(());
+        }
         
 
+        if has_pending_repeats &&
+            decls.bankdefs.try_get_by_name(
+                &util::SymbolContext::new_global(),
+                0,
+                &[node.name.as_str()]).is_none()
+        {
+            continue;
+        }
+
         let item_ref = decls.bankdefs.get_by_name_global(
             report,
             node.name_span,
@@ -16,6 +16,17 @@ pub fn collect(
         {
             continue;
         }
+
+        if let Some(ref var_name) = node.name_index_var
+        {
+            report.error_span(
+                format!(
+                    "unknown `#repeat` variable `{}` in bank name",
+                    var_name),
+                node.name_span);
+
+            return Err(());
+        }
         
 
         let item_ref = decls.bankdefs.declare(
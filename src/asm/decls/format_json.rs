@@ -0,0 +1,210 @@
+use crate::*;
+
+
+impl asm::ItemDecls
+{
+    /// Serializes the declared ruledefs, bankdefs, and symbols
+    /// as JSON, for consumption by external tooling.
+    ///
+    /// Only names are available right after collecting
+    /// the declarations. If the definitions are also given,
+    /// each ruledef additionally lists its rules' patterns
+    /// and mnemonics, and each symbol its resolved value.
+    pub fn format_json(
+        &self,
+        maybe_defs: Option<&asm::ItemDefs>)
+        -> String
+    {
+        let mut result = String::new();
+        result.push_str("{\n");
+
+
+        result.push_str("  \"ruledefs\": [");
+
+        for (index, decl) in self.ruledefs.iter().enumerate()
+        {
+            result.push_str(if index == 0 { "\n" } else { ",\n" });
+            result.push_str("    {\n");
+            result.push_str(&format!(
+                "      \"name\": {}",
+                format_json_string(&decl.name)));
+
+            let maybe_ruledef = maybe_defs
+                .and_then(|defs| defs.ruledefs.maybe_get(decl.item_ref));
+
+            if let Some(ruledef) = maybe_ruledef
+            {
+                result.push_str(&format!(
+                    ",\n      \"subruledef\": {}",
+                    ruledef.is_subruledef));
+
+                result.push_str(",\n      \"rules\": [");
+
+                for (rule_index, rule) in ruledef.rules.iter().enumerate()
+                {
+                    result.push_str(if rule_index == 0 { "\n" } else { ",\n" });
+                    result.push_str(&format!(
+                        "        {{ \"mnemonic\": {}, \"pattern\": {} }}",
+                        format_json_string(&rule.get_mnemonic()),
+                        format_json_string(&format_rule_pattern(self, rule))));
+                }
+
+                result.push_str("\n      ]");
+            }
+
+            result.push_str("\n    }");
+        }
+
+        result.push_str("\n  ],\n");
+
+
+        result.push_str("  \"bankdefs\": [");
+
+        // Skip the implicit default bankdef
+        for (index, decl) in self.bankdefs.iter().skip(1).enumerate()
+        {
+            result.push_str(if index == 0 { "\n" } else { ",\n" });
+            result.push_str(&format!(
+                "    {}",
+                format_json_string(&decl.name)));
+        }
+
+        result.push_str("\n  ],\n");
+
+
+        result.push_str("  \"symbols\": [");
+
+        // Skip anonymous `+`/`-` labels, which only
+        // have generated names
+        let named_symbols = self.symbols
+            .iter()
+            .filter(|decl| !decl.is_anonymous());
+
+        for (index, decl) in named_symbols.enumerate()
+        {
+            let kind = {
+                match decl.kind
+                {
+                    util::SymbolKind::Constant => "constant",
+                    util::SymbolKind::Label => "label",
+                    util::SymbolKind::Function => "function",
+                    util::SymbolKind::Other => "other",
+                }
+            };
+
+            result.push_str(if index == 0 { "\n" } else { ",\n" });
+            result.push_str(&format!(
+                "    {{ \"name\": {}, \"kind\": \"{}\"",
+                format_json_string(&decl.name),
+                kind));
+
+            let maybe_symbol = maybe_defs
+                .and_then(|defs| defs.symbols.maybe_get(decl.item_ref));
+
+            if let Some(symbol) = maybe_symbol
+            {
+                if let expr::Value::Integer(ref bigint) = symbol.value
+                {
+                    result.push_str(&format!(
+                        ", \"value\": \"0x{:x}\"",
+                        bigint));
+                }
+            }
+
+            result.push_str(" }");
+        }
+
+        result.push_str("\n  ]\n");
+
+
+        result.push_str("}\n");
+        result
+    }
+}
+
+
+fn format_rule_pattern(
+    decls: &asm::ItemDecls,
+    rule: &asm::Rule)
+    -> String
+{
+    let mut result = String::new();
+
+    for part in &rule.pattern
+    {
+        match part
+        {
+            asm::RulePatternPart::Whitespace =>
+                result.push(' '),
+
+            asm::RulePatternPart::Exact(c) =>
+                result.push(*c),
+
+            asm::RulePatternPart::ParameterIndex(index) =>
+            {
+                let param = &rule.parameters[*index];
+
+                let typename = {
+                    match param.typ
+                    {
+                        asm::RuleParameterType::Unspecified =>
+                            None,
+                        asm::RuleParameterType::RuledefRef(ruledef_ref) =>
+                            Some(decls.ruledefs.get(ruledef_ref).name.clone()),
+                        asm::RuleParameterType::Unsigned(size) =>
+                            Some(format!("u{}", size)),
+                        asm::RuleParameterType::Signed(size) =>
+                            Some(format!("s{}", size)),
+                        asm::RuleParameterType::Integer(size) =>
+                            Some(format!("i{}", size)),
+                        asm::RuleParameterType::RegisterRange { ref prefix, start, end, .. } =>
+                            Some(format!("{}{}..{}{}", prefix, start, prefix, end)),
+                    }
+                };
+
+                match typename
+                {
+                    Some(typename) =>
+                        result.push_str(&format!("{{{}: {}}}", param.name, typename)),
+                    None =>
+                        result.push_str(&format!("{{{}}}", param.name)),
+                }
+            }
+        }
+    }
+
+    if rule.optional_tail.is_some()
+    {
+        result.push('?');
+    }
+
+    result
+}
+
+
+/// Quotes and escapes a string for inclusion in JSON
+pub fn format_json_string(
+    s: &str)
+    -> String
+{
+    let mut result = String::new();
+    result.push('"');
+
+    for c in s.chars()
+    {
+        match c
+        {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 =>
+                result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
\ No newline at end of file
@@ -6,6 +6,8 @@ mod bankdef;
 mod ruledef;
 mod symbol;
 mod function;
+mod format_json;
+pub use format_json::format_json_string;
 
 
 #[derive(Debug)]
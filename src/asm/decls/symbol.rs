@@ -9,6 +9,10 @@ pub fn collect(
 {
     let mut symbol_ctx = util::SymbolContext::new_global();
 
+    // Nodes might have been added in between since
+    // the last pass, like from `#repeat` expansions
+    decls.symbols.clear_anonymous();
+
 
     for any_node in &mut ast.nodes
     {
@@ -27,17 +31,56 @@ pub fn collect(
                 }
             };
 
-            let item_ref = decls.symbols.declare(
-                report,
-                node.decl_span,
-                &symbol_ctx,
-                node.name.clone(),
-                node.hierarchy_level,
-                kind)?;
+            if let Some(ref parent_name) = node.parent_name
+            {
+                let maybe_parent_ref = decls.symbols.try_get_by_name(
+                    &util::SymbolContext::new_global(),
+                    0,
+                    &[parent_name.as_str()]);
+
+                if let Some(parent_ref) = maybe_parent_ref
+                {
+                    symbol_ctx = decls.symbols
+                        .get(parent_ref)
+                        .ctx
+                        .clone();
+                }
+            }
+
+            let item_ref = {
+                if node.anonymous
+                {
+                    decls.symbols.declare_anonymous(
+                        report,
+                        node.decl_span,
+                        &symbol_ctx)?
+                }
+                else
+                {
+                    decls.symbols.declare(
+                        report,
+                        node.decl_span,
+                        &symbol_ctx,
+                        node.name.clone(),
+                        node.hierarchy_level,
+                        kind)?
+                }
+            };
                 
             node.item_ref = Some(item_ref);
         }
 
+        let anonymous = {
+            if node.anonymous
+                { util::AnonymousDirection::from_name(&node.name) }
+            else
+                { None }
+        };
+
+        decls.symbols.place_symbol(
+            node.item_ref.unwrap(),
+            anonymous);
+
         symbol_ctx = decls.symbols
             .get(node.item_ref.unwrap())
             .ctx
@@ -6,6 +6,9 @@ pub struct AlignDirective
 {
     pub item_ref: util::ItemRef<Self>,
     pub align_size: usize,
+
+    /// The value of each unit in the gap, if given
+    pub fill_value: Option<util::BigInt>,
 }
 
 
@@ -25,6 +28,7 @@ pub fn define(
             let res = AlignDirective {
                 item_ref,
                 align_size: 0,
+                fill_value: None,
             };
             
             defs.align_directives.define(item_ref, res);
@@ -11,6 +11,40 @@ pub struct Bankdef
     pub size: Option<usize>,
 	pub output_offset: Option<usize>,
 	pub fill: bool,
+    pub fill_pattern: BankFillPattern,
+    /// Whether partial units in a filled bank are
+    /// padded to whole units, instead of rejected
+    pub round_units: bool,
+    /// The order in which values wider than
+    /// `addr_unit` are split into units
+    pub value_endian: BankEndianness,
+    /// The order in which each unit is split
+    /// into bytes in the output
+    pub unit_endian: BankEndianness,
+}
+
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BankEndianness
+{
+    Big,
+    Little,
+}
+
+
+/// The pattern written to the unused units of a
+/// bank that has `fill` enabled.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BankFillPattern
+{
+    /// All bits cleared
+    Zero,
+    /// Alternating `0xaa` and `0x55` (for 8-bit units)
+    Aa55,
+    /// A single set bit, walking up one position per unit
+    Walking1,
+    /// Each unit holds the low bits of its own address
+    Address,
 }
 
 
@@ -31,6 +65,10 @@ pub fn define(
         size: None,
         output_offset: Some(0),
         fill: false,
+        fill_pattern: BankFillPattern::Zero,
+        round_units: false,
+        value_endian: BankEndianness::Big,
+        unit_endian: BankEndianness::Big,
     };
 
     defs.bankdefs.define(initial_item_ref, initial_bankdef);
@@ -112,14 +150,45 @@ pub fn define(
                     (Some(size), None) => Some(size),
                     (None, Some(end)) =>
                     {
-                        Some(end
+                        if end < addr_start
+                        {
+                            report.error_span(
+                                format!(
+                                    "`addr_end` is before `addr` ({} < {})",
+                                    end.as_radix_string(16, 0, true),
+                                    addr_start.as_radix_string(16, 0, true)),
+                                node.addr_end.as_ref().unwrap().span());
+
+                            return Err(());
+                        }
+
+                        let size = end
                             .checked_sub(
                                 report,
                                 node.addr_end.as_ref().unwrap().span(),
                                 &addr_start)?
                             .checked_into::<usize>(
                                 report,
-                                node.addr_end.as_ref().unwrap().span())?)
+                                node.addr_end.as_ref().unwrap().span())?;
+
+                        if node.addr_end_inclusive
+                        {
+                            let Some(size) = size.checked_add(1)
+                                else
+                                {
+                                    report.error_span(
+                                        "bank size is out of supported range",
+                                        node.header_span);
+
+                                    return Err(());
+                                };
+
+                            Some(size)
+                        }
+                        else
+                        {
+                            Some(size)
+                        }
                     }
                     (Some(_), Some(_)) =>
                     {
@@ -132,9 +201,26 @@ pub fn define(
                 }
             };
 
-            // FIXME: Multiplication can overflow
-            let size = addr_size
-                .map(|s| s * addr_unit);
+            let size = {
+                match addr_size
+                {
+                    None => None,
+                    Some(addr_size) =>
+                    {
+                        let Some(size) = addr_size.checked_mul(addr_unit)
+                            else
+                            {
+                                report.error_span(
+                                    "bank size is out of supported range",
+                                    node.header_span);
+
+                                return Err(());
+                            };
+
+                        Some(size)
+                    }
+                }
+            };
             
             let output_offset = match &node.output_offset
             {
@@ -150,6 +236,58 @@ pub fn define(
 
             let fill = node.fill;
 
+            let fill_pattern = match &node.fill_pattern
+            {
+                None => BankFillPattern::Zero,
+                Some(expr) =>
+                {
+                    let value = asm::resolver::eval_certain(
+                        report,
+                        decls,
+                        defs,
+                        expr)?;
+
+                    let name = &value
+                        .expect_string(report, expr.span())?
+                        .utf8_contents;
+
+                    match BankFillPattern::from_name(name)
+                    {
+                        Some(pattern) => pattern,
+                        None =>
+                        {
+                            report.error_span(
+                                format!("unknown fill pattern `{}`", name),
+                                expr.span());
+        
+                            return Err(());
+                        }
+                    }
+                }
+            };
+
+            let value_endian = eval_endianness(
+                report,
+                decls,
+                defs,
+                &node.value_endian)?;
+
+            let unit_endian = eval_endianness(
+                report,
+                decls,
+                defs,
+                &node.unit_endian)?;
+
+            if unit_endian == BankEndianness::Little &&
+                addr_unit % 8 != 0
+            {
+                report.error_span(
+                    "`unit_endian` requires `bits` to be a multiple of 8",
+                    node.unit_endian.as_ref().unwrap().span());
+
+                return Err(());
+            }
+
             let bankdef = Bankdef {
                 item_ref,
                 addr_unit,
@@ -158,6 +296,10 @@ pub fn define(
                 size,
                 output_offset,
                 fill,
+                fill_pattern,
+                round_units: node.round_units,
+                value_endian,
+                unit_endian,
             };
 
             defs.bankdefs.define(item_ref, bankdef);
@@ -166,4 +308,97 @@ pub fn define(
 
 
     Ok(())
+}
+
+
+fn eval_endianness(
+    report: &mut diagn::Report,
+    decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    maybe_expr: &Option<expr::Expr>)
+    -> Result<BankEndianness, ()>
+{
+    let Some(expr) = maybe_expr
+        else { return Ok(BankEndianness::Big) };
+
+    let value = asm::resolver::eval_certain(
+        report,
+        decls,
+        defs,
+        expr)?;
+
+    let name = &value
+        .expect_string(report, expr.span())?
+        .utf8_contents;
+
+    match name.as_str()
+    {
+        "big" => Ok(BankEndianness::Big),
+        "little" => Ok(BankEndianness::Little),
+        _ =>
+        {
+            report.error_span(
+                format!("unknown endianness `{}`", name),
+                expr.span());
+
+            Err(())
+        }
+    }
+}
+
+
+impl BankFillPattern
+{
+    pub fn from_name(
+        name: &str)
+        -> Option<BankFillPattern>
+    {
+        match name
+        {
+            "zero" => Some(BankFillPattern::Zero),
+            "aa55" => Some(BankFillPattern::Aa55),
+            "walking1" => Some(BankFillPattern::Walking1),
+            "address" => Some(BankFillPattern::Address),
+            _ => None,
+        }
+    }
+
+
+    /// Produces the value of the unit at index `unit_index`
+    /// from the start of the bank, sized to `addr_unit` bits.
+    pub fn get_unit(
+        &self,
+        addr_unit: usize,
+        addr_start: &util::BigInt,
+        unit_index: usize)
+        -> util::BigInt
+    {
+        let mut value = util::BigInt::new(0, Some(addr_unit));
+
+        match self
+        {
+            BankFillPattern::Zero => {}
+
+            BankFillPattern::Aa55 =>
+            {
+                for i in 0..addr_unit
+                {
+                    value.set_bit(i, (i % 2 == 1) != (unit_index % 2 == 1));
+                }
+            }
+
+            BankFillPattern::Walking1 =>
+            {
+                value.set_bit(unit_index % addr_unit, true);
+            }
+
+            BankFillPattern::Address =>
+            {
+                let addr = &util::BigInt::from(unit_index) + addr_start;
+                value = addr.slice(addr_unit, 0);
+            }
+        }
+
+        value
+    }
 }
\ No newline at end of file
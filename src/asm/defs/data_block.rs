@@ -5,17 +5,27 @@ use crate::*;
 pub struct DataElement
 {
     pub item_ref: util::ItemRef<Self>,
+    /// Where the element was last placed, as of
+    /// the latest resolution iteration
+    pub bank_ref: Option<util::ItemRef<asm::Bankdef>>,
     pub position_within_bank: Option<usize>,
+    /// The index of the element within its `#d` directive,
+    /// and how many elements the directive has
+    pub index_in_block: usize,
+    pub block_len: usize,
     pub encoding_statically_known: bool,
     pub encoding: util::BigInt,
+    /// Whether the value was negative before being
+    /// sliced into `encoding`
+    pub negative: bool,
     pub resolved: bool,
 }
 
 
 pub fn define(
-    _report: &mut diagn::Report,
+    report: &mut diagn::Report,
     ast: &mut asm::AstTopLevel,
-    _decls: &mut asm::ItemDecls,
+    decls: &mut asm::ItemDecls,
     defs: &mut asm::ItemDefs)
     -> Result<(), ()>
 {
@@ -23,7 +33,19 @@ pub fn define(
     {
         if let asm::AstAny::DirectiveData(ref mut ast_data) = any_node
         {
-            for expr in &ast_data.elems
+            if let Some(ref expr) = ast_data.elem_size_expr
+            {
+                let elem_size = asm::resolver::eval_certain(
+                        report,
+                        decls,
+                        defs,
+                        expr)?
+                    .expect_usize(report, expr.span())?;
+
+                ast_data.elem_size = Some(elem_size);
+            }
+
+            for (index, expr) in ast_data.elems.iter().enumerate()
             {
                 let item_ref = defs.data_elems.next_item_ref();
 
@@ -43,11 +65,15 @@ pub fn define(
 
                 let data_block = DataElement {
                     item_ref,
+                    bank_ref: None,
                     position_within_bank: None,
+                    index_in_block: index,
+                    block_len: ast_data.elems.len(),
                     encoding_statically_known: statically_known,
                     encoding: util::BigInt::new(
                         0,
                         Some(size.unwrap_or(0))),
+                    negative: false,
                     resolved: false,
                 };
                 
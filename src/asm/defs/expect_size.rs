@@ -0,0 +1,40 @@
+use crate::*;
+
+
+#[derive(Debug)]
+pub struct ExpectSizeDirective
+{
+    pub item_ref: util::ItemRef<Self>,
+    /// The rules disabled by `#disable` at the
+    /// directive's position, as seen by the matcher
+    pub disabled_rules: asm::matcher::DisabledRules,
+}
+
+
+pub fn define(
+    _report: &mut diagn::Report,
+    ast: &mut asm::AstTopLevel,
+    _decls: &mut asm::ItemDecls,
+    defs: &mut asm::ItemDefs)
+    -> Result<(), ()>
+{
+    for any_node in &mut ast.nodes
+    {
+        if let asm::AstAny::DirectiveExpectSize(ref mut ast_expect_size) = any_node
+        {
+            let item_ref = defs.expect_size_directives.next_item_ref();
+
+            let expect_size = ExpectSizeDirective {
+                item_ref,
+                disabled_rules: asm::matcher::DisabledRules::new(),
+            };
+            
+            defs.expect_size_directives.define(item_ref, expect_size);
+                
+            ast_expect_size.item_ref = Some(item_ref);
+        }
+    }
+
+
+    Ok(())
+}
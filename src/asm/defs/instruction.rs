@@ -6,9 +6,16 @@ pub struct Instruction
 {
     pub item_ref: util::ItemRef<Self>,
     pub matches: asm::InstructionMatches,
+    /// Index into `matches` of the match whose
+    /// encoding was selected
+    pub chosen_match: Option<usize>,
     pub encoding_statically_known: bool,
     pub encoding: util::BigInt,
     pub resolved: bool,
+    /// Where the instruction was last placed, as of
+    /// the latest resolution iteration
+    pub bank_ref: Option<util::ItemRef<asm::Bankdef>>,
+    pub position_within_bank: Option<usize>,
 }
 
 
@@ -28,9 +35,12 @@ pub fn define(
             let instr = Instruction {
                 item_ref,
                 matches: asm::InstructionMatches::new(),
+                chosen_match: None,
                 encoding_statically_known: false,
                 encoding: util::BigInt::new(0, Some(0)),
                 resolved: false,
+                bank_ref: None,
+                position_within_bank: None,
             };
             
             defs.instructions.define(item_ref, instr);
@@ -2,7 +2,11 @@ use crate::*;
 
 
 mod bankdef;
-pub use bankdef::Bankdef;
+pub use bankdef::{
+    Bankdef,
+    BankFillPattern,
+    BankEndianness,
+};
 
 mod ruledef;
 pub use ruledef::{
@@ -44,6 +48,12 @@ pub use align::AlignDirective;
 mod addr;
 pub use addr::AddrDirective;
 
+mod patch;
+pub use patch::PatchDirective;
+
+mod expect_size;
+pub use expect_size::ExpectSizeDirective;
+
 
 #[derive(Debug)]
 pub struct ItemDefs
@@ -58,6 +68,22 @@ pub struct ItemDefs
     pub res_directives: DefList<ResDirective>,
     pub align_directives: DefList<AlignDirective>,
     pub addr_directives: DefList<AddrDirective>,
+    pub patch_directives: DefList<PatchDirective>,
+    pub expect_size_directives: DefList<ExpectSizeDirective>,
+}
+
+
+impl ItemDefs
+{
+    /// Finds the smallest and largest encoding sizes, in bits,
+    /// across all top-level rules with the given mnemonic.
+    pub fn get_mnemonic_size_bounds(
+        &self,
+        mnemonic: &str)
+        -> Option<(usize, usize)>
+    {
+        asm::matcher::get_mnemonic_size_bounds(self, mnemonic)
+    }
 }
 
 
@@ -142,6 +168,8 @@ pub fn init() -> ItemDefs
         res_directives: DefList::new(),
         align_directives: DefList::new(),
         addr_directives: DefList::new(),
+        patch_directives: DefList::new(),
+        expect_size_directives: DefList::new(),
     }
 }
 
@@ -178,6 +206,8 @@ pub fn define_remaining(
     res::define(report, ast, decls, defs)?;
     align::define(report, ast, decls, defs)?;
     addr::define(report, ast, decls, defs)?;
+    patch::define(report, ast, decls, defs)?;
+    expect_size::define(report, ast, decls, defs)?;
     
     report.stop_at_errors()?;
 
@@ -0,0 +1,42 @@
+use crate::*;
+
+
+#[derive(Debug)]
+pub struct PatchDirective
+{
+    pub item_ref: util::ItemRef<Self>,
+    pub bank_ref: util::ItemRef<asm::Bankdef>,
+    pub addr: util::BigInt,
+    pub encoding: util::BigInt,
+}
+
+
+pub fn define(
+    _report: &mut diagn::Report,
+    ast: &mut asm::AstTopLevel,
+    _decls: &mut asm::ItemDecls,
+    defs: &mut asm::ItemDefs)
+    -> Result<(), ()>
+{
+    for any_node in &mut ast.nodes
+    {
+        if let asm::AstAny::DirectivePatch(ref mut ast_patch) = any_node
+        {
+            let item_ref = defs.patch_directives.next_item_ref();
+
+            let patch = PatchDirective {
+                item_ref,
+                bank_ref: util::ItemRef::new(0),
+                addr: util::BigInt::from(0),
+                encoding: util::BigInt::new(0, Some(0)),
+            };
+            
+            defs.patch_directives.define(item_ref, patch);
+                
+            ast_patch.item_ref = Some(item_ref);
+        }
+    }
+
+
+    Ok(())
+}
@@ -6,6 +6,9 @@ pub struct ResDirective
 {
     pub item_ref: util::ItemRef<Self>,
     pub reserve_size: usize,
+
+    /// The value of each unit, for `#fill`
+    pub fill_value: Option<util::BigInt>,
 }
 
 
@@ -25,6 +28,7 @@ pub fn define(
             let res = ResDirective {
                 item_ref,
                 reserve_size: 0,
+                fill_value: None,
             };
             
             defs.res_directives.define(item_ref, res);
@@ -7,18 +7,27 @@ pub struct Ruledef
     pub item_ref: util::ItemRef<Self>,
     pub is_subruledef: bool,
     pub rules: Vec<Rule>,
+
+    /// Whether every instruction matched against this
+    /// ruledef must produce at least one bit of output
+    pub nonempty: bool,
 }
 
 
 #[derive(Debug)]
 pub struct Rule
 {
+    pub name: Option<String>,
     pub pattern_span: diagn::Span,
     pub pattern: RulePattern,
 
     /// Used in instruction-matching to prioritize matches
     /// with more "exact" pattern-parts
     pub exact_part_count: usize,
+
+    /// Index into `pattern` where an optional trailing
+    /// parameter begins, which takes the value 0 when omitted
+    pub optional_tail: Option<usize>,
     
     pub parameters: Vec<RuleParameter>,
     pub expr: expr::Expr,
@@ -45,7 +54,7 @@ pub struct RuleParameter
 }
 
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub enum RuleParameterType
 {
     Unspecified,
@@ -53,6 +62,16 @@ pub enum RuleParameterType
     Unsigned(usize),
     Signed(usize),
     Integer(usize),
+
+    /// Matches register names from `{prefix}{start}` to
+    /// `{prefix}{end}`, inclusive, binding the register number
+    /// as an unsigned integer of `size` bits
+    RegisterRange {
+        prefix: String,
+        start: usize,
+        end: usize,
+        size: usize,
+    },
 }
 
 
@@ -85,6 +104,7 @@ pub fn define(
                 item_ref,
                 is_subruledef: node.is_subruledef,
                 rules,
+                nonempty: node.nonempty,
             };
 
             defs.ruledefs.define(item_ref, ruledef);
@@ -137,9 +157,11 @@ pub fn resolve_rule(
     }
 
     Ok(Rule {
+        name: ast_rule.name.clone(),
         pattern_span: ast_rule.pattern_span,
         pattern,
         exact_part_count: exact_parts,
+        optional_tail: ast_rule.optional_tail,
         parameters,
         expr: ast_rule.expr.clone(),
     })
@@ -167,6 +189,20 @@ pub fn resolve_rule_parameter(
                 
             asm::AstRuleParameterType::Signed(s) =>
                 RuleParameterType::Signed(*s),
+
+            asm::AstRuleParameterType::RegisterRange { prefix, start, end } =>
+            {
+                let size = std::cmp::max(
+                    1,
+                    (usize::BITS - end.leading_zeros()) as usize);
+
+                RuleParameterType::RegisterRange {
+                    prefix: prefix.clone(),
+                    start: *start,
+                    end: *end,
+                    size,
+                }
+            }
             
             asm::AstRuleParameterType::Ruledef(ruledef_name) =>
             {
@@ -226,4 +262,26 @@ impl Ruledef
             .into_iter()
             .map(|i| util::ItemRef::<Rule>::new(i))
     }
+}
+
+
+impl Rule
+{
+    /// The exact characters at the start of the pattern,
+    /// up to the first whitespace or parameter
+    pub fn get_mnemonic(&self) -> String
+    {
+        let mut result = String::new();
+
+        for part in &self.pattern
+        {
+            match part
+            {
+                RulePatternPart::Exact(c) => result.push(*c),
+                _ => break,
+            }
+        }
+
+        result
+    }
 }
\ No newline at end of file
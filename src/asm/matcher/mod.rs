@@ -11,6 +11,15 @@ type WorkingMatch<'src> =
 pub type InstructionMatches = Vec<InstructionMatch>;
 
 
+/// A rule excluded from matching by `#disable`
+pub type DisabledRule = (
+    util::ItemRef<asm::Ruledef>,
+    util::ItemRef<asm::Rule>);
+
+
+pub type DisabledRules = Vec<DisabledRule>;
+
+
 #[derive(Clone, Debug)]
 pub struct InstructionMatch
 {
@@ -18,6 +27,7 @@ pub struct InstructionMatch
     pub rule_ref: util::ItemRef<asm::Rule>,
     pub args: Vec<InstructionArgument>,
     pub exact_part_count: usize,
+    pub omitted_optional: bool,
     pub encoding_statically_known: bool,
     pub encoding_size: usize,
     pub encoding: InstructionMatchResolution,
@@ -140,16 +150,82 @@ pub fn match_all(
 {
     let mut symbol_ctx = &util::SymbolContext::new_global();
 
+    // Report unknown `#using` ruledefs only once,
+    // instead of for every instruction in the block
+    let mut reported_using_spans = std::collections::HashSet::new();
+
+    let mut disabled_rules = DisabledRules::new();
+
 
     for any_node in &ast.nodes
     {
-        if let asm::AstAny::Instruction(ast_instr) = any_node
+        if let asm::AstAny::DirectiveDisable(ast_disable) = any_node
+        {
+            let Ok(rule_refs) = get_disable_rule_refs(
+                report,
+                decls,
+                defs,
+                ast_disable)
+            else { continue };
+
+            if ast_disable.enable
+            {
+                disabled_rules.retain(|r|
+                    !rule_refs.iter().any(|e| is_same_rule(r, e)));
+            }
+            else
+            {
+                disabled_rules.extend(rule_refs);
+            }
+        }
+
+        else if let asm::AstAny::Instruction(ast_instr) = any_node
         {
+            let mut using_report = diagn::Report::new();
+
+            let maybe_using_ruledefs = get_using_ruledefs(
+                &mut using_report,
+                decls,
+                ast_instr);
+
+            let Ok(using_ruledefs) = maybe_using_ruledefs
+            else
+            {
+                let span = ast_instr.using_ruledefs.as_ref().unwrap()[0].span;
+
+                if reported_using_spans.insert(span)
+                {
+                    using_report.transfer_to(report);
+                }
+
+                continue;
+            };
+
             let mut matches = match_instr(
                 opts,
                 defs,
-                ast_instr.span,
-                &ast_instr.src);
+                ast_instr.alias_target_span.unwrap_or(ast_instr.span),
+                &ast_instr.src,
+                using_ruledefs.as_deref(),
+                &disabled_rules);
+
+
+            if let Some(alias_target_span) = ast_instr.alias_target_span
+            {
+                if matches.len() == 0
+                {
+                    report.push_parent(
+                        "no match found for instruction",
+                        ast_instr.span);
+
+                    report.note_span(
+                        "expanded from alias",
+                        alias_target_span);
+
+                    report.pop_parent();
+                    continue;
+                }
+            }
 
             
             if let Err(()) = error_on_no_matches(
@@ -204,6 +280,14 @@ pub fn match_all(
             }
         }
 
+        else if let asm::AstAny::DirectiveExpectSize(ast_expect_size) = any_node
+        {
+            let expect_size = defs.expect_size_directives.get_mut(
+                ast_expect_size.item_ref.unwrap());
+
+            expect_size.disabled_rules = disabled_rules.clone();
+        }
+
         else if let asm::AstAny::Symbol(node) = any_node
         {
             let item_ref = node.item_ref.unwrap();
@@ -215,6 +299,121 @@ pub fn match_all(
 }
 
 
+/// Resolves the ruledefs of the `#using` block an
+/// instruction is in, if any, to restrict its matches
+pub fn get_using_ruledefs(
+    report: &mut diagn::Report,
+    decls: &asm::ItemDecls,
+    ast_instr: &asm::AstInstruction)
+    -> Result<Option<Vec<util::ItemRef<asm::Ruledef>>>, ()>
+{
+    let Some(ref using_ruledefs) = ast_instr.using_ruledefs
+        else { return Ok(None) };
+
+    let mut ruledef_refs = Vec::new();
+
+    for using_ruledef in using_ruledefs
+    {
+        ruledef_refs.push(decls.ruledefs.get_by_name_global(
+            report,
+            using_ruledef.span,
+            &using_ruledef.name)?);
+    }
+
+    Ok(Some(ruledef_refs))
+}
+
+
+/// Finds the rules named by a `#disable` or `#enable`
+/// directive, in the given ruledef or else in every one.
+fn get_disable_rule_refs(
+    report: &mut diagn::Report,
+    decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    ast_disable: &asm::AstDirectiveDisable)
+    -> Result<DisabledRules, ()>
+{
+    let ruledef_refs = {
+        match ast_disable.ruledef_name
+        {
+            Some(ref ruledef_name) => vec![
+                decls.ruledefs.get_by_name_global(
+                    report,
+                    ast_disable.name_span,
+                    ruledef_name)?
+            ],
+            None => (0..defs.ruledefs.defs.len())
+                .map(|i| util::ItemRef::<asm::Ruledef>::new(i))
+                .collect(),
+        }
+    };
+
+    let mut rule_refs = DisabledRules::new();
+
+    for ruledef_ref in ruledef_refs
+    {
+        let ruledef = defs.ruledefs.get(ruledef_ref);
+
+        for rule_ref in ruledef.iter_rule_refs()
+        {
+            let rule = ruledef.get_rule(rule_ref);
+
+            if rule.name.as_ref() == Some(&ast_disable.rule_name)
+            {
+                rule_refs.push((ruledef_ref, rule_ref));
+            }
+        }
+    }
+
+    if rule_refs.len() == 0
+    {
+        report.error_span(
+            format!(
+                "unknown rule `{}`",
+                ast_disable.rule_name),
+            ast_disable.name_span);
+
+        return Err(());
+    }
+
+    Ok(rule_refs)
+}
+
+
+fn is_same_rule(
+    a: &DisabledRule,
+    b: &DisabledRule)
+    -> bool
+{
+    a.0.0 == b.0.0 && a.1.0 == b.1.0
+}
+
+
+/// Whether the match, or any of its nested matches,
+/// uses a rule disabled by `#disable`
+fn uses_disabled_rule(
+    mtch: &InstructionMatch,
+    disabled_rules: &[DisabledRule])
+    -> bool
+{
+    let rule = (mtch.ruledef_ref, mtch.rule_ref);
+
+    if disabled_rules.iter().any(|r| is_same_rule(r, &rule))
+    {
+        return true;
+    }
+
+    mtch.args
+        .iter()
+        .any(|arg| match arg.kind
+        {
+            InstructionArgumentKind::Nested(ref nested) =>
+                uses_disabled_rule(nested, disabled_rules),
+            InstructionArgumentKind::Expr(_) => false,
+        })
+}
+
+
 pub fn error_on_no_matches(
     report: &mut diagn::Report,
     span: diagn::Span,
@@ -276,7 +475,8 @@ fn get_match_statically_known(
             asm::RuleParameterType::Unspecified |
             asm::RuleParameterType::Integer(_) |
             asm::RuleParameterType::Unsigned(_) |
-            asm::RuleParameterType::Signed(_) =>
+            asm::RuleParameterType::Signed(_) |
+            asm::RuleParameterType::RegisterRange { .. } =>
             {
                 if let InstructionArgumentKind::Expr(ref arg_expr) = arg.kind
                 {
@@ -339,7 +539,8 @@ fn get_match_static_size(
 
             asm::RuleParameterType::Integer(size) |
             asm::RuleParameterType::Unsigned(size) |
-            asm::RuleParameterType::Signed(size) =>
+            asm::RuleParameterType::Signed(size) |
+            asm::RuleParameterType::RegisterRange { size, .. } =>
             {
                 info.locals.insert(
                     param.name.clone(),
@@ -375,13 +576,206 @@ fn get_match_static_size(
 }
 
 
+/// Finds the smallest and largest encoding sizes, in bits,
+/// that a rule can produce, if statically known.
+/// Ruledef-typed parameters take on the bounds of
+/// the referenced ruledef.
+pub fn get_rule_size_bounds(
+    defs: &asm::ItemDefs,
+    ruledef_ref: util::ItemRef<asm::Ruledef>,
+    rule_ref: util::ItemRef<asm::Rule>)
+    -> Option<(usize, usize)>
+{
+    get_rule_size_bounds_recursive(
+        defs,
+        ruledef_ref,
+        rule_ref,
+        &mut Vec::new())
+}
+
+
+/// Finds the smallest and largest encoding sizes, in bits,
+/// across all the rules of a ruledef, if statically known.
+pub fn get_ruledef_size_bounds(
+    defs: &asm::ItemDefs,
+    ruledef_ref: util::ItemRef<asm::Ruledef>)
+    -> Option<(usize, usize)>
+{
+    get_ruledef_size_bounds_recursive(
+        defs,
+        ruledef_ref,
+        &mut Vec::new())
+}
+
+
+fn get_ruledef_size_bounds_recursive(
+    defs: &asm::ItemDefs,
+    ruledef_ref: util::ItemRef<asm::Ruledef>,
+    seen_ruledefs: &mut Vec<usize>)
+    -> Option<(usize, usize)>
+{
+    // Recursive ruledefs have no static bounds
+    if seen_ruledefs.contains(&ruledef_ref.0)
+    {
+        return None;
+    }
+
+    seen_ruledefs.push(ruledef_ref.0);
+
+    let ruledef = defs.ruledefs.get(ruledef_ref);
+
+    let mut bounds: Option<(usize, usize)> = None;
+
+    for rule_ref in ruledef.iter_rule_refs()
+    {
+        let Some(rule_bounds) = get_rule_size_bounds_recursive(
+            defs,
+            ruledef_ref,
+            rule_ref,
+            seen_ruledefs)
+        else
+        {
+            seen_ruledefs.pop();
+            return None;
+        };
+
+        bounds = Some(merge_size_bounds(bounds, rule_bounds));
+    }
+
+    seen_ruledefs.pop();
+    bounds
+}
+
+
+fn get_rule_size_bounds_recursive(
+    defs: &asm::ItemDefs,
+    ruledef_ref: util::ItemRef<asm::Ruledef>,
+    rule_ref: util::ItemRef<asm::Rule>,
+    seen_ruledefs: &mut Vec<usize>)
+    -> Option<(usize, usize)>
+{
+    let ruledef = defs.ruledefs.get(ruledef_ref);
+    let rule = &ruledef.get_rule(rule_ref);
+
+    let mut info_min = expr::StaticallyKnownProvider::new();
+    let mut info_max = expr::StaticallyKnownProvider::new();
+
+    for param in &rule.parameters
+    {
+        let (min, max) = {
+            match param.typ
+            {
+                asm::RuleParameterType::Unspecified => continue,
+
+                asm::RuleParameterType::Integer(size) |
+                asm::RuleParameterType::Unsigned(size) |
+                asm::RuleParameterType::Signed(size) |
+                asm::RuleParameterType::RegisterRange { size, .. } =>
+                    (size, size),
+
+                asm::RuleParameterType::RuledefRef(nested_ruledef_ref) =>
+                {
+                    get_ruledef_size_bounds_recursive(
+                        defs,
+                        nested_ruledef_ref,
+                        seen_ruledefs)?
+                }
+            }
+        };
+
+        info_min.locals.insert(
+            param.name.clone(),
+            expr::StaticallyKnownLocal {
+                size: Some(min),
+                ..expr::StaticallyKnownLocal::new()
+            });
+
+        info_max.locals.insert(
+            param.name.clone(),
+            expr::StaticallyKnownLocal {
+                size: Some(max),
+                ..expr::StaticallyKnownLocal::new()
+            });
+    }
+
+    let size_min = rule.expr.get_static_size(&info_min)?;
+    let size_max = rule.expr.get_static_size(&info_max)?;
+
+    Some((
+        std::cmp::min(size_min, size_max),
+        std::cmp::max(size_min, size_max)))
+}
+
+
+fn merge_size_bounds(
+    bounds: Option<(usize, usize)>,
+    other: (usize, usize))
+    -> (usize, usize)
+{
+    match bounds
+    {
+        None => other,
+        Some((min, max)) => (
+            std::cmp::min(min, other.0),
+            std::cmp::max(max, other.1)),
+    }
+}
+
+
+/// Finds the smallest and largest encoding sizes, in bits,
+/// across all top-level rules with the given mnemonic.
+/// Returns `None` if there are no such rules, or if
+/// any of them has no statically known size.
+pub fn get_mnemonic_size_bounds(
+    defs: &asm::ItemDefs,
+    mnemonic: &str)
+    -> Option<(usize, usize)>
+{
+    let mnemonic = mnemonic.to_ascii_lowercase();
+
+    let mut bounds: Option<(usize, usize)> = None;
+
+    for i in 0..defs.ruledefs.defs.len()
+    {
+        let ruledef_ref = util::ItemRef::<asm::Ruledef>::new(i);
+        let ruledef = defs.ruledefs.get(ruledef_ref);
+
+        if ruledef.is_subruledef
+        {
+            continue;
+        }
+
+        for rule_ref in ruledef.iter_rule_refs()
+        {
+            let rule = ruledef.get_rule(rule_ref);
+
+            if rule.get_mnemonic().to_ascii_lowercase() != mnemonic
+            {
+                continue;
+            }
+
+            let rule_bounds = get_rule_size_bounds(
+                defs,
+                ruledef_ref,
+                rule_ref)?;
+
+            bounds = Some(merge_size_bounds(bounds, rule_bounds));
+        }
+    }
+
+    bounds
+}
+
+
 /// Runs the instruction-matching algorithm on the given
 /// string, and returns the matches.
 pub fn match_instr(
     opts: &asm::AssemblyOptions,
     defs: &asm::ItemDefs,
     span: diagn::Span,
-    src: &str)
+    src: &str,
+    using_ruledefs: Option<&[util::ItemRef<asm::Ruledef>]>,
+    disabled_rules: &[DisabledRule])
     -> InstructionMatches
 {
     let mut working_matches = WorkingMatches::new();
@@ -420,6 +814,18 @@ pub fn match_instr(
             working_matches.extend(ruledef_matches);
         }
     }
+
+    if let Some(using_ruledefs) = using_ruledefs
+    {
+        working_matches.retain(|m|
+            using_ruledefs.iter().any(|r| r.0 == m.0.ruledef_ref.0));
+    }
+
+    if disabled_rules.len() > 0
+    {
+        working_matches.retain(|m|
+            !uses_disabled_rule(&m.0, disabled_rules));
+    }
     
     if working_matches.len() == 0
     {
@@ -470,6 +876,14 @@ pub fn match_instr(
     matches.retain(|c| c.exact_part_count == max_exact_count);
 
 
+    // Prefer matches that didn't omit an
+    // optional trailing parameter
+    if matches.iter().any(|m| !m.omitted_optional)
+    {
+        matches.retain(|m| !m.omitted_optional);
+    }
+
+
     matches
 }
 
@@ -554,6 +968,7 @@ fn begin_match_with_rule<'src>(
             rule_ref,
             args: Vec::new(),
             exact_part_count: 0,
+            omitted_optional: false,
             encoding_statically_known: false,
             encoding_size: 0,
             encoding: InstructionMatchResolution::Unresolved,
@@ -572,6 +987,16 @@ fn match_with_rule<'src>(
 {
     for part_index in at_pattern_part..rule.pattern.len()
     {
+        if rule.optional_tail == Some(part_index) &&
+            walker.is_over()
+        {
+            return match_with_omitted_tail(
+                rule,
+                walker.clone(),
+                part_index,
+                match_so_far.clone());
+        }
+
         let part = &rule.pattern[part_index];
 
         match part
@@ -622,6 +1047,18 @@ fn match_with_rule<'src>(
                         return result;
                     }
 
+                    asm::RuleParameterType::RegisterRange { ref prefix, start, end, size } =>
+                    {
+                        return match_with_register_range(
+                            defs,
+                            rule,
+                            walker.clone(),
+                            needs_consume_all_tokens,
+                            part_index,
+                            (prefix, start, end, size),
+                            match_so_far.clone());
+                    }
+
                     asm::RuleParameterType::RuledefRef(ruledef_ref) =>
                     {
                         let mut result = vec![];
@@ -659,6 +1096,39 @@ fn match_with_rule<'src>(
 }
 
 
+/// Binds the default value of 0 to the parameters
+/// of an omitted optional tail.
+fn match_with_omitted_tail<'src>(
+    rule: &asm::Rule,
+    walker: syntax::Walker<'src>,
+    at_pattern_part: usize,
+    mut match_so_far: InstructionMatch)
+    -> WorkingMatches<'src>
+{
+    let span = walker.get_cursor_span();
+
+    for part in &rule.pattern[at_pattern_part..]
+    {
+        if let asm::RulePatternPart::ParameterIndex(_) = part
+        {
+            let expr = expr::Expr::Literal(
+                span,
+                expr::Value::make_integer(0));
+
+            match_so_far.args.push(InstructionArgument {
+                kind: InstructionArgumentKind::Expr(expr),
+                span,
+                excerpt: "".to_string(),
+            });
+        }
+    }
+
+    match_so_far.omitted_optional = true;
+
+    vec![(match_so_far, walker)]
+}
+
+
 fn match_with_expr<'src>(
     defs: &asm::ItemDefs,
     rule: &asm::Rule,
@@ -715,6 +1185,60 @@ fn match_with_expr<'src>(
 }
 
 
+fn match_with_register_range<'src>(
+    defs: &asm::ItemDefs,
+    rule: &asm::Rule,
+    mut walker: syntax::Walker<'src>,
+    needs_consume_all_tokens: bool,
+    at_pattern_part: usize,
+    (prefix, start, end, size): (&str, usize, usize, usize),
+    mut match_so_far: InstructionMatch)
+    -> WorkingMatches<'src>
+{
+    let Some(tk_register) = walker.maybe_expect(syntax::TokenKind::Identifier)
+        else { return vec![] };
+
+    let excerpt = walker.get_span_excerpt(tk_register.span);
+    let lowercase = excerpt.to_ascii_lowercase();
+
+    let Some(index_str) = lowercase.strip_prefix(prefix)
+        else { return vec![] };
+
+    if index_str.len() == 0 ||
+        !index_str.chars().all(|c| c.is_ascii_digit())
+    {
+        return vec![];
+    }
+
+    let Ok(index) = usize::from_str_radix(index_str, 10)
+        else { return vec![] };
+
+    if index < start || index > end
+    {
+        return vec![];
+    }
+
+    let expr = expr::Expr::Literal(
+        tk_register.span,
+        expr::Value::make_integer(
+            util::BigInt::new(index, Some(size))));
+
+    match_so_far.args.push(InstructionArgument {
+        kind: InstructionArgumentKind::Expr(expr),
+        span: tk_register.span,
+        excerpt: excerpt.to_string(),
+    });
+
+    match_with_rule(
+        defs,
+        rule,
+        &mut walker,
+        needs_consume_all_tokens,
+        at_pattern_part + 1,
+        &mut match_so_far)
+}
+
+
 fn match_with_nested_ruledef<'src>(
     defs: &asm::ItemDefs,
     nested_ruledef_ref: util::ItemRef<asm::Ruledef>,
@@ -5,19 +5,29 @@ pub mod parser;
 pub use parser::{
     AstAny,
     AstDirectiveAddr,
+    AstDirectiveAlias,
     AstDirectiveAlign,
     AstDirectiveAssert,
     AstDirectiveBank,
     AstDirectiveBankdef,
     AstDirectiveBits,
+    AstDirectiveCommentStyle,
     AstDirectiveData,
+    AstDirectiveDiagnostic,
+    AstDirectiveDisable,
+    AstDirectiveExpectDigest,
+    AstDirectiveExpectSize,
     AstDirectiveFn,
     AstDirectiveInclude,
     AstDirectiveLabelAlign,
     AstDirectiveNoEmit,
     AstDirectiveOnce,
+    AstDirectivePatch,
+    AstDirectiveRegion,
+    AstDirectiveRepeat,
     AstDirectiveRes,
     AstDirectiveRuledef,
+    AstDirectiveUsing,
     AstField,
     AstFields,
     AstFnParameter,
@@ -30,6 +40,8 @@ pub use parser::{
     AstRuleParameterType,
     AstRulePatternPart,
     AstTopLevel,
+    AstUsingRuledef,
+    ParseCache,
 };
 
 pub mod decls;
@@ -39,6 +51,8 @@ pub mod defs;
 pub use defs::{
     ItemDefs,
     Bankdef,
+    BankFillPattern,
+    BankEndianness,
     Ruledef,
     RuledefMap,
     RuledefMapEntry,
@@ -55,6 +69,8 @@ pub use defs::{
     ResDirective,
     AlignDirective,
     AddrDirective,
+    PatchDirective,
+    ExpectSizeDirective,
 };
 
 pub mod matcher;
@@ -64,6 +80,8 @@ pub use matcher::{
     InstructionMatchResolution,
     InstructionArgument,
     InstructionArgumentKind,
+    get_rule_size_bounds,
+    get_ruledef_size_bounds,
 };
 
 pub mod resolver;
@@ -75,6 +93,16 @@ pub use resolver::{
 };
 
 pub mod output;
+pub use output::{
+    ByteRun,
+    ResolvedInstruction,
+};
+
+pub mod profile;
+pub use profile::{
+    AssemblyProfile,
+    ProfilePhase,
+};
 
 
 pub struct AssemblyResult
@@ -85,20 +113,54 @@ pub struct AssemblyResult
     pub defs: Option<asm::ItemDefs>,
     pub output: Option<util::BitVec>,
     pub iterations_taken: Option<usize>,
+    pub profile: Option<asm::AssemblyProfile>,
+    /// The parsed files, for reuse by `assemble_incremental`
+    pub parse_cache: asm::ParseCache,
 }
 
 
 pub struct AssemblyOptions
 {
     pub max_iterations: usize,
+    pub max_include_depth: usize,
     pub debug_iterations: bool,
+    pub profile: bool,
+    pub warnings_as_errors: bool,
     pub optimize_statically_known: bool,
     pub optimize_instruction_matching: bool,
 
     pub driver_symbol_defs: Vec<DriverSymbolDef>,
+
+    /// The value returned by `builddate()`. Left unset,
+    /// it returns `BUILD_DATE_SENTINEL` to keep builds reproducible.
+    pub build_date: Option<u64>,
+
+    /// Prepended to every symbol name in symbol dumps, to
+    /// namespace them when linking with other modules.
+    /// References within the source are unaffected.
+    pub symbol_prefix: String,
+
+    /// The byte written to output positions not covered by
+    /// any bank's content, such as the gap left between banks
+    /// by their `outp` fields. Left unset, those are zero.
+    pub gap_fill: Option<u8>,
+
+    /// Stops after resolution and the bank overlap check,
+    /// reporting diagnostics without building the output.
+    pub check_only: bool,
+
+    /// Resolves instructions matching multiple rules with the
+    /// same encoding size to the first-defined one, instead
+    /// of reporting them as ambiguous.
+    pub first_rule_wins: bool,
 }
 
 
+/// The value returned by `builddate()` when no
+/// build date was given
+pub const BUILD_DATE_SENTINEL: u64 = 0;
+
+
 pub struct DriverSymbolDef
 {
     pub name: String,
@@ -117,7 +179,51 @@ impl AssemblyResult
             defs: None,
             output: None,
             iterations_taken: None,
+            profile: None,
+            parse_cache: asm::ParseCache::new(),
+        }
+    }
+
+
+    /// Iterates over every instruction of a successful
+    /// assembly, ordered by bank and then by address.
+    pub fn iter_resolved_instructions<'a>(
+        &'a self,
+        report: &mut diagn::Report)
+        -> Result<std::vec::IntoIter<ResolvedInstruction<'a>>, ()>
+    {
+        if self.output.is_none()
+        {
+            report.error("assembly did not succeed");
+            return Err(());
+        }
+
+        output::iter_resolved_instructions(
+            report,
+            self.ast.as_ref().unwrap(),
+            self.decls.as_ref().unwrap(),
+            self.defs.as_ref().unwrap())
+    }
+
+
+    /// Lists the runs of identical bytes in the output of
+    /// a successful assembly that are at least `min_length`
+    /// bytes long, ordered by position.
+    pub fn get_byte_runs(
+        &self,
+        report: &mut diagn::Report,
+        min_length: usize)
+        -> Result<Vec<ByteRun>, ()>
+    {
+        if self.output.is_none()
+        {
+            report.error("assembly did not succeed");
+            return Err(());
         }
+
+        Ok(output::get_byte_runs(
+            self.output.as_ref().unwrap(),
+            min_length))
     }
 }
 
@@ -128,11 +234,19 @@ impl AssemblyOptions
     {
         AssemblyOptions {
             max_iterations: 10,
+            max_include_depth: 64,
             debug_iterations: false,
+            profile: false,
+            warnings_as_errors: false,
             optimize_statically_known: true,
             optimize_instruction_matching: true,
 
             driver_symbol_defs: Vec::new(),
+            build_date: None,
+            symbol_prefix: String::new(),
+            gap_fill: None,
+            check_only: false,
+            first_rule_wins: false,
         }
     }
 }
@@ -145,15 +259,83 @@ pub fn assemble<S>(
     root_filenames: &[S])
     -> AssemblyResult
     where S: std::borrow::Borrow<str>
+{
+    assemble_with_cache(
+        report,
+        opts,
+        fileserver,
+        root_filenames,
+        asm::ParseCache::new())
+}
+
+
+/// Assembles again after a previous assembly, only parsing
+/// the files that were changed since, or that weren't
+/// parsed before. Declarations, definitions, and resolution
+/// are always redone over the whole program, since any file
+/// can depend on the symbols of any other.
+pub fn assemble_incremental<S, T>(
+    report: &mut diagn::Report,
+    opts: &AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    root_filenames: &[S],
+    prev_assembly: AssemblyResult,
+    changed_filenames: &[T])
+    -> AssemblyResult
+    where
+        S: std::borrow::Borrow<str>,
+        T: std::borrow::Borrow<str>
+{
+    let mut cache = prev_assembly.parse_cache;
+
+    for filename in changed_filenames
+    {
+        cache.invalidate(filename.borrow());
+    }
+
+    assemble_with_cache(
+        report,
+        opts,
+        fileserver,
+        root_filenames,
+        cache)
+}
+
+
+fn assemble_with_cache<S>(
+    report: &mut diagn::Report,
+    opts: &AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    root_filenames: &[S],
+    cache: asm::ParseCache)
+    -> AssemblyResult
+    where S: std::borrow::Borrow<str>
 {
     let mut assembly = AssemblyResult::new();
+    assembly.parse_cache = cache;
+
+    if opts.warnings_as_errors
+    {
+        report.set_warnings_as_errors(true);
+    }
+
+    if opts.profile
+    {
+        assembly.profile = Some(AssemblyProfile::new());
+    }
 
     let mut run = || -> Result<(), ()>
     {
+        let mut phase_start = std::time::Instant::now();
+
         assembly.ast = Some(parser::parse_many_and_resolve_includes(
             report,
             fileserver,
-            root_filenames)?);
+            root_filenames,
+            opts.max_include_depth,
+            &mut assembly.parse_cache)?);
+
+        assembly.end_profile_phase("parse", &mut phase_start);
 
         assembly.decls = Some(decls::init(report)?);
 
@@ -168,12 +350,16 @@ pub fn assemble<S>(
                 assembly.ast.as_mut().unwrap(),
                 assembly.decls.as_mut().unwrap())?;
 
+            assembly.end_profile_phase("decls", &mut phase_start);
+
             defs::define_symbols(
                 report,
                 opts,
                 assembly.ast.as_mut().unwrap(),
                 assembly.decls.as_ref().unwrap(),
                 assembly.defs.as_mut().unwrap())?;
+
+            assembly.end_profile_phase("defs", &mut phase_start);
                 
             let resolved_constants_count = resolver::resolve_constants_simple(
                 report,
@@ -182,8 +368,18 @@ pub fn assemble<S>(
                 assembly.ast.as_ref().unwrap(),
                 assembly.decls.as_ref().unwrap(),
                 assembly.defs.as_mut().unwrap())?;
+
+            assembly.end_profile_phase("resolve_constants", &mut phase_start);
     
             let resolved_ifs_count = resolver::resolve_ifs(
+                report,
+                opts,
+                fileserver,
+                assembly.ast.as_mut().unwrap(),
+                assembly.decls.as_mut().unwrap(),
+                assembly.defs.as_mut().unwrap())?;
+
+            let resolved_repeats_count = resolver::resolve_repeats(
                 report,
                 opts,
                 fileserver,
@@ -191,8 +387,11 @@ pub fn assemble<S>(
                 assembly.decls.as_ref().unwrap(),
                 assembly.defs.as_mut().unwrap())?;
 
+            assembly.end_profile_phase("resolve_ifs_repeats", &mut phase_start);
+
             if resolved_constants_count == prev_resolved_constants_count &&
-                resolved_ifs_count == 0
+                resolved_ifs_count == 0 &&
+                resolved_repeats_count == 0
             {
                 break;
             }
@@ -205,6 +404,16 @@ pub fn assemble<S>(
             assembly.ast.as_ref().unwrap(),
             assembly.decls.as_ref().unwrap(),
             assembly.defs.as_ref().unwrap())?;
+
+        resolver::check_leftover_repeats(
+            report,
+            assembly.ast.as_ref().unwrap(),
+            assembly.decls.as_ref().unwrap(),
+            assembly.defs.as_ref().unwrap())?;
+
+        resolver::expand_aliases(
+            report,
+            assembly.ast.as_mut().unwrap())?;
             
         defs::define_remaining(
             report,
@@ -213,6 +422,8 @@ pub fn assemble<S>(
             assembly.defs.as_mut().unwrap(),
             assembly.decls.as_mut().unwrap())?;
 
+        assembly.end_profile_phase("defs", &mut phase_start);
+
         matcher::match_all(
             report,
             opts,
@@ -220,6 +431,16 @@ pub fn assemble<S>(
             assembly.decls.as_ref().unwrap(),
             assembly.defs.as_mut().unwrap())?;
 
+        assembly.end_profile_phase("match_all", &mut phase_start);
+
+        resolver::check_unknown_symbols(
+            report,
+            opts,
+            fileserver,
+            assembly.ast.as_ref().unwrap(),
+            assembly.decls.as_ref().unwrap(),
+            assembly.defs.as_mut().unwrap())?;
+
         assembly.iterations_taken = Some(resolver::resolve_iteratively(
             report,
             opts,
@@ -227,24 +448,52 @@ pub fn assemble<S>(
             assembly.ast.as_ref().unwrap(),
             assembly.decls.as_ref().unwrap(),
             assembly.defs.as_mut().unwrap(),
-            opts.max_iterations)?);
+            opts.max_iterations,
+            assembly.profile.as_mut())?);
+
+        assembly.end_profile_phase("resolve_iteratively", &mut phase_start);
 
         output::check_bank_overlap(
             report,
             assembly.decls.as_ref().unwrap(),
             assembly.defs.as_mut().unwrap())?;
 
-        assembly.output = Some(output::build_output(
+        if opts.check_only
+        {
+            check_unused_defines(
+                report,
+                opts,
+                assembly.decls.as_ref().unwrap())?;
+
+            report.stop_at_errors()?;
+
+            return Ok(());
+        }
+
+        let output = output::build_output(
             report,
+            opts,
             assembly.ast.as_ref().unwrap(),
             assembly.decls.as_ref().unwrap(),
-            assembly.defs.as_ref().unwrap())?);
+            assembly.defs.as_ref().unwrap())?;
+
+        assembly.end_profile_phase("build_output", &mut phase_start);
+
+        output::check_expected_digests(
+            report,
+            assembly.ast.as_ref().unwrap(),
+            &output)?;
 
         check_unused_defines(
             report,
             opts,
             assembly.decls.as_ref().unwrap())?;
 
+        // Also catches warnings promoted to errors
+        report.stop_at_errors()?;
+
+        assembly.output = Some(output);
+
         Ok(())
     };
     
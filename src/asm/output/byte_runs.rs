@@ -0,0 +1,52 @@
+use crate::*;
+
+
+/// A run of consecutive identical bytes in the output.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ByteRun
+{
+    pub value: u8,
+    /// The position of the first byte, in bytes
+    /// from the start of the output
+    pub start: usize,
+    /// The number of bytes in the run
+    pub length: usize,
+}
+
+
+/// Splits the output into runs of identical bytes, keeping
+/// only those at least `min_length` bytes long, to estimate
+/// how well it would compress with run-length encoding.
+pub fn get_byte_runs(
+    output: &util::BitVec,
+    min_length: usize)
+    -> Vec<ByteRun>
+{
+    let bytes = output.format_binary();
+
+    let mut runs = Vec::new();
+
+    let mut start = 0;
+    while start < bytes.len()
+    {
+        let value = bytes[start];
+
+        let length = bytes[start..]
+            .iter()
+            .take_while(|b| **b == value)
+            .count();
+
+        if length >= min_length
+        {
+            runs.push(ByteRun {
+                value,
+                start,
+                length,
+            });
+        }
+
+        start += length;
+    }
+
+    runs
+}
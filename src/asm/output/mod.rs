@@ -1,6 +1,22 @@
 use crate::*;
 
 
+mod byte_runs;
+pub use byte_runs::{
+    ByteRun,
+    get_byte_runs,
+};
+
+mod object_json;
+pub use object_json::format_object_json;
+
+mod resolved_instructions;
+pub use resolved_instructions::{
+    ResolvedInstruction,
+    iter_resolved_instructions,
+};
+
+
 pub fn check_bank_overlap(
     report: &mut diagn::Report,
     decls: &asm::ItemDecls,
@@ -64,6 +80,8 @@ pub fn check_bank_overlap(
                         "bank `{}` defined here",
                         decl2.name),
                     decl2.span);
+
+                report.pop_parent();
                     
                 return Err(());
             }
@@ -76,6 +94,7 @@ pub fn check_bank_overlap(
 
 pub fn build_output(
     report: &mut diagn::Report,
+    opts: &asm::AssemblyOptions,
     ast: &asm::AstTopLevel,
     decls: &asm::ItemDecls,
     defs: &asm::ItemDefs)
@@ -89,6 +108,12 @@ pub fn build_output(
         defs,
         &mut output);
 
+    let mut written_ranges = vec![Vec::new(); defs.bankdefs.len()];
+
+    // The span of the last output in each bank,
+    // to point at when a bank overflows
+    let mut last_output_spans = vec![None; defs.bankdefs.len()];
+
     let mut iter = asm::ResolveIterator::new(
         ast,
         defs,
@@ -116,7 +141,8 @@ pub fn build_output(
                     defs,
                     &ctx,
                     0,
-                    false)?;
+                    false,
+                    last_output_spans[ctx.bank_ref.0])?;
 
                 let maybe_pos = ctx.get_output_position(defs);
 
@@ -124,7 +150,8 @@ pub fn build_output(
                     maybe_pos,
                     0,
                     symbol.value.unwrap_bigint().clone(),
-                    ast_symbol.decl_span);
+                    ast_symbol.decl_span,
+                    false);
             }
         }
         
@@ -145,7 +172,8 @@ pub fn build_output(
                 defs,
                 &ctx,
                 instr.encoding.size.unwrap(),
-                true)?;
+                true,
+                last_output_spans[ctx.bank_ref.0])?;
                 
             let addr = ctx
                 .get_address(
@@ -167,7 +195,19 @@ pub fn build_output(
 				ast_instr.span,
                 pos,
 				addr,
-                &instr.encoding);
+                &instr.encoding,
+                false);
+
+            apply_value_endian(
+                defs.bankdefs.get(ctx.bank_ref),
+                pos,
+                instr.encoding.size.unwrap(),
+                &mut output);
+
+            written_ranges[ctx.bank_ref.0].push(
+                (pos, instr.encoding.size.unwrap()));
+
+            last_output_spans[ctx.bank_ref.0] = Some(ast_instr.span);
         }
         
         else if let asm::ResolverNode::DataElement(ast_data, elem_index) = ctx.node
@@ -189,7 +229,8 @@ pub fn build_output(
                 defs,
                 &ctx,
                 elem.encoding.size.unwrap(),
-                true)?;
+                true,
+                last_output_spans[ctx.bank_ref.0])?;
                 
             let pos = ctx.get_output_position(defs).unwrap();
             let addr = ctx
@@ -210,7 +251,99 @@ pub fn build_output(
                 span,
                 pos,
                 addr,
-                &elem.encoding);
+                &elem.encoding,
+                elem.negative);
+
+            apply_value_endian(
+                defs.bankdefs.get(ctx.bank_ref),
+                pos,
+                elem.encoding.size.unwrap(),
+                &mut output);
+
+            written_ranges[ctx.bank_ref.0].push(
+                (pos, elem.encoding.size.unwrap()));
+
+            last_output_spans[ctx.bank_ref.0] = Some(span);
+        }
+        
+        else if let asm::ResolverNode::Align(ast_align) = ctx.node
+        {
+            let item_ref = ast_align.item_ref.unwrap();
+            let align = defs.align_directives.get(item_ref);
+
+            let Some(ref fill_value) = align.fill_value
+                else { continue };
+
+            let gap = asm::resolver::bits_until_alignment(
+                ctx.bank_data.cur_position,
+                align.align_size);
+
+            let unit = fill_value.size.unwrap();
+
+            if gap % unit != 0
+            {
+                report.error_span(
+                    format!(
+                        "alignment gap of {} bits can't be filled with {}-bit units",
+                        gap,
+                        unit),
+                    ast_align.header_span);
+
+                return Err(());
+            }
+
+            if gap == 0
+            {
+                continue;
+            }
+
+            check_bank_usage(
+                report,
+                ast_align.header_span,
+                defs,
+                &ctx)?;
+
+            check_bank_output(
+                report,
+                ast_align.header_span,
+                decls,
+                defs,
+                &ctx,
+                gap,
+                true,
+                last_output_spans[ctx.bank_ref.0])?;
+
+            let pos = ctx.get_output_position(defs).unwrap();
+            let addr = ctx
+                .get_address(
+                    report,
+                    ast_align.header_span,
+                    defs,
+                    true)?
+                .unwrap();
+
+            overlap_checker.check_and_insert(
+                report,
+                ast_align.header_span,
+                pos,
+                gap)?;
+
+            for offset in (0..gap).step_by(unit)
+            {
+                output.write_bigint(pos + offset, fill_value);
+            }
+
+            output.mark_span(
+                Some(pos),
+                gap,
+                addr,
+                ast_align.header_span,
+                false);
+
+            written_ranges[ctx.bank_ref.0].push(
+                (pos, gap));
+
+            last_output_spans[ctx.bank_ref.0] = Some(ast_align.header_span);
         }
         
         else if let asm::ResolverNode::Res(ast_res) = ctx.node
@@ -231,7 +364,8 @@ pub fn build_output(
                 defs,
                 &ctx,
                 res.reserve_size,
-                false)?;
+                res.fill_value.is_some(),
+                last_output_spans[ctx.bank_ref.0])?;
                 
             if let Some(pos) = ctx.get_output_position(defs)
             {
@@ -241,9 +375,75 @@ pub fn build_output(
                     pos,
                     res.reserve_size)?;
             }
+
+            if let Some(ref fill_value) = res.fill_value
+            {
+                let pos = ctx.get_output_position(defs).unwrap();
+                let addr = ctx
+                    .get_address(
+                        report,
+                        ast_res.header_span,
+                        defs,
+                        true)?
+                    .unwrap();
+
+                let unit = fill_value.size.unwrap();
+
+                for offset in (0..res.reserve_size).step_by(unit)
+                {
+                    output.write_bigint(pos + offset, fill_value);
+                }
+
+                output.mark_span(
+                    Some(pos),
+                    res.reserve_size,
+                    addr,
+                    ast_res.header_span,
+                    false);
+
+                written_ranges[ctx.bank_ref.0].push(
+                    (pos, res.reserve_size));
+            }
+
+            if res.reserve_size > 0
+            {
+                last_output_spans[ctx.bank_ref.0] = Some(ast_res.header_span);
+            }
         }
     }
 
+    check_partial_units(
+        report,
+        defs,
+        &mut written_ranges,
+        &mut output)?;
+
+    fill_bank_patterns(
+        defs,
+        &mut written_ranges,
+        &mut output);
+
+    apply_patches(
+        report,
+        ast,
+        defs,
+        &mut written_ranges,
+        &mut output)?;
+
+    apply_unit_endian(
+        defs,
+        &written_ranges,
+        &mut output);
+
+    if let Some(gap_fill) = opts.gap_fill
+    {
+        fill_gaps(
+            defs,
+            &written_ranges,
+            gap_fill,
+            &mut output);
+    }
+
     Ok(output)
 }
 
@@ -274,6 +474,451 @@ fn fill_banks(
 }
 
 
+/// Checks that the data in each filled bank covers whole
+/// units, so that the padding around it does too.
+/// Partial units are an error, unless the bank has
+/// `round_units`, in which case their remaining bits are
+/// left as zeros, and a trailing one is extended to the
+/// end of its unit.
+fn check_partial_units(
+    report: &mut diagn::Report,
+    defs: &asm::ItemDefs,
+    written_ranges: &mut Vec<Vec<(usize, usize)>>,
+    output: &mut util::BitVec)
+    -> Result<(), ()>
+{
+    for i in 0..defs.bankdefs.defs.len()
+    {
+        let bankdef = defs.bankdefs.get(util::ItemRef::new(i));
+        if !bankdef.fill
+        {
+            continue;
+        }
+
+        let Some(offset) = bankdef.output_offset
+            else { continue };
+
+        let addr_unit = bankdef.addr_unit;
+
+        let ranges = &mut written_ranges[i];
+        ranges.sort();
+
+        // Merge adjacent ranges, since only the
+        // boundaries of contiguous data matter
+        let mut merged: Vec<(usize, usize)> = Vec::new();
+        for &(pos, size) in ranges.iter()
+        {
+            match merged.last_mut()
+            {
+                Some(last) if last.1 >= pos =>
+                    last.1 = last.1.max(pos + size),
+                _ => merged.push((pos, pos + size)),
+            }
+        }
+
+        for (start, end) in merged
+        {
+            let boundary = {
+                if (start - offset) % addr_unit != 0
+                    { Some(start) }
+                else if (end - offset) % addr_unit != 0
+                    { Some(end) }
+                else
+                    { None }
+            };
+
+            let Some(boundary) = boundary
+                else { continue };
+
+            if bankdef.round_units
+            {
+                let unit_end = offset +
+                    (end - offset).div_ceil(addr_unit) * addr_unit;
+
+                if output.len() < unit_end
+                {
+                    output.write_bit(unit_end - 1, false);
+                }
+
+                continue;
+            }
+
+            let span = output.spans
+                .iter()
+                .filter(|s| s.size > 0)
+                .find(|s| s.offset.map_or(
+                    false,
+                    |o| o <= boundary && boundary <= o + s.size))
+                .map(|s| s.span)
+                .unwrap_or_else(diagn::Span::new_dummy);
+
+            report.push_parent(
+                format!(
+                    "data leaves a partial {}-bit unit in a filled bank",
+                    addr_unit),
+                span);
+
+            report.note(
+                "use `round_units` to pad it with zeros");
+
+            report.pop_parent();
+
+            return Err(());
+        }
+    }
+
+    Ok(())
+}
+
+
+/// Writes each bank's fill pattern to the units
+/// that weren't written by instructions or data.
+fn fill_bank_patterns(
+    defs: &asm::ItemDefs,
+    written_ranges: &mut Vec<Vec<(usize, usize)>>,
+    output: &mut util::BitVec)
+{
+    for i in 0..defs.bankdefs.defs.len()
+    {
+        let bankdef = defs.bankdefs.get(util::ItemRef::new(i));
+        if !bankdef.fill ||
+            bankdef.fill_pattern == asm::BankFillPattern::Zero
+        {
+            continue;
+        }
+
+        let Some(offset) = bankdef.output_offset
+            else { continue };
+
+        let ranges = &mut written_ranges[i];
+        ranges.sort();
+
+        let end = {
+            match bankdef.size
+            {
+                Some(size) => offset + size,
+                None => ranges
+                    .iter()
+                    .map(|r| r.0 + r.1)
+                    .max()
+                    .unwrap_or(offset),
+            }
+        };
+
+        let mut range_index = 0;
+        let mut unit_index = 0;
+
+        while offset + (unit_index + 1) * bankdef.addr_unit <= end
+        {
+            let unit_start = offset + unit_index * bankdef.addr_unit;
+            let unit_end = unit_start + bankdef.addr_unit;
+
+            while range_index < ranges.len() &&
+                ranges[range_index].0 + ranges[range_index].1 <= unit_start
+            {
+                range_index += 1;
+            }
+
+            let is_written =
+                range_index < ranges.len() &&
+                ranges[range_index].0 < unit_end;
+
+            if !is_written
+            {
+                output.write_bigint(
+                    unit_start,
+                    &bankdef.fill_pattern.get_unit(
+                        bankdef.addr_unit,
+                        &bankdef.addr_start,
+                        unit_index));
+            }
+
+            unit_index += 1;
+        }
+    }
+}
+
+
+/// Reverses the order of the units of a value just
+/// written to the output, if its bank is `value_endian`
+/// little. Values that aren't made of whole units,
+/// or that fit in a single one, are left as-is.
+fn apply_value_endian(
+    bankdef: &asm::Bankdef,
+    pos: usize,
+    size: usize,
+    output: &mut util::BitVec)
+{
+    if bankdef.value_endian == asm::BankEndianness::Little &&
+        size > bankdef.addr_unit &&
+        size % bankdef.addr_unit == 0
+    {
+        reverse_chunks(
+            output,
+            pos,
+            size,
+            bankdef.addr_unit);
+    }
+}
+
+
+/// Reverses the order of the bytes within each unit
+/// of the banks that are `unit_endian` little.
+/// This runs after every value was written, so it
+/// composes with `value_endian`: the value is first
+/// split into units, then each unit into bytes.
+fn apply_unit_endian(
+    defs: &asm::ItemDefs,
+    written_ranges: &Vec<Vec<(usize, usize)>>,
+    output: &mut util::BitVec)
+{
+    for i in 0..defs.bankdefs.defs.len()
+    {
+        let bankdef = defs.bankdefs.get(util::ItemRef::new(i));
+        if bankdef.unit_endian != asm::BankEndianness::Little
+        {
+            continue;
+        }
+
+        let Some(offset) = bankdef.output_offset
+            else { continue };
+
+        let end = {
+            match bankdef.size
+            {
+                Some(size) => offset + size,
+                None => written_ranges[i]
+                    .iter()
+                    .map(|r| r.0 + r.1)
+                    .max()
+                    .unwrap_or(offset),
+            }
+        };
+
+        // Don't extend the output past what was written
+        let end = std::cmp::min(end, output.len());
+
+        let mut unit_start = offset;
+        while unit_start + bankdef.addr_unit <= end
+        {
+            reverse_chunks(
+                output,
+                unit_start,
+                bankdef.addr_unit,
+                8);
+
+            unit_start += bankdef.addr_unit;
+        }
+    }
+}
+
+
+/// Reverses the order of the `chunk_size`-bit
+/// chunks within the given span of output.
+fn reverse_chunks(
+    output: &mut util::BitVec,
+    pos: usize,
+    size: usize,
+    chunk_size: usize)
+{
+    let bits = (pos..(pos + size))
+        .map(|i| output.read_bit(i))
+        .collect::<Vec<_>>();
+
+    for (chunk_index, chunk) in bits.chunks(chunk_size).rev().enumerate()
+    {
+        for (i, bit) in chunk.iter().enumerate()
+        {
+            output.write_bit(
+                pos + chunk_index * chunk_size + i,
+                *bit);
+        }
+    }
+}
+
+
+/// Writes the given byte to the output positions that
+/// fall outside of every filled bank and weren't written
+/// by instructions or data, such as gaps between banks.
+fn fill_gaps(
+    defs: &asm::ItemDefs,
+    written_ranges: &Vec<Vec<(usize, usize)>>,
+    gap_fill: u8,
+    output: &mut util::BitVec)
+{
+    let mut covered_ranges = written_ranges
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>();
+
+    for i in 0..defs.bankdefs.defs.len()
+    {
+        let bankdef = defs.bankdefs.get(util::ItemRef::new(i));
+        if !bankdef.fill
+        {
+            continue;
+        }
+
+        if let (Some(size), Some(offset)) =
+            (bankdef.size, bankdef.output_offset)
+        {
+            covered_ranges.push((offset, size));
+        }
+    }
+
+    covered_ranges.sort();
+
+    let mut range_index = 0;
+
+    for pos in 0..output.len()
+    {
+        while range_index < covered_ranges.len() &&
+            covered_ranges[range_index].0 + covered_ranges[range_index].1 <= pos
+        {
+            range_index += 1;
+        }
+
+        let is_covered = covered_ranges[range_index..]
+            .iter()
+            .take_while(|r| r.0 <= pos)
+            .any(|r| pos < r.0 + r.1);
+
+        if !is_covered
+        {
+            output.write_bit(
+                pos,
+                (gap_fill >> (7 - pos % 8)) & 1 != 0);
+        }
+    }
+}
+
+
+/// Overwrites already-emitted output with the values
+/// given by `#patch` directives.
+fn apply_patches(
+    report: &mut diagn::Report,
+    ast: &asm::AstTopLevel,
+    defs: &asm::ItemDefs,
+    written_ranges: &mut Vec<Vec<(usize, usize)>>,
+    output: &mut util::BitVec)
+    -> Result<(), ()>
+{
+    let mut applied: Vec<(usize, &util::BigInt, diagn::Span)> = Vec::new();
+
+    for node in &ast.nodes
+    {
+        let asm::AstAny::DirectivePatch(ast_patch) = node
+            else { continue };
+
+        let patch = defs.patch_directives.get(ast_patch.item_ref.unwrap());
+        let bankdef = defs.bankdefs.get(patch.bank_ref);
+        let size = patch.encoding.size.unwrap();
+
+        let maybe_pos = bankdef.output_offset.and_then(|offset| {
+            let addr_offset = patch.addr
+                .checked_sub(
+                    report,
+                    ast_patch.addr_expr.span(),
+                    &bankdef.addr_start)
+                .ok()?
+                .maybe_into::<usize>()?;
+
+            Some(offset + addr_offset.checked_mul(bankdef.addr_unit)?)
+        });
+
+        let ranges = &mut written_ranges[patch.bank_ref.0];
+        ranges.sort();
+
+        let pos = {
+            match maybe_pos
+            {
+                Some(pos) if is_range_written(ranges, pos, size) => pos,
+                _ =>
+                {
+                    report.error_span(
+                        "`#patch` address was not emitted",
+                        ast_patch.addr_expr.span());
+
+                    return Err(());
+                }
+            }
+        };
+
+        for &(prev_pos, prev_encoding, prev_span) in &applied
+        {
+            let prev_size = prev_encoding.size.unwrap();
+
+            let overlap_start = std::cmp::max(pos, prev_pos);
+            let overlap_end = std::cmp::min(pos + size, prev_pos + prev_size);
+
+            let conflicts = (overlap_start..overlap_end).any(|i| {
+                patch.encoding.get_bit(size - 1 - (i - pos)) !=
+                    prev_encoding.get_bit(prev_size - 1 - (i - prev_pos))
+            });
+
+            if conflicts
+            {
+                report.push_parent(
+                    "`#patch` conflicts with an earlier patch",
+                    ast_patch.header_span);
+
+                report.note_span(
+                    "earlier patch here",
+                    prev_span);
+
+                report.pop_parent();
+
+                return Err(());
+            }
+        }
+
+        output.write_bigint(
+            pos,
+            &patch.encoding);
+
+        apply_value_endian(
+            bankdef,
+            pos,
+            size,
+            output);
+
+        applied.push((pos, &patch.encoding, ast_patch.header_span));
+    }
+
+    Ok(())
+}
+
+
+/// Whether the sorted `ranges` fully cover the
+/// given span of output.
+fn is_range_written(
+    ranges: &[(usize, usize)],
+    pos: usize,
+    size: usize)
+    -> bool
+{
+    let mut covered_up_to = pos;
+
+    for &(range_pos, range_size) in ranges
+    {
+        if covered_up_to >= pos + size
+        {
+            break;
+        }
+
+        if range_pos <= covered_up_to
+        {
+            covered_up_to = std::cmp::max(
+                covered_up_to,
+                range_pos + range_size);
+        }
+    }
+
+    covered_up_to >= pos + size
+}
+
+
 fn check_bank_usage(
     report: &mut diagn::Report,
     span: diagn::Span,
@@ -306,7 +951,8 @@ fn check_bank_output(
     defs: &asm::ItemDefs,
     ctx: &asm::ResolverContext,
     size: usize,
-    write: bool)
+    write: bool,
+    last_output_span: Option<diagn::Span>)
     -> Result<(), ()>
 {
     let bankdef = defs.bankdefs.get(ctx.bank_ref);
@@ -317,17 +963,32 @@ fn check_bank_output(
         // FIXME: Addition can overflow
         if ctx.bank_data.cur_position + size > bank_size
         {
-            report.push_parent(
+            let mut msg = diagn::Message::error_span(
                 format!(
                     "output out of range for bank `{}`",
                     bankdef_decl.name),
                 span);
 
-            report.note_span(
+            msg.inner.push(diagn::Message::note_span(
                 "bank defined here:",
-                bankdef_decl.span);
-    
-            report.pop_parent();
+                bankdef_decl.span));
+
+            let overflow = ctx.bank_data.cur_position + size - bank_size;
+
+            msg.inner.push(diagn::Message::note(
+                format!(
+                    "exceeds the bank's size of {} by {}",
+                    describe_bank_size(bank_size, bankdef.addr_unit),
+                    describe_bank_size(overflow, bankdef.addr_unit))));
+
+            if let Some(last_output_span) = last_output_span
+            {
+                msg.inner.push(diagn::Message::note_span(
+                    "last output that fit in the bank",
+                    last_output_span));
+            }
+
+            report.message(msg);
     
             return Err(());
         }
@@ -351,4 +1012,111 @@ fn check_bank_output(
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Formats a size in bits as a count of the
+/// bank's address units whenever it divides evenly
+fn describe_bank_size(
+    bits: usize,
+    addr_unit: usize)
+    -> String
+{
+    if bits % addr_unit == 0
+    {
+        let units = bits / addr_unit;
+        format!("{} unit{}", units, if units == 1 { "" } else { "s" })
+    }
+    else
+    {
+        format!("{} bit{}", bits, if bits == 1 { "" } else { "s" })
+    }
+}
+
+
+/// Compares the final output against every
+/// `#expect_digest` in the source.
+pub fn check_expected_digests(
+    report: &mut diagn::Report,
+    ast: &asm::AstTopLevel,
+    output: &util::BitVec)
+    -> Result<(), ()>
+{
+    let mut maybe_actual_digest = None;
+
+    for node in &ast.nodes
+    {
+        let asm::AstAny::DirectiveExpectDigest(node) = node
+            else { continue };
+
+        let actual_digest = maybe_actual_digest
+            .get_or_insert_with(|| util::sha256_hex(&output.format_binary()));
+
+        if *actual_digest != node.sha256
+        {
+            report.push_parent(
+                "output digest mismatch",
+                node.header_span);
+
+            report.push_multiple(vec![
+                diagn::Message::note(format!(
+                    "expected sha256 digest `{}`",
+                    node.sha256)),
+                diagn::Message::note(format!(
+                    "got sha256 digest `{}`",
+                    actual_digest)),
+            ]);
+
+            report.pop_parent();
+
+            return Err(());
+        }
+    }
+
+    Ok(())
+}
+
+
+/// Finds the range of output positions taken by a bank:
+/// its full size if it has `fill` enabled, or else up to the
+/// end of its last instruction or data element.
+/// Returns `None` for banks that don't produce output.
+pub fn get_bank_output_range(
+    defs: &asm::ItemDefs,
+    bank_ref: util::ItemRef<asm::Bankdef>)
+    -> Option<std::ops::Range<usize>>
+{
+    let bankdef = defs.bankdefs.get(bank_ref);
+
+    let offset = bankdef.output_offset?;
+
+    if bankdef.fill
+    {
+        if let Some(size) = bankdef.size
+        {
+            return Some(offset..(offset + size));
+        }
+    }
+
+    let instr_ends = defs.instructions.defs
+        .iter()
+        .flatten()
+        .filter(|instr| instr.bank_ref.map(|r| r.0) == Some(bank_ref.0))
+        .filter_map(|instr| instr.position_within_bank
+            .zip(instr.encoding.size)
+            .map(|(pos, size)| pos + size));
+
+    let data_ends = defs.data_elems.defs
+        .iter()
+        .flatten()
+        .filter(|elem| elem.bank_ref.map(|r| r.0) == Some(bank_ref.0))
+        .filter_map(|elem| elem.position_within_bank
+            .zip(elem.encoding.size)
+            .map(|(pos, size)| pos + size));
+
+    let end = instr_ends
+        .chain(data_ends)
+        .max()
+        .unwrap_or(0);
+
+    Some(offset..(offset + end))
+}
@@ -0,0 +1,92 @@
+use crate::*;
+
+
+/// Serializes the output bits and the emitted symbols
+/// as a JSON object file, for consumption by a separate
+/// linker.
+///
+/// The schema is versioned by the `"version"` field:
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "bits": 16,
+///   "data": "55aa",
+///   "symbols": [
+///     { "name": "start", "kind": "label", "value": "0x0" }
+///   ],
+///   "relocations": [],
+///   "externs": []
+/// }
+/// ```
+///
+/// `data` holds the output as hex digits, padded with
+/// zeros to a whole digit, of which the first `bits`
+/// are meaningful. Each symbol is followed by its locals,
+/// which are given by their full name, like `loop.inner`,
+/// and every name starts with the given `symbol_prefix`.
+///
+/// `relocations` is reserved for the places in `data` that
+/// a linker must patch, and `externs` for the symbols that
+/// are referenced but defined in another module. Neither is
+/// tracked by the assembler yet, so both are always empty.
+pub fn format_object_json(
+    decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    output: &util::BitVec,
+    symbol_prefix: &str)
+    -> String
+{
+    let mut result = String::new();
+    result.push_str("{\n");
+
+    result.push_str("  \"version\": 1,\n");
+
+    result.push_str(&format!(
+        "  \"bits\": {},\n",
+        output.len()));
+
+    result.push_str(&format!(
+        "  \"data\": \"{}\",\n",
+        output.format_hexstr()));
+
+
+    let mut is_first = true;
+
+    let symbols = decls.symbols.format(
+        decls,
+        defs,
+        symbol_prefix,
+        &mut |result, symbol_decl, name, bigint|
+        {
+            let kind = {
+                match symbol_decl.kind
+                {
+                    util::SymbolKind::Constant => "constant",
+                    util::SymbolKind::Label => "label",
+                    util::SymbolKind::Function => "function",
+                    util::SymbolKind::Other => "other",
+                }
+            };
+
+            result.push_str(if is_first { "\n" } else { ",\n" });
+            result.push_str(&format!(
+                "    {{ \"name\": {}, \"kind\": \"{}\", \"value\": \"{}\" }}",
+                asm::decls::format_json_string(name),
+                kind,
+                bigint.as_radix_string(16, 0, true)));
+
+            is_first = false;
+        });
+
+    result.push_str("  \"symbols\": [");
+    result.push_str(&symbols);
+    result.push_str("\n  ],\n");
+
+    result.push_str("  \"relocations\": [],\n");
+    result.push_str("  \"externs\": []\n");
+
+
+    result.push_str("}\n");
+    result
+}
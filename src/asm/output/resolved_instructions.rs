@@ -0,0 +1,105 @@
+use crate::*;
+
+
+/// An instruction as it was finally resolved and
+/// emitted to the output.
+#[derive(Clone, Debug)]
+pub struct ResolvedInstruction<'a>
+{
+    pub span: diagn::Span,
+    pub src: &'a str,
+    pub bank_ref: util::ItemRef<asm::Bankdef>,
+    pub address: util::BigInt,
+    pub output_position: Option<usize>,
+    pub ruledef_ref: util::ItemRef<asm::Ruledef>,
+    pub rule_ref: util::ItemRef<asm::Rule>,
+    pub args: &'a [asm::InstructionArgument],
+    pub encoding: &'a util::BigInt,
+}
+
+
+impl<'a> ResolvedInstruction<'a>
+{
+    /// The size of the encoding, in bits.
+    pub fn size(&self) -> usize
+    {
+        self.encoding.size.unwrap()
+    }
+
+
+    /// The encoding split into bytes, most-significant first,
+    /// with the last byte padded with zero bits if the size
+    /// is not a multiple of 8.
+    pub fn bytes(&self) -> Vec<u8>
+    {
+        let size = self.size();
+
+        let mut bytes = vec![0u8; (size + 7) / 8];
+
+        for i in 0..size
+        {
+            if self.encoding.get_bit(size - 1 - i)
+            {
+                bytes[i / 8] |= 0x80 >> (i % 8);
+            }
+        }
+
+        bytes
+    }
+}
+
+
+/// Iterates over every instruction of a finished assembly,
+/// ordered by bank and then by address.
+pub fn iter_resolved_instructions<'a>(
+    report: &mut diagn::Report,
+    ast: &'a asm::AstTopLevel,
+    decls: &'a asm::ItemDecls,
+    defs: &'a asm::ItemDefs)
+    -> Result<std::vec::IntoIter<ResolvedInstruction<'a>>, ()>
+{
+    let mut result = Vec::new();
+
+    let mut iter = asm::ResolveIterator::new(
+        ast,
+        defs,
+        false,
+        true);
+
+    while let Some(ctx) = iter.next(report, decls, defs)?
+    {
+        if let asm::ResolverNode::Instruction(ast_instr) = ctx.node
+        {
+            let instr = defs.instructions.get(ast_instr.item_ref.unwrap());
+
+            let Some(chosen_match) = instr.chosen_match
+                else { continue };
+
+            let mtch = &instr.matches[chosen_match];
+
+            let address = ctx.eval_address(
+                report,
+                ast_instr.span,
+                defs,
+                false)?;
+
+            result.push(ResolvedInstruction {
+                span: ast_instr.span,
+                src: &ast_instr.src,
+                bank_ref: ctx.bank_ref,
+                address,
+                output_position: ctx.get_output_position(defs),
+                ruledef_ref: mtch.ruledef_ref,
+                rule_ref: mtch.rule_ref,
+                args: &mtch.args,
+                encoding: &instr.encoding,
+            });
+        }
+    }
+
+    result.sort_by(|a, b|
+        a.bank_ref.0.cmp(&b.bank_ref.0)
+            .then_with(|| a.address.cmp(&b.address)));
+
+    Ok(result.into_iter())
+}
\ No newline at end of file
@@ -1,6 +1,43 @@
 use crate::*;
 
 
+/// Names of all recognized directives, used for
+/// suggesting corrections on unknown directives.
+pub const DIRECTIVE_NAMES: &[&str] = &[
+    "addr",
+    "alias",
+    "align",
+    "assert",
+    "bank",
+    "bankdef",
+    "bits",
+    "commentstyle",
+    "const",
+    "d",
+    "disable",
+    "enable",
+    "error",
+    "expect_digest",
+    "expect_size",
+    "fill",
+    "fn",
+    "if",
+    "include",
+    "labelalign",
+    "noemit",
+    "once",
+    "patch",
+    "region",
+    "reltable",
+    "repeat",
+    "res",
+    "ruledef",
+    "subruledef",
+    "using",
+    "warning",
+];
+
+
 pub fn parse(
     report: &mut diagn::Report,
     walker: &mut syntax::Walker)
@@ -33,12 +70,28 @@ pub fn parse(
                     header_span)?));
         }
     }
+
+    if let Some(size_str) = name.strip_prefix("reltable")
+    {
+        if let Ok(elem_size) = usize::from_str_radix(size_str, 10)
+        {
+            return Ok(asm::AstAny::DirectiveData(
+                asm::parser::directive_reltable::parse(
+                    report,
+                    walker,
+                    elem_size,
+                    header_span)?));
+        }
+    }
     
     match name.as_ref()
     {
         "addr" => Ok(asm::AstAny::DirectiveAddr(
             asm::parser::directive_addr::parse(report, walker, header_span)?)),
         
+        "alias" => Ok(asm::AstAny::DirectiveAlias(
+            asm::parser::directive_alias::parse(report, walker, header_span)?)),
+        
         "align" => Ok(asm::AstAny::DirectiveAlign(
             asm::parser::directive_align::parse(report, walker, header_span)?)),
         
@@ -51,9 +104,30 @@ pub fn parse(
         "bits" => Ok(asm::AstAny::DirectiveBits(
             asm::parser::directive_bits::parse(report, walker, header_span)?)),
         
+        "commentstyle" => Ok(asm::AstAny::DirectiveCommentStyle(
+            asm::parser::directive_commentstyle::parse(report, walker, header_span)?)),
+            
         "const" => Ok(asm::AstAny::Symbol(
             asm::parser::directive_const::parse(report, walker, header_span)?)),
             
+        "disable" => Ok(asm::AstAny::DirectiveDisable(
+            asm::parser::directive_disable::parse(report, walker, header_span, false)?)),
+            
+        "enable" => Ok(asm::AstAny::DirectiveDisable(
+            asm::parser::directive_disable::parse(report, walker, header_span, true)?)),
+            
+        "error" => Ok(asm::AstAny::DirectiveDiagnostic(
+            asm::parser::directive_diagnostic::parse(report, walker, header_span, diagn::MessageKind::Error)?)),
+            
+        "expect_digest" => Ok(asm::AstAny::DirectiveExpectDigest(
+            asm::parser::directive_expect_digest::parse(report, walker, header_span)?)),
+            
+        "expect_size" => Ok(asm::AstAny::DirectiveExpectSize(
+            asm::parser::directive_expect_size::parse(report, walker, header_span)?)),
+        
+        "fill" => Ok(asm::AstAny::DirectiveRes(
+            asm::parser::directive_res::parse_fill(report, walker, header_span)?)),
+        
         "fn" => Ok(asm::AstAny::DirectiveFn(
             asm::parser::directive_fn::parse(report, walker, header_span)?)),
         
@@ -72,6 +146,15 @@ pub fn parse(
         "once" => Ok(asm::AstAny::DirectiveOnce(
             asm::parser::directive_once::parse(report, walker, header_span)?)),
             
+        "patch" => Ok(asm::AstAny::DirectivePatch(
+            asm::parser::directive_patch::parse(report, walker, header_span)?)),
+            
+        "region" => Ok(asm::AstAny::DirectiveRegion(
+            asm::parser::directive_region::parse(report, walker, header_span)?)),
+            
+        "repeat" => Ok(asm::AstAny::DirectiveRepeat(
+            asm::parser::directive_repeat::parse(report, walker, header_span)?)),
+            
         "res" => Ok(asm::AstAny::DirectiveRes(
             asm::parser::directive_res::parse(report, walker, header_span)?)),
         
@@ -84,11 +167,33 @@ pub fn parse(
         "assert" => Ok(asm::AstAny::DirectiveAssert(
             asm::parser::directive_assert::parse(report, walker, header_span)?)),
         
+        "using" => Ok(asm::AstAny::DirectiveUsing(
+            asm::parser::directive_using::parse(report, walker, header_span)?)),
+        
+        "warning" => Ok(asm::AstAny::DirectiveDiagnostic(
+            asm::parser::directive_diagnostic::parse(report, walker, header_span, diagn::MessageKind::Warning)?)),
+        
         _ =>
         {
-            report.error_span(
-                format!("unknown directive `{}`", name),
-                header_span);
+            let maybe_suggestion = util::find_closest_match(
+                &name,
+                DIRECTIVE_NAMES.iter().copied());
+
+            match maybe_suggestion
+            {
+                Some(suggestion) =>
+                    report.error_span(
+                        format!(
+                            "unknown directive `#{}`; did you mean `#{}`?",
+                            name,
+                            suggestion),
+                        header_span),
+
+                None =>
+                    report.error_span(
+                        format!("unknown directive `#{}`", name),
+                        header_span),
+            }
             
             Err(())
         }
@@ -0,0 +1,49 @@
+use crate::*;
+
+
+#[derive(Clone, Debug)]
+pub struct AstDirectiveAlias
+{
+    pub header_span: diagn::Span,
+    pub name_span: diagn::Span,
+    pub name: String,
+    pub target_span: diagn::Span,
+    pub target_src: String,
+}
+
+
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span)
+    -> Result<AstDirectiveAlias, ()>
+{
+    let tk_name = walker.expect(report, syntax::TokenKind::Identifier)?;
+    let name = walker.get_span_excerpt(tk_name.span).to_ascii_lowercase();
+
+    let tk_heavy_arrow = walker.expect(report, syntax::TokenKind::HeavyArrowRight)?;
+
+    walker.skip_ignorable();
+
+    let target = walker.advance_until_linebreak();
+    let target_src = target.get_full_excerpt().to_string();
+
+    if target_src.trim().len() == 0
+    {
+        report.error_span(
+            "expected instruction",
+            tk_heavy_arrow.span.after());
+
+        return Err(());
+    }
+
+    walker.expect_linebreak(report)?;
+
+    Ok(AstDirectiveAlias {
+        header_span,
+        name_span: tk_name.span,
+        name,
+        target_span: target.get_full_span(),
+        target_src,
+    })
+}
@@ -7,6 +7,9 @@ pub struct AstDirectiveAlign
     pub header_span: diagn::Span,
     pub expr: expr::Expr,
 
+    /// The unit value written over the gap, as in `#align 32, 0xea`
+    pub fill_expr: Option<expr::Expr>,
+
     pub item_ref: Option<util::ItemRef<asm::AlignDirective>>,
 }
 
@@ -19,11 +22,19 @@ pub fn parse(
 {
     let expr = expr::parse(report, walker)?;
 
+    let fill_expr = {
+        if walker.maybe_expect(syntax::TokenKind::Comma).is_some()
+            { Some(expr::parse(report, walker)?) }
+        else
+            { None }
+    };
+
     walker.expect_linebreak(report)?;
 
     Ok(AstDirectiveAlign {
         header_span,
         expr,
+        fill_expr,
 
         item_ref: None,
     })
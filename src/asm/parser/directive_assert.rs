@@ -6,6 +6,9 @@ pub struct AstDirectiveAssert
 {
     pub header_span: diagn::Span,
     pub condition_expr: expr::Expr,
+
+    /// Reported along with the failure, as in `#assert x < 4, "too large"`
+    pub message_expr: Option<expr::Expr>,
 }
 
 
@@ -17,10 +20,18 @@ pub fn parse(
 {
     let expr = expr::parse(report, walker)?;
 
+    let message_expr = {
+        if walker.maybe_expect(syntax::TokenKind::Comma).is_some()
+            { Some(expr::parse(report, walker)?) }
+        else
+            { None }
+    };
+
     walker.expect_linebreak(report)?;
 
     Ok(AstDirectiveAssert {
         header_span,
         condition_expr: expr,
+        message_expr,
     })
 }
\ No newline at end of file
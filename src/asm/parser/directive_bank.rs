@@ -7,6 +7,10 @@ pub struct AstDirectiveBank
     pub header_span: diagn::Span,
     pub name_span: diagn::Span,
     pub name: String,
+
+    /// For names like `page{i}` within a `#repeat`, the loop
+    /// variable whose value gets appended to `name`
+    pub name_index_var: Option<String>,
     
     pub item_ref: Option<util::ItemRef::<asm::Bankdef>>,
 }
@@ -18,9 +22,10 @@ pub fn parse(
     header_span: diagn::Span)
     -> Result<AstDirectiveBank, ()>
 {
-    let tk_name = walker.expect(report, syntax::TokenKind::Identifier)?;
-    let name = walker.get_span_excerpt(tk_name.span).to_string();
-    let name_span = tk_name.span;
+    let (name, name_span, name_index_var) =
+        asm::parser::directive_bankdef::parse_bank_name(
+            report,
+            walker)?;
 
     walker.expect_linebreak(report)?;
 
@@ -28,6 +33,7 @@ pub fn parse(
         header_span,
         name_span,
         name,
+        name_index_var,
 
         item_ref: None,
     })
@@ -8,13 +8,30 @@ pub struct AstDirectiveBankdef
     pub name_span: diagn::Span,
     pub name: String,
 
+    /// For names like `page{i}` within a `#repeat`, the loop
+    /// variable whose value gets appended to `name`
+    pub name_index_var: Option<String>,
+
     pub addr_unit: Option<expr::Expr>,
     pub label_align: Option<expr::Expr>,
 	pub addr_start: Option<expr::Expr>,
 	pub addr_end: Option<expr::Expr>,
+
+	/// Whether `addr_end` is the last address in the bank,
+	/// rather than the first one past it
+	pub addr_end_inclusive: bool,
+
 	pub addr_size: Option<expr::Expr>,
 	pub output_offset: Option<expr::Expr>,
 	pub fill: bool,
+	pub fill_pattern: Option<expr::Expr>,
+
+	/// Whether partial units left by data in a filled bank
+	/// are padded to whole units, instead of rejected
+	pub round_units: bool,
+
+	pub value_endian: Option<expr::Expr>,
+	pub unit_endian: Option<expr::Expr>,
     
     pub item_ref: Option<util::ItemRef::<asm::Bankdef>>,
 }
@@ -26,9 +43,9 @@ pub fn parse(
     header_span: diagn::Span)
     -> Result<AstDirectiveBankdef, ()>
 {
-    let tk_name = walker.expect(report, syntax::TokenKind::Identifier)?;
-    let name = walker.get_span_excerpt(tk_name.span).to_string();
-    let name_span = tk_name.span;
+    let (name, name_span, name_index_var) = parse_bank_name(
+        report,
+        walker)?;
 
     walker.expect(report, syntax::TokenKind::BraceOpen)?;
 
@@ -49,6 +66,10 @@ pub fn parse(
     let addr_end = fields.extract_as_optional_expr(
         report,
         "addr_end")?;
+
+    let addr_end_inclusive = fields.extract_as_bool(
+        report,
+        "addr_end_inclusive")?;
         
     let addr_size = fields.extract_as_optional_expr(
         report,
@@ -58,12 +79,37 @@ pub fn parse(
         report,
         "outp")?;
         
-    let fill = fields.extract_as_bool(
+    let (fill, fill_pattern) = {
+        match fields.extract_optional("fill")
+        {
+            Some(field) => (true, field.maybe_expr),
+            None => (false, None),
+        }
+    };
+
+    let round_units = fields.extract_as_bool(
+        report,
+        "round_units")?;
+
+    let value_endian = fields.extract_as_optional_expr(
+        report,
+        "value_endian")?;
+
+    let unit_endian = fields.extract_as_optional_expr(
         report,
-        "fill")?;
+        "unit_endian")?;
 
     fields.report_remaining(report)?;
 
+    if addr_end_inclusive && addr_end.is_none()
+    {
+        report.error_span(
+            "`addr_end_inclusive` used without `addr_end`",
+            header_span);
+
+        return Err(());
+    }
+
     walker.expect(report, syntax::TokenKind::BraceClose)?;
     walker.expect_linebreak(report)?;
 
@@ -71,15 +117,51 @@ pub fn parse(
         header_span,
         name_span,
         name,
+        name_index_var,
         
         addr_unit,
         label_align,
         addr_start,
         addr_end,
+        addr_end_inclusive,
         addr_size,
         output_offset,
         fill,
+        fill_pattern,
+        round_units,
+        value_endian,
+        unit_endian,
 
         item_ref: None,
     })
-}
\ No newline at end of file
+}
+
+
+/// Parses a bank name, optionally followed by a `#repeat`
+/// variable in braces, as in `page{i}`.
+pub fn parse_bank_name(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker)
+    -> Result<(String, diagn::Span, Option<String>), ()>
+{
+    let tk_name = walker.expect(report, syntax::TokenKind::Identifier)?;
+    let name = walker.get_span_excerpt(tk_name.span).to_string();
+
+    if walker.next_token().kind == syntax::TokenKind::BraceOpen &&
+        walker.next_nth_token(1).kind == syntax::TokenKind::Identifier &&
+        walker.next_nth_token(2).kind == syntax::TokenKind::BraceClose
+    {
+        walker.expect(report, syntax::TokenKind::BraceOpen)?;
+        let tk_var = walker.expect(report, syntax::TokenKind::Identifier)?;
+        let tk_close = walker.expect(report, syntax::TokenKind::BraceClose)?;
+
+        let var_name = walker.get_span_excerpt(tk_var.span).to_string();
+
+        return Ok((
+            name,
+            tk_name.span.join(tk_close.span),
+            Some(var_name)));
+    }
+
+    Ok((name, tk_name.span, None))
+}
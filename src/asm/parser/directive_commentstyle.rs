@@ -0,0 +1,68 @@
+use crate::*;
+
+
+#[derive(Clone, Debug)]
+pub struct AstDirectiveCommentStyle
+{
+    pub header_span: diagn::Span,
+    pub comment_styles: syntax::CommentStyles,
+}
+
+
+/// Parses a list of comment styles, like `";", "//", "/*"`,
+/// which replace the ones recognized for the rest of the file.
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span)
+    -> Result<AstDirectiveCommentStyle, ()>
+{
+    let mut comment_styles = syntax::CommentStyles {
+        semicolon: false,
+        double_slash: false,
+        slash_asterisk: false,
+    };
+
+    loop
+    {
+        let tk_style = walker.expect(report, syntax::TokenKind::String)?;
+
+        let style = syntax::excerpt_as_string_contents(
+            report,
+            tk_style.span,
+            walker.get_span_excerpt(tk_style.span))?;
+
+        match style.as_ref()
+        {
+            ";" => comment_styles.semicolon = true,
+            "//" => comment_styles.double_slash = true,
+            "/*" => comment_styles.slash_asterisk = true,
+            _ =>
+            {
+                report.push_parent(
+                    format!("unknown comment style `{}`", style),
+                    tk_style.span);
+
+                report.note("valid styles are `;`, `//`, and `/*`");
+
+                report.pop_parent();
+
+                return Err(());
+            }
+        }
+
+        if walker.maybe_expect(syntax::TokenKind::Comma).is_none()
+        {
+            break;
+        }
+    }
+
+    walker.expect_linebreak(report)?;
+
+    walker.set_comment_styles(comment_styles);
+
+    Ok(AstDirectiveCommentStyle {
+        header_span,
+        comment_styles,
+    })
+}
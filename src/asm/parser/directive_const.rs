@@ -58,6 +58,8 @@ pub fn parse(
             expr,
         }),
         no_emit,
+        parent_name: None,
+        anonymous: false,
 
         item_ref: None,
     })
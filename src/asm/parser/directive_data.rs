@@ -6,6 +6,9 @@ pub struct AstDirectiveData
 {
     pub header_span: diagn::Span,
     pub elem_size: Option<usize>,
+    /// For `#d(WIDTH)`, the constant expression giving
+    /// `elem_size`, evaluated when defining the elements
+    pub elem_size_expr: Option<expr::Expr>,
     pub elems: Vec<expr::Expr>,
 
     pub item_refs: Vec<util::ItemRef<asm::DataElement>>,
@@ -19,6 +22,23 @@ pub fn parse(
     header_span: diagn::Span)
     -> Result<AstDirectiveData, ()>
 {
+    // Only taken as a width if the parenthesis immediately
+    // follows the directive name, since `#d (x)` is data
+    let elem_size_expr = {
+        if elem_size.is_none() &&
+            walker.next_token().kind == syntax::TokenKind::ParenOpen
+        {
+            walker.expect(report, syntax::TokenKind::ParenOpen)?;
+            let expr = expr::parse(report, walker)?;
+            walker.expect(report, syntax::TokenKind::ParenClose)?;
+            Some(expr)
+        }
+        else
+        {
+            None
+        }
+    };
+
     let mut elems = Vec::new();
 
     loop
@@ -41,6 +61,7 @@ pub fn parse(
     Ok(AstDirectiveData {
         header_span,
         elem_size,
+        elem_size_expr,
         elems,
 
         item_refs: Vec::new(),
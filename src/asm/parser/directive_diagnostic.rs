@@ -0,0 +1,30 @@
+use crate::*;
+
+
+/// A user diagnostic, as in `#warning "msg"` or `#error "msg"`
+#[derive(Clone, Debug)]
+pub struct AstDirectiveDiagnostic
+{
+    pub header_span: diagn::Span,
+    pub kind: diagn::MessageKind,
+    pub message_expr: expr::Expr,
+}
+
+
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span,
+    kind: diagn::MessageKind)
+    -> Result<AstDirectiveDiagnostic, ()>
+{
+    let message_expr = expr::parse(report, walker)?;
+
+    walker.expect_linebreak(report)?;
+
+    Ok(AstDirectiveDiagnostic {
+        header_span,
+        kind,
+        message_expr,
+    })
+}
@@ -0,0 +1,51 @@
+use crate::*;
+
+
+#[derive(Clone, Debug)]
+pub struct AstDirectiveDisable
+{
+    pub header_span: diagn::Span,
+    pub name_span: diagn::Span,
+
+    /// Set when given as `ruledef.rule`, otherwise
+    /// rules with the name are looked up in every ruledef
+    pub ruledef_name: Option<String>,
+    pub rule_name: String,
+
+    /// Whether this is an `#enable` directive, which
+    /// undoes a previous `#disable`
+    pub enable: bool,
+}
+
+
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span,
+    enable: bool)
+    -> Result<AstDirectiveDisable, ()>
+{
+    let tk_name = walker.expect(report, syntax::TokenKind::Identifier)?;
+    let mut name_span = tk_name.span;
+    let mut ruledef_name = None;
+    let mut rule_name = walker.get_span_excerpt(tk_name.span).to_string();
+
+    if walker.maybe_expect(syntax::TokenKind::Dot).is_some()
+    {
+        let tk_rule_name = walker.expect(report, syntax::TokenKind::Identifier)?;
+        name_span = name_span.join(tk_rule_name.span);
+
+        ruledef_name = Some(rule_name);
+        rule_name = walker.get_span_excerpt(tk_rule_name.span).to_string();
+    }
+
+    walker.expect_linebreak(report)?;
+
+    Ok(AstDirectiveDisable {
+        header_span,
+        name_span,
+        ruledef_name,
+        rule_name,
+        enable,
+    })
+}
@@ -0,0 +1,60 @@
+use crate::*;
+
+
+#[derive(Clone, Debug)]
+pub struct AstDirectiveExpectDigest
+{
+    pub header_span: diagn::Span,
+    pub digest_span: diagn::Span,
+
+    /// The expected digest, in lowercase hex
+    pub sha256: String,
+}
+
+
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span)
+    -> Result<AstDirectiveExpectDigest, ()>
+{
+    let tk_digest = walker.expect(report, syntax::TokenKind::String)?;
+
+    let digest = syntax::excerpt_as_string_contents(
+        report,
+        tk_digest.span,
+        walker.get_span_excerpt(tk_digest.span))?;
+
+    walker.expect_linebreak(report)?;
+
+    let (algorithm, hex) = digest
+        .split_once(':')
+        .unwrap_or(("", &digest));
+
+    if algorithm != "sha256"
+    {
+        report.error_span(
+            format!(
+                "unsupported digest algorithm `{}`; expected `sha256:...`",
+                algorithm),
+            tk_digest.span);
+
+        return Err(());
+    }
+
+    if hex.len() != 64 ||
+        !hex.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        report.error_span(
+            "invalid sha256 digest; expected 64 hex digits",
+            tk_digest.span);
+
+        return Err(());
+    }
+
+    Ok(AstDirectiveExpectDigest {
+        header_span: header_span.join(tk_digest.span),
+        digest_span: tk_digest.span,
+        sha256: hex.to_ascii_lowercase(),
+    })
+}
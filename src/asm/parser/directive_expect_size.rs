@@ -0,0 +1,79 @@
+use crate::*;
+
+
+#[derive(Clone, Debug)]
+pub struct AstDirectiveExpectSize
+{
+    pub header_span: diagn::Span,
+
+    /// The instruction to be assembled in isolation
+    pub instr: asm::AstInstruction,
+
+    /// The expected size, in bytes
+    pub size_expr: expr::Expr,
+
+    pub item_ref: Option<util::ItemRef<asm::ExpectSizeDirective>>,
+}
+
+
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span)
+    -> Result<AstDirectiveExpectSize, ()>
+{
+    walker.skip_ignorable();
+
+    let start = walker.get_cursor_index();
+    let mut end = start;
+
+    while !walker.is_over()
+    {
+        let token = walker.next_token();
+
+        if token.kind == syntax::TokenKind::DoubleEqual ||
+            token.kind == syntax::TokenKind::LineBreak
+        {
+            break;
+        }
+
+        walker.advance_to_token_end(&token);
+
+        if !token.kind.is_ignorable()
+        {
+            end = walker.get_cursor_index();
+        }
+    }
+
+    let line = walker.slice(start, end);
+
+    if start == end
+    {
+        report.error_span(
+            "expected instruction",
+            header_span.after());
+
+        return Err(());
+    }
+
+    walker.expect(report, syntax::TokenKind::DoubleEqual)?;
+
+    let size_expr = expr::parse(report, walker)?;
+
+    walker.expect_linebreak(report)?;
+
+    Ok(AstDirectiveExpectSize {
+        header_span,
+        instr: asm::AstInstruction {
+            span: line.get_full_span(),
+            src: line.get_full_excerpt().to_string(),
+            alias_target_span: None,
+            using_ruledefs: None,
+
+            item_ref: None,
+        },
+        size_expr,
+
+        item_ref: None,
+    })
+}
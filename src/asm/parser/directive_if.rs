@@ -33,7 +33,7 @@ pub fn parse(
 }
 
 
-fn parse_braced_block(
+pub fn parse_braced_block(
     report: &mut diagn::Report,
     walker: &mut syntax::Walker)
     -> Result<asm::AstTopLevel, ()>
@@ -0,0 +1,36 @@
+use crate::*;
+
+
+#[derive(Clone, Debug)]
+pub struct AstDirectivePatch
+{
+    pub header_span: diagn::Span,
+    pub addr_expr: expr::Expr,
+    pub value_expr: expr::Expr,
+
+    pub item_ref: Option<util::ItemRef<asm::PatchDirective>>,
+}
+
+
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span)
+    -> Result<AstDirectivePatch, ()>
+{
+    let addr_expr = expr::parse(report, walker)?;
+
+    walker.expect(report, syntax::TokenKind::Comma)?;
+
+    let value_expr = expr::parse(report, walker)?;
+
+    walker.expect_linebreak(report)?;
+
+    Ok(AstDirectivePatch {
+        header_span,
+        addr_expr,
+        value_expr,
+
+        item_ref: None,
+    })
+}
@@ -0,0 +1,108 @@
+use crate::*;
+
+
+#[derive(Clone, Debug)]
+pub struct AstDirectiveRegion
+{
+    pub header_span: diagn::Span,
+    pub name: String,
+    pub name_span: diagn::Span,
+
+    /// The body, surrounded by the symbols that
+    /// describe the region's bounds
+    pub block: asm::AstTopLevel,
+}
+
+
+/// Parses `#region name { ... }`, which declares a global
+/// label `name` at the start of the body, along with the
+/// children `name.start`, `name.end`, and `name.len`.
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span)
+    -> Result<AstDirectiveRegion, ()>
+{
+    let tk_name = walker.expect(report, syntax::TokenKind::Identifier)?;
+    let name = walker.get_span_excerpt(tk_name.span).to_string();
+    let name_span = tk_name.span;
+
+    let body = asm::parser::directive_if::parse_braced_block(
+        report,
+        walker)?;
+
+    let make_symbol = |
+        hierarchy_level: usize,
+        symbol_name: &str,
+        kind: asm::AstSymbolKind,
+        parent_name: Option<String>|
+    {
+        asm::AstAny::Symbol(asm::AstSymbol {
+            decl_span: name_span,
+            hierarchy_level,
+            name: symbol_name.to_string(),
+            kind,
+            no_emit: false,
+            parent_name,
+            anonymous: false,
+
+            item_ref: None,
+        })
+    };
+
+    let make_variable = |child_name: &str|
+    {
+        expr::Expr::Variable(
+            name_span,
+            0,
+            vec![name.clone(), child_name.to_string()])
+    };
+
+    let len_expr = expr::Expr::BinaryOp(
+        name_span,
+        name_span,
+        expr::BinaryOp::Sub,
+        Box::new(make_variable("end")),
+        Box::new(make_variable("start")));
+
+    let mut block = asm::AstTopLevel {
+        nodes: Vec::new(),
+    };
+
+    block.nodes.push(make_symbol(
+        0,
+        &name,
+        asm::AstSymbolKind::Label,
+        None));
+
+    block.nodes.push(make_symbol(
+        1,
+        "start",
+        asm::AstSymbolKind::Label,
+        None));
+
+    block.nodes.extend(body.nodes);
+
+    // The body may have declared other global labels,
+    // so the remaining symbols name their parent
+    block.nodes.push(make_symbol(
+        1,
+        "end",
+        asm::AstSymbolKind::Label,
+        Some(name.clone())));
+
+    block.nodes.push(make_symbol(
+        1,
+        "len",
+        asm::AstSymbolKind::Constant(asm::AstSymbolConstant {
+            expr: len_expr,
+        }),
+        Some(name.clone())));
+
+    Ok(AstDirectiveRegion {
+        header_span,
+        name,
+        name_span,
+        block,
+    })
+}
@@ -0,0 +1,52 @@
+use crate::*;
+
+
+/// Parses `#reltableN base, a, b, ...` into a data directive
+/// emitting the offset `a - base` of each entry
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    elem_size: usize,
+    header_span: diagn::Span)
+    -> Result<asm::AstDirectiveData, ()>
+{
+    let base_expr = expr::parse(report, walker)?;
+
+    walker.expect(report, syntax::TokenKind::Comma)?;
+
+    let mut elems = Vec::new();
+
+    loop
+    {
+        let entry_expr = expr::parse(report, walker)?;
+        let span = entry_expr.span();
+
+        elems.push(expr::Expr::BinaryOp(
+            span,
+            span,
+            expr::BinaryOp::Sub,
+            Box::new(entry_expr),
+            Box::new(base_expr.clone())));
+
+        if !walker.maybe_expect(syntax::TokenKind::Comma).is_some()
+        {
+            break;
+        }
+
+        if walker.next_linebreak().is_some()
+        {
+            break;
+        }
+    }
+
+    walker.expect_linebreak(report)?;
+
+    Ok(asm::AstDirectiveData {
+        header_span,
+        elem_size: Some(elem_size),
+        elem_size_expr: None,
+        elems,
+
+        item_refs: Vec::new(),
+    })
+}
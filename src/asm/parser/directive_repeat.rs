@@ -0,0 +1,153 @@
+use crate::*;
+
+
+#[derive(Clone, Debug)]
+pub struct AstDirectiveRepeat
+{
+    pub header_span: diagn::Span,
+    pub var_span: diagn::Span,
+    pub var_name: Option<String>,
+
+    /// Defaults to zero when using the `#repeat <count>` form
+    pub start_expr: Option<expr::Expr>,
+    pub end_expr: expr::Expr,
+
+    /// Defaults to one
+    pub step_expr: Option<expr::Expr>,
+
+    pub body: asm::AstTopLevel,
+}
+
+
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span)
+    -> Result<AstDirectiveRepeat, ()>
+{
+    let is_range_form =
+        walker.next_useful_is(0, syntax::TokenKind::Identifier) &&
+        walker.next_useful_is(1, syntax::TokenKind::Identifier) &&
+        walker.get_span_excerpt(walker.next_nth_useful_token(1).span) == "in";
+
+    if !is_range_form
+    {
+        let count_expr = expr::parse(report, walker)?;
+
+        let body = asm::parser::directive_if::parse_braced_block(
+            report,
+            walker)?;
+
+        return Ok(AstDirectiveRepeat {
+            header_span,
+            var_span: header_span,
+            var_name: None,
+
+            start_expr: None,
+            end_expr: count_expr,
+            step_expr: None,
+
+            body,
+        });
+    }
+
+
+    let tk_var = walker.expect(report, syntax::TokenKind::Identifier)?;
+    let var_name = walker.get_span_excerpt(tk_var.span).to_string();
+    walker.expect(report, syntax::TokenKind::Identifier)?;
+
+
+    // Limit the walker so that the start expression
+    // doesn't try to consume the `..` operator
+    let Some(range_op_index) = find_range_operator(walker)
+        else {
+            report.error_span(
+                "expected `..`",
+                walker.get_cursor_span());
+            
+            return Err(());
+        };
+
+    let prev_limit = walker.get_cursor_limit();
+    walker.set_cursor_limit(range_op_index);
+    let maybe_start_expr = expr::parse(report, walker);
+    walker.set_cursor_limit(prev_limit);
+    let start_expr = maybe_start_expr?;
+
+    walker.expect(report, syntax::TokenKind::Dot)?;
+    walker.expect(report, syntax::TokenKind::Dot)?;
+
+    let end_expr = expr::parse(report, walker)?;
+
+
+    let is_step_keyword =
+        walker.next_useful_is(0, syntax::TokenKind::Identifier) &&
+        walker.get_span_excerpt(walker.next_nth_useful_token(0).span) == "step";
+
+    let step_expr = {
+        if is_step_keyword
+        {
+            walker.expect(report, syntax::TokenKind::Identifier)?;
+            Some(expr::parse(report, walker)?)
+        }
+        else
+        {
+            None
+        }
+    };
+
+
+    let body = asm::parser::directive_if::parse_braced_block(
+        report,
+        walker)?;
+
+    Ok(AstDirectiveRepeat {
+        header_span,
+        var_span: tk_var.span,
+        var_name: Some(var_name),
+
+        start_expr: Some(start_expr),
+        end_expr,
+        step_expr,
+
+        body,
+    })
+}
+
+
+/// Finds the byte index of a `..` operator on the current line,
+/// outside of any parentheses.
+fn find_range_operator(
+    walker: &syntax::Walker)
+    -> Option<usize>
+{
+    let mut nth = 0;
+    let mut paren_nesting = 0;
+
+    loop
+    {
+        let tk = walker.next_nth_token(nth);
+
+        match tk.kind
+        {
+            syntax::TokenKind::LineBreak |
+            syntax::TokenKind::BraceOpen =>
+                return None,
+
+            syntax::TokenKind::ParenOpen =>
+                paren_nesting += 1,
+
+            syntax::TokenKind::ParenClose =>
+                paren_nesting -= 1,
+
+            syntax::TokenKind::Dot
+                if paren_nesting == 0 &&
+                    walker.next_nth_token(nth + 1).kind == syntax::TokenKind::Dot =>
+                return Some(walker.get_index_at_span_start(tk.span)),
+
+            _ => {}
+        }
+
+        nth += 1;
+    }
+}
@@ -7,6 +7,10 @@ pub struct AstDirectiveRes
     pub header_span: diagn::Span,
     pub expr: expr::Expr,
 
+    /// The unit value for `#fill`, which writes
+    /// its reserved space instead of leaving it blank
+    pub fill_expr: Option<expr::Expr>,
+
     pub item_ref: Option<util::ItemRef<asm::ResDirective>>,
 }
 
@@ -24,6 +28,33 @@ pub fn parse(
     Ok(AstDirectiveRes {
         header_span,
         expr,
+        fill_expr: None,
+
+        item_ref: None,
+    })
+}
+
+
+/// Parses `#fill count, value` into a `#res` that
+/// writes `value` to each of its `count` units
+pub fn parse_fill(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span)
+    -> Result<AstDirectiveRes, ()>
+{
+    let expr = expr::parse(report, walker)?;
+
+    walker.expect(report, syntax::TokenKind::Comma)?;
+
+    let fill_expr = expr::parse(report, walker)?;
+
+    walker.expect_linebreak(report)?;
+
+    Ok(AstDirectiveRes {
+        header_span,
+        expr,
+        fill_expr: Some(fill_expr),
 
         item_ref: None,
     })
@@ -9,6 +9,7 @@ pub struct AstDirectiveRuledef
     pub is_subruledef: bool,
     pub name: Option<String>,
     pub rules: Vec<AstRule>,
+    pub nonempty: bool,
 
     pub item_ref: Option<util::ItemRef::<asm::Ruledef>>,
 }
@@ -17,8 +18,18 @@ pub struct AstDirectiveRuledef
 #[derive(Clone, Debug)]
 pub struct AstRule
 {
+    /// Set by a leading `@name:`, for `#disable`/`#enable`
+    pub name: Option<String>,
+    pub name_span: diagn::Span,
+
     pub pattern_span: diagn::Span,
     pub pattern: Vec<AstRulePatternPart>,
+
+    /// Index into `pattern` where an optional trailing
+    /// parameter begins, including its leading whitespace,
+    /// as in `ret {n: u8}?`
+    pub optional_tail: Option<usize>,
+
     pub expr: expr::Expr,
 }
 
@@ -50,6 +61,11 @@ pub enum AstRuleParameterType
     Unsigned(usize),
     Signed(usize),
     Integer(usize),
+    RegisterRange {
+        prefix: String,
+        start: usize,
+        end: usize,
+    },
 }
 
 
@@ -60,6 +76,30 @@ pub fn parse(
     header_span: diagn::Span)
     -> Result<AstDirectiveRuledef, ()>
 {
+    let mut nonempty = false;
+
+    if let Some(_) = walker.maybe_expect(syntax::TokenKind::ParenOpen)
+    {
+        let tk_attrb = walker.expect(report, syntax::TokenKind::Identifier)?;
+        let attrb = walker.get_span_excerpt(tk_attrb.span);
+
+        match attrb.as_ref()
+        {
+            "nonempty" if !is_subruledef => nonempty = true,
+            _ =>
+            {
+                report.error_span(
+                    format!("invalid attribute `{}`", attrb),
+                    tk_attrb.span);
+
+                return Err(());
+            }
+        }
+
+        walker.expect(report, syntax::TokenKind::ParenClose)?;
+    }
+
+
     let tk_name = walker.maybe_expect(syntax::TokenKind::Identifier);
     let name = tk_name.clone().map(|tk| walker.get_span_excerpt(tk.span).to_string());
     let name_span = tk_name
@@ -68,7 +108,7 @@ pub fn parse(
 
     walker.expect(report, syntax::TokenKind::BraceOpen)?;
 
-    let mut rules = Vec::new();
+    let mut rules: Vec<AstRule> = Vec::new();
 
     while !walker.next_useful_is(0, syntax::TokenKind::BraceClose)
     {
@@ -79,6 +119,28 @@ pub fn parse(
         
         walker.expect_linebreak(report)?;
 
+        if let Some(ref name) = rule.name
+        {
+            let maybe_prev = rules
+                .iter()
+                .find(|r| r.name.as_ref() == Some(name));
+
+            if let Some(prev) = maybe_prev
+            {
+                report.push_parent(
+                    format!("duplicate rule name `{}`", name),
+                    rule.name_span);
+
+                report.note_span(
+                    "first declared here",
+                    prev.name_span);
+
+                report.pop_parent();
+
+                return Err(());
+            }
+        }
+
         rules.push(rule);
     }
 
@@ -91,6 +153,7 @@ pub fn parse(
         is_subruledef,
         name,
         rules,
+        nonempty,
 
         item_ref: None,
     })
@@ -105,6 +168,7 @@ fn parse_rule(
 {
     let mut pattern_span = diagn::Span::new_dummy();
     let mut pattern = Vec::new();
+    let mut optional_tail = None;
     let mut has_used_empty_specifier = false;
 
 
@@ -112,6 +176,27 @@ fn parse_rule(
     walker.skip_ignorable();
 
 
+    // Parse an optional `@name:` before the pattern.
+    // A colon can't appear in a pattern, so this never
+    // takes over a pattern that starts with `@word`.
+    let mut name = None;
+    let mut name_span = diagn::Span::new_dummy();
+
+    if walker.next_token().kind == syntax::TokenKind::At &&
+        walker.next_nth_token(1).kind == syntax::TokenKind::Identifier &&
+        walker.next_nth_token(2).kind == syntax::TokenKind::Colon
+    {
+        let tk_at = walker.expect(report, syntax::TokenKind::At)?;
+        let tk_name = walker.expect(report, syntax::TokenKind::Identifier)?;
+        walker.expect(report, syntax::TokenKind::Colon)?;
+
+        name = Some(walker.get_span_excerpt(tk_name.span).to_string());
+        name_span = tk_at.span.join(tk_name.span);
+
+        walker.skip_ignorable();
+    }
+
+
     while !walker.is_over() &&
         !walker.next_useful_is(0, syntax::TokenKind::HeavyArrowRight)
     {
@@ -133,10 +218,46 @@ fn parse_rule(
             else
             {
                 let param = parse_rule_parameter(report, walker)?;
+                let param_span = param.name_span;
+                let is_ruledef_param = matches!(
+                    param.typ,
+                    AstRuleParameterType::Ruledef(_));
+
+                let tail_start = match pattern.last()
+                {
+                    Some(AstRulePatternPart::Whitespace) => pattern.len() - 1,
+                    _ => pattern.len(),
+                };
+
                 pattern.push(AstRulePatternPart::Parameter(param));
 
                 let tk_close = walker.expect(report, syntax::TokenKind::BraceClose)?;
                 pattern_span = pattern_span.join(tk_close.span);
+
+                if let Some(tk_question) = walker.maybe_expect(syntax::TokenKind::Question)
+                {
+                    pattern_span = pattern_span.join(tk_question.span);
+
+                    if is_ruledef_param
+                    {
+                        report.error_span(
+                            "optional parameter cannot be of a ruledef type",
+                            param_span);
+
+                        return Err(());
+                    }
+
+                    if !walker.next_useful_is(0, syntax::TokenKind::HeavyArrowRight)
+                    {
+                        report.error_span(
+                            "optional parameter must be at the end of the pattern",
+                            tk_question.span);
+
+                        return Err(());
+                    }
+
+                    optional_tail = Some(tail_start);
+                }
             }
         }
         
@@ -179,8 +300,11 @@ fn parse_rule(
     let expr = expr::parse(report, walker)?;
 
     Ok(AstRule {
+        name,
+        name_span,
         pattern_span,
         pattern,
+        optional_tail,
         expr,
     })
 }
@@ -200,8 +324,30 @@ fn parse_rule_parameter(
         {
             let tk_typename = walker.expect(report, syntax::TokenKind::Identifier)?;
             let typename = walker.get_span_excerpt(tk_typename.span);
-            let typ = interpret_typename(typename);
-            (typ, tk_typename.span)
+
+            if walker.next_useful_is(0, syntax::TokenKind::Dot) &&
+                walker.next_useful_is(1, syntax::TokenKind::Dot)
+            {
+                walker.expect(report, syntax::TokenKind::Dot)?;
+                walker.expect(report, syntax::TokenKind::Dot)?;
+
+                let tk_typename_end = walker.expect(report, syntax::TokenKind::Identifier)?;
+                let typename_end = walker.get_span_excerpt(tk_typename_end.span);
+                let type_span = tk_typename.span.join(tk_typename_end.span);
+
+                let typ = interpret_register_range(
+                    report,
+                    type_span,
+                    typename,
+                    typename_end)?;
+
+                (typ, type_span)
+            }
+            else
+            {
+                let typ = interpret_typename(typename);
+                (typ, tk_typename.span)
+            }
         }
         else
         {
@@ -241,4 +387,50 @@ fn interpret_typename(
     }
 
     AstRuleParameterType::Ruledef(typename.to_string())
+}
+
+
+/// Interprets a type such as `r0..r7`, which matches
+/// register names from `r0` to `r7`, inclusive.
+fn interpret_register_range(
+    report: &mut diagn::Report,
+    span: diagn::Span,
+    first: &str,
+    last: &str)
+    -> Result<AstRuleParameterType, ()>
+{
+    let split_register = |name: &str| -> Option<(String, usize)>
+    {
+        let prefix = name.trim_end_matches(|c: char| c.is_ascii_digit());
+        let index = usize::from_str_radix(&name[prefix.len()..], 10).ok()?;
+
+        if prefix.len() == 0
+        {
+            return None;
+        }
+
+        Some((prefix.to_ascii_lowercase(), index))
+    };
+
+    match (split_register(first), split_register(last))
+    {
+        (Some((prefix, start)), Some((prefix_end, end)))
+            if prefix == prefix_end && start <= end =>
+        {
+            Ok(AstRuleParameterType::RegisterRange {
+                prefix,
+                start,
+                end,
+            })
+        }
+
+        _ =>
+        {
+            report.error_span(
+                "invalid register range",
+                span);
+
+            Err(())
+        }
+    }
 }
\ No newline at end of file
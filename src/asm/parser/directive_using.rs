@@ -0,0 +1,101 @@
+use crate::*;
+
+
+#[derive(Clone, Debug)]
+pub struct AstDirectiveUsing
+{
+    pub header_span: diagn::Span,
+    pub ruledefs: Vec<AstUsingRuledef>,
+    pub block: asm::AstTopLevel,
+}
+
+
+#[derive(Clone, Debug)]
+pub struct AstUsingRuledef
+{
+    pub name: String,
+    pub span: diagn::Span,
+}
+
+
+pub fn parse(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker,
+    header_span: diagn::Span)
+    -> Result<AstDirectiveUsing, ()>
+{
+    let mut ruledefs = Vec::new();
+
+    loop
+    {
+        let tk_name = walker.expect(report, syntax::TokenKind::Identifier)?;
+
+        ruledefs.push(AstUsingRuledef {
+            name: walker.get_span_excerpt(tk_name.span).to_string(),
+            span: tk_name.span,
+        });
+
+        if walker.maybe_expect(syntax::TokenKind::Comma).is_none()
+        {
+            break;
+        }
+    }
+
+    let mut block = asm::parser::directive_if::parse_braced_block(
+        report,
+        walker)?;
+
+    // Nested `#using` blocks were already flattened while
+    // parsing, so their instructions keep the inner scope
+    apply_scope(&mut block, &ruledefs);
+
+    Ok(AstDirectiveUsing {
+        header_span,
+        ruledefs,
+        block,
+    })
+}
+
+
+fn apply_scope(
+    ast: &mut asm::AstTopLevel,
+    ruledefs: &Vec<AstUsingRuledef>)
+{
+    for node in &mut ast.nodes
+    {
+        match node
+        {
+            asm::AstAny::Instruction(ref mut ast_instr) =>
+                apply_scope_to_instruction(ast_instr, ruledefs),
+
+            asm::AstAny::DirectiveExpectSize(ref mut ast_expect_size) =>
+                apply_scope_to_instruction(&mut ast_expect_size.instr, ruledefs),
+
+            asm::AstAny::DirectiveIf(ref mut ast_if) =>
+            {
+                apply_scope(&mut ast_if.true_arm, ruledefs);
+
+                if let Some(ref mut false_arm) = ast_if.false_arm
+                {
+                    apply_scope(false_arm, ruledefs);
+                }
+            }
+
+            asm::AstAny::DirectiveRepeat(ref mut ast_repeat) =>
+                apply_scope(&mut ast_repeat.body, ruledefs),
+
+            _ => {}
+        }
+    }
+}
+
+
+fn apply_scope_to_instruction(
+    ast_instr: &mut asm::AstInstruction,
+    ruledefs: &Vec<AstUsingRuledef>)
+{
+    if ast_instr.using_ruledefs.is_none()
+    {
+        ast_instr.using_ruledefs = Some(ruledefs.clone());
+    }
+}
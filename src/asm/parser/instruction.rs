@@ -7,6 +7,14 @@ pub struct AstInstruction
     pub span: diagn::Span,
     pub src: String,
 
+    /// When expanded from an `#alias`, the span of
+    /// the instruction text that replaced `src`
+    pub alias_target_span: Option<diagn::Span>,
+
+    /// When within `#using` blocks, the ruledefs
+    /// of the innermost one, which restrict matching
+    pub using_ruledefs: Option<Vec<asm::AstUsingRuledef>>,
+
     pub item_ref: Option<util::ItemRef<asm::Instruction>>,
 }
 
@@ -25,6 +33,8 @@ pub fn parse(
     Ok(AstInstruction {
         span: line.get_full_span(),
         src: line.get_full_excerpt().to_string(),
+        alias_target_span: None,
+        using_ruledefs: None,
 
         item_ref: None,
     })
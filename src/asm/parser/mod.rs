@@ -6,6 +6,9 @@ mod directive;
 mod directive_addr;
 pub use directive_addr::AstDirectiveAddr;
 
+mod directive_alias;
+pub use directive_alias::AstDirectiveAlias;
+
 mod directive_align;
 pub use directive_align::AstDirectiveAlign;
 
@@ -21,17 +24,32 @@ pub use directive_bankdef::AstDirectiveBankdef;
 mod directive_bits;
 pub use directive_bits::AstDirectiveBits;
 
+mod directive_commentstyle;
+pub use directive_commentstyle::AstDirectiveCommentStyle;
+
 mod directive_const;
 
 mod directive_data;
 pub use directive_data::AstDirectiveData;
 
+mod directive_diagnostic;
+pub use directive_diagnostic::AstDirectiveDiagnostic;
+
+mod directive_expect_digest;
+pub use directive_expect_digest::AstDirectiveExpectDigest;
+
+mod directive_expect_size;
+pub use directive_expect_size::AstDirectiveExpectSize;
+
 mod directive_fn;
 pub use directive_fn::{
     AstDirectiveFn,
     AstFnParameter,
 };
 
+mod directive_disable;
+pub use directive_disable::AstDirectiveDisable;
+
 mod directive_if;
 pub use directive_if::AstDirectiveIf;
 
@@ -47,6 +65,17 @@ pub use directive_noemit::AstDirectiveNoEmit;
 mod directive_once;
 pub use directive_once::AstDirectiveOnce;
 
+mod directive_patch;
+pub use directive_patch::AstDirectivePatch;
+
+mod directive_region;
+pub use directive_region::AstDirectiveRegion;
+
+mod directive_reltable;
+
+mod directive_repeat;
+pub use directive_repeat::AstDirectiveRepeat;
+
 mod directive_res;
 pub use directive_res::AstDirectiveRes;
 
@@ -59,6 +88,12 @@ pub use directive_ruledef::{
     AstRuleParameterType,
 };
 
+mod directive_using;
+pub use directive_using::{
+    AstDirectiveUsing,
+    AstUsingRuledef,
+};
+
 mod fields;
 pub use fields::{
     AstFields,
@@ -80,20 +115,30 @@ pub use symbol::{
 pub enum AstAny
 {
     DirectiveAddr(AstDirectiveAddr),
+    DirectiveAlias(AstDirectiveAlias),
     DirectiveAlign(AstDirectiveAlign),
     DirectiveAssert(AstDirectiveAssert),
     DirectiveBank(AstDirectiveBank),
     DirectiveBankdef(AstDirectiveBankdef),
     DirectiveBits(AstDirectiveBits),
+    DirectiveCommentStyle(AstDirectiveCommentStyle),
     DirectiveData(AstDirectiveData),
+    DirectiveDiagnostic(AstDirectiveDiagnostic),
+    DirectiveDisable(AstDirectiveDisable),
+    DirectiveExpectDigest(AstDirectiveExpectDigest),
+    DirectiveExpectSize(AstDirectiveExpectSize),
     DirectiveFn(AstDirectiveFn),
     DirectiveIf(AstDirectiveIf),
     DirectiveInclude(AstDirectiveInclude),
     DirectiveLabelAlign(AstDirectiveLabelAlign),
     DirectiveNoEmit(AstDirectiveNoEmit),
     DirectiveOnce(AstDirectiveOnce),
+    DirectivePatch(AstDirectivePatch),
+    DirectiveRegion(AstDirectiveRegion),
+    DirectiveRepeat(AstDirectiveRepeat),
     DirectiveRes(AstDirectiveRes),
     DirectiveRuledef(AstDirectiveRuledef),
+    DirectiveUsing(AstDirectiveUsing),
     Instruction(AstInstruction),
     Symbol(AstSymbol),
 }
@@ -106,10 +151,64 @@ pub struct AstTopLevel
 }
 
 
+/// The ASTs of individual files, as parsed before their
+/// `#include` directives are expanded, so that files which
+/// haven't changed can skip tokenization and parsing
+/// when reassembling.
+#[derive(Clone, Debug, Default)]
+pub struct ParseCache
+{
+    files: std::collections::HashMap<String, (util::FileServerHandle, AstTopLevel)>,
+}
+
+
+impl ParseCache
+{
+    pub fn new() -> ParseCache
+    {
+        ParseCache {
+            files: std::collections::HashMap::new(),
+        }
+    }
+
+
+    /// Drops the AST of the given file, so that
+    /// it gets parsed again on the next use.
+    pub fn invalidate(&mut self, filename: &str)
+    {
+        self.files.remove(filename);
+    }
+
+
+    /// Whether the AST of the given file is cached.
+    pub fn contains(&self, filename: &str) -> bool
+    {
+        self.files.contains_key(filename)
+    }
+
+
+    fn get(
+        &self,
+        filename: &str,
+        file_handle: util::FileServerHandle)
+        -> Option<&AstTopLevel>
+    {
+        // A different handle means the cached spans
+        // would point into the wrong file
+        self.files
+            .get(filename)
+            .filter(|(handle, _)| *handle == file_handle)
+            .map(|(_, ast)| ast)
+    }
+}
+
+
 pub fn parse_many_and_resolve_includes<S>(
     report: &mut diagn::Report,
     fileserver: &mut dyn util::FileServer,
-    root_filenames: &[S])
+    root_filenames: &[S],
+    max_include_depth: usize,
+    cache: &mut ParseCache)
     -> Result<AstTopLevel, ()>
     where S: std::borrow::Borrow<str>
 {
@@ -127,7 +226,9 @@ pub fn parse_many_and_resolve_includes<S>(
             fileserver,
             file.borrow(),
             &mut Vec::new(),
-            &mut once_filenames)?;
+            &mut once_filenames,
+            max_include_depth,
+            cache)?;
 
         result.nodes.extend(ast.nodes);
     }
@@ -142,7 +243,9 @@ pub fn parse_and_resolve_includes<S>(
     fileserver: &mut dyn util::FileServer,
     root_filename: S,
     seen_filenames: &mut Vec<String>,
-    once_filenames: &mut std::collections::HashSet<String>)
+    once_filenames: &mut std::collections::HashSet<String>,
+    max_include_depth: usize,
+    cache: &mut ParseCache)
     -> Result<AstTopLevel, ()>
     where S: std::borrow::Borrow<str>
 {
@@ -158,17 +261,34 @@ pub fn parse_and_resolve_includes<S>(
         span,
         root_filename.borrow())?;
 
-    let src = fileserver.get_str(
-        report,
-        span,
-        file_handle)?;
+    let mut root_ast = {
+        match cache.get(root_filename.borrow(), file_handle)
+        {
+            Some(ast) => ast.clone(),
+            None =>
+            {
+                let src = fileserver.get_str(
+                    report,
+                    span,
+                    file_handle)?;
 
-    let mut walker = syntax::Walker::new(
-        &src,
-        file_handle,
-        0);
+                let mut walker = syntax::Walker::new(
+                    &src,
+                    file_handle,
+                    0);
 
-    let mut root_ast = parse(report, &mut walker)?;
+                let ast = parse(report, &mut walker)?;
+
+                cache.files.insert(
+                    root_filename.borrow().to_owned(),
+                    (file_handle, ast.clone()));
+
+                ast
+            }
+        }
+    };
+
+    let navigation_path = fileserver.get_navigation_path(file_handle);
 
     // Check presence of an #once directive
     if root_ast.nodes.iter().any(|n| matches!(n, AstAny::DirectiveOnce(_)))
@@ -188,7 +308,7 @@ pub fn parse_and_resolve_includes<S>(
             let included_filename = util::filename_navigate(
                 report,
                 ast_include.filename_span,
-                root_filename.borrow(),
+                &navigation_path,
                 &ast_include.filename)?;
 
 
@@ -201,6 +321,25 @@ pub fn parse_and_resolve_includes<S>(
                 return Err(());
             }
 
+
+            if seen_filenames.len() >= max_include_depth
+            {
+                report.push_parent(
+                    "include nesting too deep",
+                    ast_include.filename_span);
+
+                report.note(
+                    format!(
+                        "maximum depth is {}, included through {} -> {}",
+                        max_include_depth,
+                        seen_filenames.join(" -> "),
+                        included_filename));
+
+                report.pop_parent();
+
+                return Err(());
+            }
+
     
             seen_filenames.push(included_filename.clone());
 
@@ -210,7 +349,9 @@ pub fn parse_and_resolve_includes<S>(
                 fileserver,
                 included_filename.as_ref(),
                 seen_filenames,
-                once_filenames)?;
+                once_filenames,
+                max_include_depth,
+                cache)?;
 
             let inner_ast_len = inner_ast.nodes.len();
 
@@ -243,9 +384,12 @@ pub fn parse(
     
     while !walker.is_over()
     {
-        if let Some(node) = parse_line(report, walker)?
+        let maybe_node = parse_line(report, walker)?;
+        walker.check_unterminated(report)?;
+
+        if let Some(node) = maybe_node
         {
-            nodes.push(node);
+            push_node(&mut nodes, node);
         }
     }
 
@@ -265,9 +409,12 @@ pub fn parse_nested_toplevel(
     while !walker.is_over() &&
         !walker.next_useful_is(0, syntax::TokenKind::BraceClose)
     {
-        if let Some(node) = parse_line(report, walker)?
+        let maybe_node = parse_line(report, walker)?;
+        walker.check_unterminated(report)?;
+
+        if let Some(node) = maybe_node
         {
-            nodes.push(node);
+            push_node(&mut nodes, node);
         }
     }
 
@@ -277,6 +424,27 @@ pub fn parse_nested_toplevel(
 }
 
 
+/// Pushes a parsed node, splicing in the contents of
+/// `#using` blocks, whose instructions already
+/// carry their ruledef scope, and of `#region` blocks,
+/// which already hold the region's symbols
+fn push_node(
+    nodes: &mut Vec<AstAny>,
+    node: AstAny)
+{
+    match node
+    {
+        AstAny::DirectiveUsing(ast_using) =>
+            nodes.extend(ast_using.block.nodes),
+
+        AstAny::DirectiveRegion(ast_region) =>
+            nodes.extend(ast_region.block.nodes),
+
+        _ => nodes.push(node),
+    }
+}
+
+
 fn parse_line(
     report: &mut diagn::Report,
     walker: &mut syntax::Walker)
@@ -308,6 +476,14 @@ fn parse_line(
         Ok(Some(symbol::parse(report, walker)?))
     }
 
+    // Anonymous labels (a lone plus or minus sign)
+    else if (walker.next_useful_is(0, syntax::TokenKind::Plus) ||
+        walker.next_useful_is(0, syntax::TokenKind::Minus)) &&
+        walker.next_linebreak_after(1)
+    {
+        Ok(Some(symbol::parse_anonymous(report, walker)?))
+    }
+
     // Empty lines
     else if walker.maybe_expect_linebreak().is_some()
     {
@@ -330,20 +506,30 @@ impl AstAny
         match self
         {
             AstAny::DirectiveAddr(node) => node.header_span,
+            AstAny::DirectiveAlias(node) => node.header_span,
             AstAny::DirectiveAlign(node) => node.header_span,
             AstAny::DirectiveAssert(node) => node.header_span,
             AstAny::DirectiveBank(node) => node.header_span,
             AstAny::DirectiveBankdef(node) => node.header_span,
             AstAny::DirectiveBits(node) => node.header_span,
+            AstAny::DirectiveCommentStyle(node) => node.header_span,
             AstAny::DirectiveData(node) => node.header_span,
+            AstAny::DirectiveDiagnostic(node) => node.header_span,
+            AstAny::DirectiveDisable(node) => node.header_span,
+            AstAny::DirectiveExpectDigest(node) => node.header_span,
+            AstAny::DirectiveExpectSize(node) => node.header_span,
             AstAny::DirectiveFn(node) => node.header_span,
             AstAny::DirectiveIf(node) => node.header_span,
             AstAny::DirectiveInclude(node) => node.header_span,
             AstAny::DirectiveLabelAlign(node) => node.header_span,
             AstAny::DirectiveNoEmit(node) => node.header_span,
             AstAny::DirectiveOnce(node) => node.header_span,
+            AstAny::DirectivePatch(node) => node.header_span,
+            AstAny::DirectiveRegion(node) => node.header_span,
+            AstAny::DirectiveRepeat(node) => node.header_span,
             AstAny::DirectiveRes(node) => node.header_span,
             AstAny::DirectiveRuledef(node) => node.header_span,
+            AstAny::DirectiveUsing(node) => node.header_span,
             AstAny::Instruction(node) => node.span,
             AstAny::Symbol(node) => node.decl_span,
         }
@@ -9,6 +9,15 @@ pub struct AstSymbol
     pub name: String,
     pub kind: AstSymbolKind,
     pub no_emit: bool,
+
+    /// When set, the symbol is declared as a child of this
+    /// global symbol, instead of the one in context, like
+    /// for the symbols that `#region` places after its body
+    pub parent_name: Option<String>,
+
+    /// Whether this is an anonymous `+` or `-` label,
+    /// referenced only by position, through `name`
+    pub anonymous: bool,
     
     pub item_ref: Option<util::ItemRef::<asm::Symbol>>,
 }
@@ -61,6 +70,8 @@ pub fn parse(
                 expr,
             }),
             no_emit: false,
+            parent_name: None,
+            anonymous: false,
 
             item_ref: None,
         }))
@@ -76,8 +87,40 @@ pub fn parse(
             name,
             kind: AstSymbolKind::Label,
             no_emit: false,
+            parent_name: None,
+            anonymous: false,
 
             item_ref: None,
         }))
     }
+}
+
+
+pub fn parse_anonymous(
+    report: &mut diagn::Report,
+    walker: &mut syntax::Walker)
+    -> Result<asm::AstAny, ()>
+{
+    let tk_sign = {
+        match walker.maybe_expect(syntax::TokenKind::Plus)
+        {
+            Some(tk_plus) => tk_plus,
+            None => walker.expect(report, syntax::TokenKind::Minus)?,
+        }
+    };
+
+    let name = walker.get_span_excerpt(tk_sign.span).to_string();
+    walker.expect_linebreak(report)?;
+
+    Ok(asm::AstAny::Symbol(AstSymbol {
+        decl_span: tk_sign.span,
+        hierarchy_level: 0,
+        name,
+        kind: AstSymbolKind::Label,
+        no_emit: true,
+        parent_name: None,
+        anonymous: true,
+
+        item_ref: None,
+    }))
 }
\ No newline at end of file
@@ -0,0 +1,139 @@
+use crate::*;
+
+
+/// Timing information collected during assembly,
+/// when `AssemblyOptions::profile` is set.
+#[derive(Debug)]
+pub struct AssemblyProfile
+{
+    /// Time spent in each phase, in the order each
+    /// phase was first run. Phases that run multiple
+    /// times accumulate into a single entry.
+    pub phases: Vec<ProfilePhase>,
+
+    /// How many times each node was evaluated
+    /// during iterative resolution
+    pub node_evaluations: std::collections::HashMap<diagn::Span, usize>,
+}
+
+
+#[derive(Debug)]
+pub struct ProfilePhase
+{
+    pub name: &'static str,
+    pub duration: std::time::Duration,
+}
+
+
+/// How many of the most re-evaluated nodes
+/// to list in the summary
+const SUMMARY_NODE_COUNT: usize = 10;
+
+
+impl AssemblyProfile
+{
+    pub fn new() -> AssemblyProfile
+    {
+        AssemblyProfile {
+            phases: Vec::new(),
+            node_evaluations: std::collections::HashMap::new(),
+        }
+    }
+
+
+    pub fn add_phase_time(
+        &mut self,
+        name: &'static str,
+        duration: std::time::Duration)
+    {
+        match self.phases.iter_mut().find(|p| p.name == name)
+        {
+            Some(phase) => phase.duration += duration,
+            None => self.phases.push(ProfilePhase {
+                name,
+                duration,
+            }),
+        }
+    }
+
+
+    pub fn get_phase(
+        &self,
+        name: &str)
+        -> Option<&ProfilePhase>
+    {
+        self.phases
+            .iter()
+            .find(|p| p.name == name)
+    }
+
+
+    pub fn count_evaluation(
+        &mut self,
+        span: diagn::Span)
+    {
+        *self.node_evaluations
+            .entry(span)
+            .or_insert(0) += 1;
+    }
+
+
+    pub fn format_summary(
+        &self,
+        fileserver: &dyn util::FileServer)
+        -> String
+    {
+        let mut result = String::new();
+
+        result.push_str("profile:\n");
+
+        for phase in &self.phases
+        {
+            result.push_str(&format!(
+                "  {:<20} {:>10.3} ms\n",
+                phase.name,
+                phase.duration.as_secs_f64() * 1000.0));
+        }
+
+        let mut nodes = self.node_evaluations
+            .iter()
+            .collect::<Vec<_>>();
+
+        // Most evaluated first, then in source order
+        nodes.sort_by_key(|(span, count)| {
+            (std::cmp::Reverse(**count), span.file_handle, span.location())
+        });
+
+        result.push_str("most evaluated nodes:\n");
+
+        for (span, count) in nodes.iter().take(SUMMARY_NODE_COUNT)
+        {
+            result.push_str(&format!(
+                "  {:>6}x  {}: {}\n",
+                count,
+                fileserver.get_filename(span.file_handle),
+                fileserver.get_excerpt(**span).trim()));
+        }
+
+        result
+    }
+}
+
+
+impl asm::AssemblyResult
+{
+    /// Adds the time elapsed since `start` to the given
+    /// phase, if profiling, and then restarts the timer.
+    pub fn end_profile_phase(
+        &mut self,
+        name: &'static str,
+        start: &mut std::time::Instant)
+    {
+        if let Some(ref mut profile) = self.profile
+        {
+            profile.add_phase_time(name, start.elapsed());
+        }
+
+        *start = std::time::Instant::now();
+    }
+}
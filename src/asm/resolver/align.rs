@@ -13,6 +13,17 @@ pub fn resolve_align(
 {
     let item_ref = ast_align.item_ref.unwrap();
 
+    // Alignment affects the layout itself,
+    // so its boundary can't depend on addresses
+    if ctx.is_first_iteration
+    {
+        check_layout_independent(
+            report,
+            &ast_align.expr,
+            decls,
+            ctx)?;
+    }
+
     let value = asm::resolver::eval(
         report,
         opts,
@@ -39,9 +50,39 @@ pub fn resolve_align(
         }
     };
 
+    let fill_value = {
+        match ast_align.fill_expr
+        {
+            None => None,
+            Some(ref fill_expr) => Some(asm::resolver::res::resolve_fill_value(
+                report,
+                opts,
+                fileserver,
+                fill_expr,
+                decls,
+                defs,
+                ctx)?),
+        }
+    };
+
     let align = defs.align_directives.get_mut(item_ref);
     let prev_value = align.align_size.clone();
+    let prev_fill_value = align.fill_value.take();
     align.align_size = value;
+    align.fill_value = fill_value;
+
+
+    if align.fill_value != prev_fill_value
+    {
+        if ctx.is_last_iteration
+        {
+            report.error_span(
+                "fill value did not converge",
+                ast_align.fill_expr.as_ref().unwrap().span());
+        }
+
+        return Ok(asm::ResolutionState::Unresolved);
+    }
 
 
     if align.align_size != prev_value
@@ -77,4 +118,52 @@ pub fn resolve_align(
 
 
     Ok(asm::ResolutionState::Resolved)
+}
+
+
+fn check_layout_independent(
+    report: &mut diagn::Report,
+    expr: &expr::Expr,
+    decls: &asm::ItemDecls,
+    ctx: &asm::ResolverContext)
+    -> Result<(), ()>
+{
+    let maybe_span = expr.find_variable(&mut |hierarchy_level, hierarchy|
+    {
+        if hierarchy_level == 0 &&
+            (hierarchy[0] == "$" || hierarchy[0] == "pc")
+        {
+            return true;
+        }
+
+        let maybe_symbol_ref = decls.symbols.try_get_by_name(
+            ctx.symbol_ctx,
+            hierarchy_level,
+            hierarchy);
+
+        match maybe_symbol_ref
+        {
+            Some(symbol_ref) => matches!(
+                decls.symbols.get(symbol_ref).kind,
+                util::SymbolKind::Label),
+            None => false,
+        }
+    });
+
+    if let Some(span) = maybe_span
+    {
+        report.push_parent(
+            "alignment boundary must be a constant",
+            expr.span());
+
+        report.note_span(
+            "depends on an address here",
+            span);
+
+        report.pop_parent();
+
+        return Err(());
+    }
+
+    Ok(())
 }
\ No newline at end of file
@@ -30,12 +30,50 @@ pub fn resolve_assert(
         report,
         ast_assert.condition_expr.span())?;
 
+    // Check the message even when the assertion holds,
+    // so that an invalid one doesn't go unnoticed
+    let maybe_message = {
+        match ast_assert.message_expr
+        {
+            None => Ok(None),
+
+            Some(ref message_expr) =>
+            {
+                asm::resolver::eval(
+                        report,
+                        opts,
+                        fileserver,
+                        decls,
+                        defs,
+                        ctx,
+                        &mut expr::EvalContext::new(),
+                        message_expr)
+                    .and_then(|message| message
+                        .expect_string(report, message_expr.span())
+                        .map(|s| Some(s.utf8_contents.clone())))
+            }
+        }
+    };
+
     if !satisfied
     {
-        report.error_span(
-            "assertion failed",
-            ast_assert.condition_expr.span());
+        match maybe_message
+        {
+            Ok(Some(ref message)) =>
+                report.error_span(
+                    format!(
+                        "assertion failed: {}",
+                        message),
+                    ast_assert.condition_expr.span()),
+
+            _ =>
+                report.error_span(
+                    "assertion failed",
+                    ast_assert.condition_expr.span()),
+        }
     }
+
+    maybe_message?;
     
     Ok(asm::ResolutionState::Resolved)
 }
\ No newline at end of file
@@ -13,6 +13,10 @@ pub fn resolve_data_element(
     -> Result<asm::ResolutionState, ()>
 {
     let item_ref = ast_data.item_refs[elem_index];
+    let data_elem = defs.data_elems.get_mut(item_ref);
+    data_elem.bank_ref = Some(ctx.bank_ref);
+    data_elem.position_within_bank = Some(ctx.bank_data.cur_position);
+
     let data_elem = defs.data_elems.get(item_ref);
 
     if data_elem.resolved
@@ -120,6 +124,11 @@ pub fn resolve_data_element(
     }
 
 
+    let is_negative = maybe_encoding
+        .as_ref()
+        .map_or(false, |e| e.sign() < 0);
+
+
     // Apply definite size via slice
     let maybe_encoding = {
         maybe_encoding.map(|e|
@@ -143,6 +152,7 @@ pub fn resolve_data_element(
     if let Some(ref encoding) = maybe_encoding
     {
         data_elem.encoding = encoding.clone();
+        data_elem.negative = is_negative;
 
         // Optimize future iterations for the case where it's
         // statically known that the encoding can be resolved
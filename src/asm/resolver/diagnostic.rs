@@ -0,0 +1,53 @@
+use crate::*;
+
+
+/// Reports a `#warning` or `#error` once everything else
+/// is resolved, so that ones in untaken `#if` branches
+/// are never reported
+pub fn resolve_diagnostic(
+    report: &mut diagn::Report,
+    opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    ast_diagnostic: &asm::AstDirectiveDiagnostic,
+    decls: &asm::ItemDecls,
+    defs: &mut asm::ItemDefs,
+    ctx: &asm::ResolverContext)
+    -> Result<asm::ResolutionState, ()>
+{
+    if !ctx.is_last_iteration
+    {
+        return Ok(asm::ResolutionState::Unresolved);
+    }
+
+    let value = asm::resolver::eval(
+        report,
+        opts,
+        fileserver,
+        decls,
+        defs,
+        ctx,
+        &mut expr::EvalContext::new(),
+        &ast_diagnostic.message_expr)?;
+
+    let message = value.expect_string(
+        report,
+        ast_diagnostic.message_expr.span())?;
+
+    let span = ast_diagnostic.header_span
+        .join(ast_diagnostic.message_expr.span());
+
+    match ast_diagnostic.kind
+    {
+        diagn::MessageKind::Warning =>
+        {
+            report.warning_span(&message.utf8_contents, span);
+            Ok(asm::ResolutionState::Resolved)
+        }
+
+        _ =>
+        {
+            report.error_span(&message.utf8_contents, span);
+            Err(())
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::*;
+
+
+/// Replaces every instruction that consists solely of an
+/// `#alias` name with the instruction text it stands for.
+pub fn expand_aliases(
+    report: &mut diagn::Report,
+    ast: &mut asm::AstTopLevel)
+    -> Result<(), ()>
+{
+    let mut aliases = std::collections::HashMap::<String, asm::AstDirectiveAlias>::new();
+
+    for node in &ast.nodes
+    {
+        let asm::AstAny::DirectiveAlias(node) = node
+            else { continue };
+
+        if let Some(prev_alias) = aliases.get(&node.name)
+        {
+            report.push_parent(
+                format!("duplicate alias `{}`", node.name),
+                node.name_span);
+
+            report.note_span(
+                "first declared here",
+                prev_alias.name_span);
+
+            report.pop_parent();
+
+            return Err(());
+        }
+
+        aliases.insert(node.name.clone(), node.clone());
+    }
+
+    if aliases.len() == 0
+    {
+        return Ok(());
+    }
+
+
+    for node in &mut ast.nodes
+    {
+        let asm::AstAny::Instruction(ast_instr) = node
+            else { continue };
+
+        let Some(name) = get_alias_name(&ast_instr.src, ast_instr.span)
+            else { continue };
+
+        let mut expanded: Vec<&str> = Vec::new();
+        let mut maybe_alias = aliases.get(&name);
+
+        while let Some(alias) = maybe_alias
+        {
+            if expanded.contains(&alias.name.as_str())
+            {
+                report.push_parent(
+                    format!("alias `{}` expands to itself", name),
+                    ast_instr.span);
+
+                report.note_span(
+                    format!("while expanding alias `{}`", alias.name),
+                    alias.header_span);
+
+                report.pop_parent();
+
+                return Err(());
+            }
+
+            expanded.push(&alias.name);
+
+            ast_instr.src = alias.target_src.clone();
+            ast_instr.alias_target_span = Some(alias.target_span);
+
+            maybe_alias = get_alias_name(&alias.target_src, alias.target_span)
+                .and_then(|next_name| aliases.get(&next_name));
+        }
+    }
+
+    Ok(())
+}
+
+
+/// Returns the lowercased mnemonic of an instruction
+/// that has nothing else besides it.
+fn get_alias_name(
+    src: &str,
+    span: diagn::Span)
+    -> Option<String>
+{
+    let mut walker = syntax::Walker::new(
+        src,
+        span.file_handle,
+        span.location()?.0);
+
+    let mut name = None;
+
+    while !walker.is_over()
+    {
+        let tk = walker.next_token();
+        walker.advance_to_token_end(&tk);
+
+        if tk.kind.is_ignorable()
+        {
+            continue;
+        }
+
+        if tk.kind != syntax::TokenKind::Identifier ||
+            name.is_some()
+        {
+            return None;
+        }
+
+        name = Some(walker.get_span_excerpt(tk.span).to_ascii_lowercase());
+    }
+
+    name
+}
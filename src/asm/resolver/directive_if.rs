@@ -6,7 +6,7 @@ pub fn resolve_ifs(
     opts: &asm::AssemblyOptions,
     fileserver: &mut dyn util::FileServer,
     ast: &mut asm::AstTopLevel,
-    decls: &asm::ItemDecls,
+    decls: &mut asm::ItemDecls,
     defs: &asm::ItemDefs)
     -> Result<usize, ()>
 {
@@ -38,17 +38,30 @@ pub fn resolve_ifs(
         let asm::AstAny::DirectiveIf(node) = ast.nodes.remove(n)
             else { unreachable!() };
         
-        if condition_result
+        let (taken_arm, excluded_arm) = {
+            if condition_result
+                { (Some(node.true_arm), node.false_arm) }
+            else
+                { (node.false_arm, Some(node.true_arm)) }
+        };
+
+        if let Some(excluded_arm) = excluded_arm
         {
-            ast.nodes.splice(
-                n..n,
-                node.true_arm.nodes);
+            let mut symbol_ctx = get_symbol_ctx_at(
+                &ast.nodes[0..n],
+                decls);
+
+            declare_excluded_symbols(
+                &excluded_arm,
+                decls,
+                &mut symbol_ctx);
         }
-        else if let Some(false_arm) = node.false_arm
+
+        if let Some(taken_arm) = taken_arm
         {
             ast.nodes.splice(
                 n..n,
-                false_arm.nodes);
+                taken_arm.nodes);
         }
 
         resolved_count += 1;
@@ -58,6 +71,67 @@ pub fn resolve_ifs(
 }
 
 
+/// Finds the symbol context in effect after the given nodes,
+/// i.e. the context of the last symbol declared among them.
+fn get_symbol_ctx_at(
+    nodes: &[asm::AstAny],
+    decls: &asm::ItemDecls)
+    -> util::SymbolContext
+{
+    for node in nodes.iter().rev()
+    {
+        if let asm::AstAny::Symbol(asm::AstSymbol { item_ref: Some(item_ref), .. }) = node
+        {
+            return decls.symbols.get(*item_ref).ctx.clone();
+        }
+    }
+
+    util::SymbolContext::new_global()
+}
+
+
+/// Records the symbols in a dropped `#if` arm, so that
+/// later references to them get a more helpful message.
+fn declare_excluded_symbols(
+    ast: &asm::AstTopLevel,
+    decls: &mut asm::ItemDecls,
+    symbol_ctx: &mut util::SymbolContext)
+{
+    for node in &ast.nodes
+    {
+        match node
+        {
+            asm::AstAny::Symbol(node) if !node.anonymous =>
+            {
+                decls.symbols.declare_excluded(
+                    node.decl_span,
+                    symbol_ctx,
+                    node.name.clone(),
+                    node.hierarchy_level);
+            }
+
+            asm::AstAny::DirectiveIf(node) =>
+            {
+                declare_excluded_symbols(
+                    &node.true_arm,
+                    decls,
+                    &mut symbol_ctx.clone());
+
+                if let Some(ref false_arm) = node.false_arm
+                {
+                    declare_excluded_symbols(
+                        false_arm,
+                        decls,
+                        &mut symbol_ctx.clone());
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+
 pub fn check_leftover_ifs(
     report: &mut diagn::Report,
     ast: &asm::AstTopLevel,
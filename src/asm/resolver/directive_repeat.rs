@@ -0,0 +1,562 @@
+use crate::*;
+
+
+pub fn resolve_repeats(
+    report: &mut diagn::Report,
+    opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    ast: &mut asm::AstTopLevel,
+    decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs)
+    -> Result<usize, ()>
+{
+    let mut resolved_count = 0;
+
+
+    for n in (0..ast.nodes.len()).rev()
+    {
+        let asm::AstAny::DirectiveRepeat(node) = &ast.nodes[n]
+            else { continue };
+
+        let Some(range) = eval_range(report, decls, defs, node)?
+            else { continue };
+
+        if opts.debug_iterations
+        {
+            println!("  #repeat: {} = {:?}",
+                fileserver.get_excerpt(node.header_span),
+                range);
+        }
+
+        let asm::AstAny::DirectiveRepeat(node) = ast.nodes.remove(n)
+            else { unreachable!() };
+
+        let mut expanded = Vec::new();
+
+        for value in iterate_range(range)
+        {
+            let mut body = node.body.clone();
+
+            if let Some(ref var_name) = node.var_name
+            {
+                let value = util::BigInt::from(value);
+
+                for body_node in &mut body.nodes
+                {
+                    substitute_in_node(
+                        body_node,
+                        var_name,
+                        &value);
+                }
+            }
+
+            expanded.extend(body.nodes);
+        }
+
+        ast.nodes.splice(
+            n..n,
+            expanded);
+
+        resolved_count += 1;
+    }
+
+    Ok(resolved_count)
+}
+
+
+pub fn check_leftover_repeats(
+    report: &mut diagn::Report,
+    ast: &asm::AstTopLevel,
+    decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs)
+    -> Result<(), ()>
+{
+    for node in &ast.nodes
+    {
+        let asm::AstAny::DirectiveRepeat(node) = node
+            else { continue };
+
+        let exprs = node.start_expr
+            .iter()
+            .chain(std::iter::once(&node.end_expr))
+            .chain(node.step_expr.iter());
+
+        for expr in exprs
+        {
+            report.push_parent(
+                "unresolved `#repeat` range",
+                expr.span());
+
+            let result =
+                asm::resolver::eval_certain(
+                    report,
+                    decls,
+                    defs,
+                    expr);
+
+            report.pop_parent();
+
+            if let Ok(_) = result
+            {
+                continue;
+            }
+
+            return Err(());
+        }
+
+        report.error_span(
+            "unresolved `#repeat` range",
+            node.header_span);
+
+        return Err(());
+    }
+
+    Ok(())
+}
+
+
+/// The start, end (exclusive), and step of a `#repeat`.
+type RepeatRange = (i64, i64, i64);
+
+
+fn eval_range(
+    report: &mut diagn::Report,
+    decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    node: &asm::AstDirectiveRepeat)
+    -> Result<Option<RepeatRange>, ()>
+{
+    let start = {
+        match node.start_expr
+        {
+            Some(ref expr) => eval_range_bound(report, decls, defs, expr)?,
+            None => Some(0),
+        }
+    };
+
+    let end = eval_range_bound(report, decls, defs, &node.end_expr)?;
+
+    let step = {
+        match node.step_expr
+        {
+            Some(ref expr) => eval_range_bound(report, decls, defs, expr)?,
+            None => Some(1),
+        }
+    };
+
+    let (Some(start), Some(end), Some(step)) = (start, end, step)
+        else { return Ok(None) };
+
+    if step == 0
+    {
+        report.error_span(
+            "`#repeat` step cannot be zero",
+            node.step_expr.as_ref().unwrap().span());
+
+        return Err(());
+    }
+
+    if node.start_expr.is_none() && end < 0
+    {
+        report.error_span(
+            "`#repeat` count cannot be negative",
+            node.end_expr.span());
+
+        return Err(());
+    }
+
+    let iterations = count_iterations((start, end, step));
+
+    if iterations > MAX_ITERATIONS
+    {
+        report.error_span(
+            format!(
+                "`#repeat` of {} iterations exceeds the limit of {}",
+                iterations,
+                MAX_ITERATIONS),
+            node.header_span);
+
+        return Err(());
+    }
+
+    Ok(Some((start, end, step)))
+}
+
+
+/// Guards against expanding a mistyped count,
+/// which would otherwise exhaust the memory
+const MAX_ITERATIONS: u64 = 1 << 20;
+
+
+fn count_iterations(
+    (start, end, step): RepeatRange)
+    -> u64
+{
+    let (start, end, step) = (start as i128, end as i128, step as i128);
+
+    let distance = {
+        if step > 0
+            { end - start }
+        else
+            { start - end }
+    };
+
+    if distance <= 0
+    {
+        return 0;
+    }
+
+    let step = step.abs();
+    ((distance + step - 1) / step) as u64
+}
+
+
+fn eval_range_bound(
+    report: &mut diagn::Report,
+    decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    expr: &expr::Expr)
+    -> Result<Option<i64>, ()>
+{
+    let value = asm::resolver::eval_simple(
+        report,
+        decls,
+        defs,
+        expr)?;
+
+    let expr::Value::Integer(bigint) = value
+        else { return Ok(None) };
+
+    Ok(Some(bigint.checked_into::<i64>(
+        report,
+        expr.span())?))
+}
+
+
+/// Yields every value of the range, going downward
+/// when the step is negative.
+fn iterate_range(
+    (start, end, step): RepeatRange)
+    -> impl Iterator<Item = i64>
+{
+    std::iter::successors(
+        Some(start),
+        move |value| value.checked_add(step))
+        .take_while(move |value| {
+            if step > 0
+                { *value < end }
+            else
+                { *value > end }
+        })
+}
+
+
+fn substitute_in_node(
+    node: &mut asm::AstAny,
+    var_name: &str,
+    value: &util::BigInt)
+{
+    match node
+    {
+        asm::AstAny::DirectiveAddr(node) =>
+            substitute_in_expr(&mut node.expr, var_name, value),
+
+        asm::AstAny::DirectiveAlign(node) =>
+        {
+            substitute_in_expr(&mut node.expr, var_name, value);
+
+            if let Some(ref mut expr) = node.fill_expr
+            {
+                substitute_in_expr(expr, var_name, value);
+            }
+        }
+
+        asm::AstAny::DirectiveAssert(node) =>
+        {
+            substitute_in_expr(&mut node.condition_expr, var_name, value);
+
+            if let Some(ref mut expr) = node.message_expr
+            {
+                substitute_in_expr(expr, var_name, value);
+            }
+        }
+
+        asm::AstAny::DirectiveDiagnostic(node) =>
+            substitute_in_expr(&mut node.message_expr, var_name, value),
+
+        asm::AstAny::DirectiveBank(node) =>
+            substitute_in_bank_name(
+                &mut node.name,
+                &mut node.name_index_var,
+                var_name,
+                value),
+
+        asm::AstAny::DirectiveBankdef(node) =>
+        {
+            substitute_in_bank_name(
+                &mut node.name,
+                &mut node.name_index_var,
+                var_name,
+                value);
+
+            let exprs = [
+                &mut node.addr_unit,
+                &mut node.label_align,
+                &mut node.addr_start,
+                &mut node.addr_end,
+                &mut node.addr_size,
+                &mut node.output_offset,
+                &mut node.fill_pattern,
+            ];
+
+            for expr in exprs.into_iter().flatten()
+            {
+                substitute_in_expr(expr, var_name, value);
+            }
+        }
+
+        asm::AstAny::DirectiveData(node) =>
+        {
+            for elem in &mut node.elems
+            {
+                substitute_in_expr(elem, var_name, value);
+            }
+        }
+
+        asm::AstAny::DirectiveIf(node) =>
+        {
+            substitute_in_expr(&mut node.condition_expr, var_name, value);
+
+            for inner in &mut node.true_arm.nodes
+            {
+                substitute_in_node(inner, var_name, value);
+            }
+
+            if let Some(ref mut false_arm) = node.false_arm
+            {
+                for inner in &mut false_arm.nodes
+                {
+                    substitute_in_node(inner, var_name, value);
+                }
+            }
+        }
+
+        asm::AstAny::DirectiveLabelAlign(node) =>
+            substitute_in_expr(&mut node.expr, var_name, value),
+
+        asm::AstAny::DirectiveRepeat(node) =>
+        {
+            if let Some(ref mut expr) = node.start_expr
+            {
+                substitute_in_expr(expr, var_name, value);
+            }
+
+            substitute_in_expr(&mut node.end_expr, var_name, value);
+
+            if let Some(ref mut expr) = node.step_expr
+            {
+                substitute_in_expr(expr, var_name, value);
+            }
+
+            // An inner loop variable with the same name
+            // shadows ours
+            if node.var_name.as_deref() != Some(var_name)
+            {
+                for inner in &mut node.body.nodes
+                {
+                    substitute_in_node(inner, var_name, value);
+                }
+            }
+        }
+
+        asm::AstAny::DirectiveExpectSize(node) =>
+        {
+            substitute_in_instruction(&mut node.instr, var_name, value);
+            substitute_in_expr(&mut node.size_expr, var_name, value);
+        }
+
+        asm::AstAny::DirectivePatch(node) =>
+        {
+            substitute_in_expr(&mut node.addr_expr, var_name, value);
+            substitute_in_expr(&mut node.value_expr, var_name, value);
+        }
+
+        asm::AstAny::DirectiveRes(node) =>
+        {
+            substitute_in_expr(&mut node.expr, var_name, value);
+
+            if let Some(ref mut expr) = node.fill_expr
+            {
+                substitute_in_expr(expr, var_name, value);
+            }
+        }
+
+        asm::AstAny::Instruction(node) =>
+            substitute_in_instruction(node, var_name, value),
+
+        asm::AstAny::Symbol(asm::AstSymbol {
+            kind: asm::AstSymbolKind::Constant(ref mut constant), .. }) =>
+            substitute_in_expr(&mut constant.expr, var_name, value),
+
+        _ => {}
+    }
+}
+
+
+fn substitute_in_expr(
+    expr: &mut expr::Expr,
+    var_name: &str,
+    value: &util::BigInt)
+{
+    match expr
+    {
+        expr::Expr::Variable(span, 0, ref hierarchy)
+            if hierarchy.len() == 1 && hierarchy[0] == var_name =>
+        {
+            *expr = expr::Expr::Literal(
+                *span,
+                expr::Value::make_integer(value.clone()));
+        }
+
+        expr::Expr::Variable(..) |
+        expr::Expr::Literal(..) => {}
+
+        expr::Expr::UnaryOp(_, _, _, ref mut inner) =>
+            substitute_in_expr(inner, var_name, value),
+
+        expr::Expr::BinaryOp(_, _, _, ref mut lhs, ref mut rhs) =>
+        {
+            substitute_in_expr(lhs, var_name, value);
+            substitute_in_expr(rhs, var_name, value);
+        }
+
+        expr::Expr::TernaryOp(_, ref mut condition, ref mut true_branch, ref mut false_branch) =>
+        {
+            substitute_in_expr(condition, var_name, value);
+            substitute_in_expr(true_branch, var_name, value);
+            substitute_in_expr(false_branch, var_name, value);
+        }
+
+        expr::Expr::Slice(_, _, ref mut left_expr, ref mut right_expr, ref mut inner) =>
+        {
+            substitute_in_expr(left_expr, var_name, value);
+            substitute_in_expr(right_expr, var_name, value);
+            substitute_in_expr(inner, var_name, value);
+        }
+
+        expr::Expr::SliceShort(_, _, ref mut size_expr, ref mut inner) =>
+        {
+            substitute_in_expr(size_expr, var_name, value);
+            substitute_in_expr(inner, var_name, value);
+        }
+
+        expr::Expr::Block(_, ref mut exprs) =>
+        {
+            for inner in exprs
+            {
+                substitute_in_expr(inner, var_name, value);
+            }
+        }
+
+        expr::Expr::Call(_, ref mut func, ref mut args) =>
+        {
+            substitute_in_expr(func, var_name, value);
+
+            for arg in args
+            {
+                substitute_in_expr(arg, var_name, value);
+            }
+        }
+
+        expr::Expr::Match(_, ref mut inner, ref mut arms, ref mut else_arm) =>
+        {
+            substitute_in_expr(inner, var_name, value);
+
+            for arm in arms
+            {
+                substitute_in_expr(&mut arm.pattern, var_name, value);
+                substitute_in_expr(&mut arm.value, var_name, value);
+            }
+
+            if let Some(else_arm) = else_arm
+            {
+                substitute_in_expr(else_arm, var_name, value);
+            }
+        }
+
+        expr::Expr::Asm(_, ref mut ast) =>
+        {
+            for inner in &mut ast.nodes
+            {
+                substitute_in_node(inner, var_name, value);
+            }
+        }
+    }
+}
+
+
+/// Appends the loop value to a `#bank` name that indexes it.
+fn substitute_in_bank_name(
+    name: &mut String,
+    name_index_var: &mut Option<String>,
+    var_name: &str,
+    value: &util::BigInt)
+{
+    if name_index_var.as_deref() == Some(var_name)
+    {
+        name.push_str(&format!("{}", value.maybe_into::<i64>().unwrap()));
+        *name_index_var = None;
+    }
+}
+
+
+/// Instructions are only matched later on, so substitute
+/// the variable directly in their source text, like
+/// `asm` blocks do for their arguments.
+fn substitute_in_instruction(
+    node: &mut asm::AstInstruction,
+    var_name: &str,
+    value: &util::BigInt)
+{
+    let value_str = {
+        let value = value.maybe_into::<i64>().unwrap();
+
+        if value < 0
+            { format!("({})", value) }
+        else
+            { format!("{}", value) }
+    };
+
+    let mut new_src = String::new();
+    let mut copied_up_to = 0;
+    let mut prev_useful_kind = None;
+
+    let mut walker = syntax::Walker::new(
+        &node.src,
+        node.span.file_handle,
+        node.span.location().unwrap().0);
+
+    while !walker.is_over()
+    {
+        let tk = walker.next_token();
+        walker.advance_to_token_end(&tk);
+
+        if tk.kind == syntax::TokenKind::Identifier &&
+            prev_useful_kind != Some(syntax::TokenKind::Dot) &&
+            walker.get_span_excerpt(tk.span) == var_name
+        {
+            let start = walker.get_index_at_span_start(tk.span);
+            new_src.push_str(&node.src[copied_up_to..start]);
+            new_src.push_str(&value_str);
+            copied_up_to = walker.get_cursor_index();
+        }
+
+        if !tk.kind.is_ignorable()
+        {
+            prev_useful_kind = Some(tk.kind);
+        }
+    }
+
+    new_src.push_str(&node.src[copied_up_to..]);
+    node.src = new_src;
+}
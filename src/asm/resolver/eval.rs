@@ -230,6 +230,14 @@ pub fn eval_variable_simple(
         query.hierarchy_level,
         query.hierarchy);
 
+    if symbol_ref.is_none()
+    {
+        if let Some(builtin) = expr::resolve_builtin_fn_variable(query)
+        {
+            return Ok(builtin);
+        }
+    }
+
     match symbol_ref
         .map(|s| defs.symbols.maybe_get(s))
         .flatten()
@@ -263,6 +271,19 @@ pub fn eval_variable_certain(
         }
     }
 
+    let maybe_symbol_ref = decls.symbols.try_get_by_name(
+        &util::SymbolContext::new_global(),
+        query.hierarchy_level,
+        query.hierarchy);
+
+    if maybe_symbol_ref.is_none()
+    {
+        if let Some(builtin) = expr::resolve_builtin_fn_variable(query)
+        {
+            return Ok(builtin);
+        }
+    }
+
     let symbol_ref = decls.symbols.get_by_name(
         query.report,
         query.span,
@@ -298,7 +319,7 @@ pub fn eval_variable_certain(
 
 
 fn eval_builtin_symbol(
-    _decls: &asm::ItemDecls,
+    decls: &asm::ItemDecls,
     defs: &asm::ItemDefs,
     ctx: &asm::ResolverContext,
     query: &mut expr::EvalVariableQuery,
@@ -318,13 +339,23 @@ fn eval_builtin_symbol(
 
         _ =>
         {
-            if let Some(_) = asm::resolver::resolve_builtin_fn(name)
+            // User symbols take precedence over builtin functions
+            let maybe_symbol_ref = decls.symbols.try_get_by_name(
+                ctx.symbol_ctx,
+                query.hierarchy_level,
+                query.hierarchy);
+
+            if maybe_symbol_ref.is_some()
+            {
+                Ok(None)
+            }
+            else if let Some(_) = asm::resolver::resolve_builtin_fn(name)
             {
                 Ok(Some(expr::Value::AsmBuiltInFunction(name.to_string())))
             }
             else
             {
-                Ok(None)
+                Ok(expr::resolve_builtin_fn_variable(query))
             }
         }
     }
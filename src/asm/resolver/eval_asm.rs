@@ -42,7 +42,9 @@ pub fn eval_asm(
                 opts,
                 defs,
                 ast_instr.span,
-                &new_excerpt);
+                &new_excerpt,
+                None,
+                &[]);
 
 
             let attempted_match_excerpt = {
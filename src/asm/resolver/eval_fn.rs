@@ -2,6 +2,7 @@ use crate::*;
 
 
 type BuiltinFn = fn(
+    opts: &asm::AssemblyOptions,
     fileserver: &mut dyn util::FileServer,
     decls: &asm::ItemDecls,
     defs: &asm::ItemDefs,
@@ -17,8 +18,17 @@ pub fn resolve_builtin_fn(
     match name
     {
         "incbin" => Some(eval_builtin_incbin),
+        "incbin16be" => Some(eval_builtin_incbin16be),
+        "incbin16le" => Some(eval_builtin_incbin16le),
+        "incbin32be" => Some(eval_builtin_incbin32be),
+        "incbin32le" => Some(eval_builtin_incbin32le),
         "incbinstr" => Some(eval_builtin_incbinstr),
         "inchexstr" => Some(eval_builtin_inchexstr),
+        "builddate" => Some(eval_builtin_builddate),
+        "aligned" => Some(eval_builtin_aligned),
+        "here" => Some(eval_builtin_here),
+        "checksum_excluding" => Some(eval_builtin_checksum_excluding),
+        "lookup" => Some(eval_builtin_lookup),
         _ => None,
     }
 }
@@ -31,8 +41,13 @@ pub fn get_statically_known_builtin_fn(
     match query.func.as_ref()
     {
         "incbin" => true,
+        "incbin16be" => true,
+        "incbin16le" => true,
+        "incbin32be" => true,
+        "incbin32le" => true,
         "incbinstr" => true,
         "inchexstr" => true,
+        "builddate" => true,
         _ => false,
     }
 }
@@ -52,6 +67,7 @@ pub fn eval_fn(
         let builtin_fn = resolve_builtin_fn(name).unwrap();
 
         builtin_fn(
+            opts,
             fileserver,
             decls,
             defs,
@@ -116,12 +132,149 @@ pub fn eval_fn(
 
 
 fn eval_builtin_incbin(
+    _opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    _decls: &asm::ItemDecls,
+    _defs: &asm::ItemDefs,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    let bytes = read_incbin_bytes(
+        fileserver,
+        ctx,
+        query)?;
+
+    Ok(expr::Value::make_integer(
+        util::BigInt::from_bytes_be(&bytes)))
+}
+
+
+fn eval_builtin_incbin16be(
+    _opts: &asm::AssemblyOptions,
     fileserver: &mut dyn util::FileServer,
     _decls: &asm::ItemDecls,
     _defs: &asm::ItemDefs,
     ctx: &asm::ResolverContext,
     query: &mut expr::EvalFunctionQuery)
     -> Result<expr::Value, ()>
+{
+    eval_builtin_incbin_words(
+        fileserver,
+        2,
+        true,
+        ctx,
+        query,
+        "incbin16be")
+}
+
+
+fn eval_builtin_incbin16le(
+    _opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    _decls: &asm::ItemDecls,
+    _defs: &asm::ItemDefs,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    eval_builtin_incbin_words(
+        fileserver,
+        2,
+        false,
+        ctx,
+        query,
+        "incbin16le")
+}
+
+
+fn eval_builtin_incbin32be(
+    _opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    _decls: &asm::ItemDecls,
+    _defs: &asm::ItemDefs,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    eval_builtin_incbin_words(
+        fileserver,
+        4,
+        true,
+        ctx,
+        query,
+        "incbin32be")
+}
+
+
+fn eval_builtin_incbin32le(
+    _opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    _decls: &asm::ItemDecls,
+    _defs: &asm::ItemDefs,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    eval_builtin_incbin_words(
+        fileserver,
+        4,
+        false,
+        ctx,
+        query,
+        "incbin32le")
+}
+
+
+/// Reads the file as a sequence of little-endian words,
+/// and re-emits them in the target endianness, i.e. swaps
+/// the bytes within every word for a big-endian target
+/// and copies them unchanged for a little-endian one
+fn eval_builtin_incbin_words(
+    fileserver: &mut dyn util::FileServer,
+    bytes_per_word: usize,
+    big_endian: bool,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery,
+    fn_name: &str)
+    -> Result<expr::Value, ()>
+{
+    let mut bytes = read_incbin_bytes(
+        fileserver,
+        ctx,
+        query)?;
+
+    if bytes.len() % bytes_per_word != 0
+    {
+        query.report.error_span(
+            format!(
+                "`{}` data size is not a multiple of {} bytes (got {})",
+                fn_name,
+                bytes_per_word,
+                bytes.len()),
+            query.span);
+        
+        return Err(());
+    }
+
+    if big_endian
+    {
+        for word in bytes.chunks_mut(bytes_per_word)
+        {
+            word.reverse();
+        }
+    }
+
+    Ok(expr::Value::make_integer(
+        util::BigInt::from_bytes_be(&bytes)))
+}
+
+
+fn read_incbin_bytes(
+    fileserver: &mut dyn util::FileServer,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<Vec<u8>, ()>
 {
     query.ensure_min_max_arg_number(1, 3)?;
 
@@ -129,13 +282,13 @@ fn eval_builtin_incbin(
         query.report,
         query.args[0].span)?;
 
-    let filename_ctx = fileserver.get_filename(
+    let filename_ctx = fileserver.get_navigation_path(
         ctx.file_handle_ctx.unwrap());
 
     let absolute_filename = util::filename_navigate(
         query.report,
         query.args[0].span,
-        filename_ctx,
+        &filename_ctx,
         &relative_filename.utf8_contents)?;
 
     let file_handle = fileserver.get_handle(
@@ -199,12 +352,12 @@ fn eval_builtin_incbin(
         return Err(());
     }
 
-    Ok(expr::Value::make_integer(
-        util::BigInt::from_bytes_be(&bytes[start..end])))
+    Ok(bytes[start..end].to_vec())
 }
 
 
 fn eval_builtin_incbinstr(
+    _opts: &asm::AssemblyOptions,
     fileserver: &mut dyn util::FileServer,
     decls: &asm::ItemDecls,
     defs: &asm::ItemDefs,
@@ -224,6 +377,7 @@ fn eval_builtin_incbinstr(
 
 
 fn eval_builtin_inchexstr(
+    _opts: &asm::AssemblyOptions,
     fileserver: &mut dyn util::FileServer,
     decls: &asm::ItemDecls,
     defs: &asm::ItemDefs,
@@ -258,13 +412,13 @@ fn eval_builtin_incstr(
         query.report,
         query.args[0].span)?;
 
-    let filename_ctx = fileserver.get_filename(
+    let filename_ctx = fileserver.get_navigation_path(
         ctx.file_handle_ctx.unwrap());
     
     let absolute_filename = util::filename_navigate(
         query.report,
         query.args[0].span,
-        filename_ctx,
+        &filename_ctx,
         &relative_filename.utf8_contents)?;
 
     let file_handle = fileserver.get_handle(
@@ -374,3 +528,287 @@ fn eval_builtin_incstr(
             bigint_size - (start * bits_per_char),
             bigint_size - (end * bits_per_char))))
 }
+
+
+fn eval_builtin_builddate(
+    opts: &asm::AssemblyOptions,
+    _fileserver: &mut dyn util::FileServer,
+    _decls: &asm::ItemDecls,
+    _defs: &asm::ItemDefs,
+    _ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    query.ensure_arg_number(0)?;
+
+    let build_date = opts.build_date
+        .unwrap_or(asm::BUILD_DATE_SENTINEL);
+
+    Ok(expr::Value::make_integer(
+        util::BigInt::new(build_date, Some(64))))
+}
+
+
+/// Whether the current output position is a multiple
+/// of the given number of bits, counting from address zero.
+fn eval_builtin_aligned(
+    _opts: &asm::AssemblyOptions,
+    _fileserver: &mut dyn util::FileServer,
+    _decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    query.ensure_arg_number(1)?;
+
+    let alignment = query.args[0].value.expect_nonzero_usize(
+        query.report,
+        query.args[0].span)?;
+
+    let bankdef = defs.bankdefs.get(ctx.bank_ref);
+
+    let bit_position = bankdef.addr_start
+        .checked_mul(
+            query.report,
+            query.span,
+            &util::BigInt::from(bankdef.addr_unit))?
+        .checked_add(
+            query.report,
+            query.span,
+            &util::BigInt::from(ctx.bank_data.cur_position))?;
+
+    let excess_bits = bit_position.checked_mod(
+        query.report,
+        query.span,
+        &util::BigInt::from(alignment))?;
+
+    Ok(expr::Value::make_bool(excess_bits.sign() == 0))
+}
+
+
+/// The current address, same as `$`.
+fn eval_builtin_here(
+    _opts: &asm::AssemblyOptions,
+    _fileserver: &mut dyn util::FileServer,
+    _decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    query.ensure_arg_number(0)?;
+
+    Ok(expr::Value::Integer(ctx.eval_address(
+        query.report,
+        query.span,
+        defs,
+        ctx.can_guess())?))
+}
+
+
+/// The 8-bit sum of the bytes emitted to the current bank
+/// from address `start` up to, but excluding, `end`, taking
+/// the `self_width` address units at `self_addr` as zero.
+/// This lets a checksum lie within the range it covers.
+fn eval_builtin_checksum_excluding(
+    _opts: &asm::AssemblyOptions,
+    _fileserver: &mut dyn util::FileServer,
+    _decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    query.ensure_arg_number(4)?;
+
+    let bankdef = defs.bankdefs.get(ctx.bank_ref);
+
+    let start = get_bank_position_from_addr(
+        defs,
+        ctx,
+        query,
+        0)?;
+
+    let end = get_bank_position_from_addr(
+        defs,
+        ctx,
+        query,
+        1)?;
+
+    let self_start = get_bank_position_from_addr(
+        defs,
+        ctx,
+        query,
+        2)?;
+
+    let self_width = query.args[3].value.expect_usize(
+        query.report,
+        query.args[3].span)?;
+
+    let self_end = self_start + self_width * bankdef.addr_unit;
+
+    if end < start
+    {
+        query.report.error_span(
+            "end address is before start address",
+            query.args[1].span);
+
+        return Err(());
+    }
+
+    // Gather the bank's contents as of the
+    // latest resolution iteration
+    let mut contents = util::BitVec::new();
+
+    for instr in defs.instructions.defs.iter().flatten()
+    {
+        if let (Some(bank_ref), Some(pos)) =
+            (instr.bank_ref, instr.position_within_bank)
+        {
+            if bank_ref.0 == ctx.bank_ref.0
+            {
+                contents.write_bigint(pos, &instr.encoding);
+            }
+        }
+    }
+
+    for elem in defs.data_elems.defs.iter().flatten()
+    {
+        if let (Some(bank_ref), Some(pos)) =
+            (elem.bank_ref, elem.position_within_bank)
+        {
+            if bank_ref.0 == ctx.bank_ref.0
+            {
+                contents.write_bigint(pos, &elem.encoding);
+            }
+        }
+    }
+
+    let mut sum: u8 = 0;
+
+    for pos in (start..end).step_by(8)
+    {
+        let mut byte: u8 = 0;
+
+        for bit in pos..std::cmp::min(pos + 8, end)
+        {
+            byte <<= 1;
+
+            let is_self = bit >= self_start && bit < self_end;
+
+            if !is_self && bit < contents.len() && contents.read_bit(bit)
+            {
+                byte |= 1;
+            }
+        }
+
+        sum = sum.wrapping_add(byte);
+    }
+
+    Ok(expr::Value::make_integer(sum))
+}
+
+
+/// The element at `index` of the `#d` directive whose
+/// data starts at address `table` in the current bank.
+fn eval_builtin_lookup(
+    _opts: &asm::AssemblyOptions,
+    _fileserver: &mut dyn util::FileServer,
+    _decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    query.ensure_arg_number(2)?;
+
+    let table_pos = get_bank_position_from_addr(
+        defs,
+        ctx,
+        query,
+        0)?;
+
+    let index = query.args[1].value.expect_usize(
+        query.report,
+        query.args[1].span)?;
+
+    let maybe_first_elem = defs.data_elems.defs
+        .iter()
+        .flatten()
+        .find(|elem|
+            elem.bank_ref.map(|r| r.0) == Some(ctx.bank_ref.0) &&
+            elem.position_within_bank == Some(table_pos));
+
+    let Some(first_elem) = maybe_first_elem
+        else
+        {
+            // The table might not have been placed yet
+            if ctx.can_guess()
+            {
+                return Ok(expr::Value::make_integer(0));
+            }
+
+            query.report.error_span(
+                "no data table at the given address",
+                query.args[0].span);
+
+            return Err(());
+        };
+
+    let table_len = first_elem.block_len - first_elem.index_in_block;
+
+    if index >= table_len
+    {
+        query.report.error_span(
+            format!(
+                "index {} is out of range for a table of {} elements",
+                index,
+                table_len),
+            query.args[1].span);
+
+        return Err(());
+    }
+
+    let elem = defs.data_elems.get(
+        util::ItemRef::new(first_elem.item_ref.0 + index));
+
+    Ok(expr::Value::make_integer(elem.encoding.clone()))
+}
+
+
+/// Converts the address given in an argument to a
+/// bit position within the current bank.
+fn get_bank_position_from_addr(
+    defs: &asm::ItemDefs,
+    ctx: &asm::ResolverContext,
+    query: &mut expr::EvalFunctionQuery,
+    arg_index: usize)
+    -> Result<usize, ()>
+{
+    let bankdef = defs.bankdefs.get(ctx.bank_ref);
+
+    let addr = query.args[arg_index].value.expect_bigint(
+        query.report,
+        query.args[arg_index].span)?;
+
+    let addr_offset = addr.checked_sub(
+        query.report,
+        query.args[arg_index].span,
+        &bankdef.addr_start)?;
+
+    if addr_offset.sign() < 0
+    {
+        query.report.error_span(
+            "address is before the start of the current bank",
+            query.args[arg_index].span);
+
+        return Err(());
+    }
+
+    let addr_offset = addr_offset.checked_into::<usize>(
+        query.report,
+        query.args[arg_index].span)?;
+
+    Ok(addr_offset * bankdef.addr_unit)
+}
@@ -0,0 +1,100 @@
+use crate::*;
+
+
+pub fn resolve_expect_size(
+    report: &mut diagn::Report,
+    opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    ast_expect_size: &asm::AstDirectiveExpectSize,
+    decls: &asm::ItemDecls,
+    defs: &mut asm::ItemDefs,
+    ctx: &asm::ResolverContext)
+    -> Result<asm::ResolutionState, ()>
+{
+    if !ctx.is_last_iteration
+    {
+        return Ok(asm::ResolutionState::Unresolved);
+    }
+
+    let ast_instr = &ast_expect_size.instr;
+
+    let using_ruledefs = asm::matcher::get_using_ruledefs(
+        report,
+        decls,
+        ast_instr)?;
+
+    let disabled_rules = defs.expect_size_directives
+        .get(ast_expect_size.item_ref.unwrap())
+        .disabled_rules
+        .clone();
+
+    let mut matches = asm::matcher::match_instr(
+        opts,
+        defs,
+        ast_instr.span,
+        &ast_instr.src,
+        using_ruledefs.as_deref(),
+        &disabled_rules);
+
+    asm::matcher::error_on_no_matches(
+        report,
+        ast_instr.span,
+        &matches)?;
+
+    let maybe_encodings = asm::resolver::instruction::resolve_encoding(
+        report,
+        opts,
+        ast_instr.span,
+        fileserver,
+        &mut matches,
+        decls,
+        defs,
+        ctx,
+        &mut expr::EvalContext::new())?;
+
+    let Some(encodings) = maybe_encodings
+        else { return Err(()) };
+
+    let size = encodings[0].1.size.unwrap();
+
+    let expected_size = asm::resolver::eval(
+        report,
+        opts,
+        fileserver,
+        decls,
+        defs,
+        ctx,
+        &mut expr::EvalContext::new(),
+        &ast_expect_size.size_expr)?
+        .expect_usize(
+            report,
+            ast_expect_size.size_expr.span())?;
+
+    if size != expected_size * 8
+    {
+        report.push_parent(
+            "instruction size mismatch",
+            ast_instr.span);
+
+        report.note(
+            format!(
+                "expected {} byte{}, got {}",
+                expected_size,
+                if expected_size == 1 { "" } else { "s" },
+                if size % 8 == 0
+                {
+                    format!(
+                        "{} byte{}",
+                        size / 8,
+                        if size == 8 { "" } else { "s" })
+                }
+                else
+                {
+                    format!("{} bits", size)
+                }));
+
+        report.pop_parent();
+    }
+
+    Ok(asm::ResolutionState::Resolved)
+}
@@ -12,9 +12,20 @@ pub fn resolve_instruction(
     -> Result<asm::ResolutionState, ()>
 {
     let instr = defs.instructions.get_mut(ast_instr.item_ref.unwrap());
+    instr.bank_ref = Some(ctx.bank_ref);
+    instr.position_within_bank = Some(ctx.bank_data.cur_position);
 
     if instr.resolved
     {
+        if ctx.is_last_iteration
+        {
+            check_nonempty_encoding(
+                report,
+                ast_instr,
+                decls,
+                defs)?;
+        }
+
         return Ok(asm::ResolutionState::Resolved);
     }
 
@@ -43,6 +54,9 @@ pub fn resolve_instruction(
     let maybe_chosen_encoding =
         maybe_encodings.as_ref().map(|e| e[0].1.clone());
 
+    let maybe_chosen_match =
+        maybe_encodings.as_ref().map(|e| e[0].0);
+
     // Reassign matches to satisfy the borrow checker
     let instr = defs.instructions.get_mut(ast_instr.item_ref.unwrap());
     instr.matches = matches;
@@ -57,6 +71,7 @@ pub fn resolve_instruction(
     if let Some(encoding) = maybe_chosen_encoding
     {
         instr.encoding = encoding;
+        instr.chosen_match = maybe_chosen_match;
 
         // Optimize future iterations for the case where it's
         // statically known that the encoding can be resolved
@@ -101,10 +116,62 @@ pub fn resolve_instruction(
     }
 
 
+    if ctx.is_last_iteration
+    {
+        check_nonempty_encoding(
+            report,
+            ast_instr,
+            decls,
+            defs)?;
+    }
+
+
     Ok(asm::ResolutionState::Resolved)
 }
 
 
+/// Reports an error if the instruction was matched against
+/// a `#ruledef(nonempty)` but its encoding came out zero bits wide.
+fn check_nonempty_encoding(
+    report: &mut diagn::Report,
+    ast_instr: &asm::AstInstruction,
+    decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs)
+    -> Result<(), ()>
+{
+    let instr = defs.instructions.get(ast_instr.item_ref.unwrap());
+
+    let Some(chosen_match) = instr.chosen_match
+        else { return Ok(()) };
+
+    let mtch = &instr.matches[chosen_match];
+    let ruledef = defs.ruledefs.get(mtch.ruledef_ref);
+
+    if !ruledef.nonempty ||
+        instr.encoding.size != Some(0)
+    {
+        return Ok(());
+    }
+
+    let rule = ruledef.get_rule(mtch.rule_ref);
+    let ruledef_name = &decls.ruledefs.get(mtch.ruledef_ref).name;
+
+    report.push_parent(
+        "instruction produced no output",
+        ast_instr.span);
+
+    report.note_span(
+        format!(
+            "`{}` is declared `nonempty`, but this rule emitted zero bits",
+            ruledef_name),
+        rule.pattern_span);
+
+    report.pop_parent();
+
+    Err(())
+}
+
+
 pub fn resolve_encoding<'encoding>(
     report: &mut diagn::Report,
     opts: &asm::AssemblyOptions,
@@ -186,12 +253,21 @@ pub fn resolve_encoding<'encoding>(
         .min()
         .unwrap();
 
-    let smallest_encodings = encodings_resolved
+    let mut smallest_encodings = encodings_resolved
         .iter()
         .filter(|e| e.1.size.unwrap() == smallest_size)
         .copied()
         .collect::<Vec<_>>();
-    
+
+    // Break ties in favor of the first-defined rule
+    if opts.first_rule_wins
+    {
+        smallest_encodings.sort_by_cached_key(|e|
+            get_rule_definition_order(&matches[e.0]));
+
+        smallest_encodings.truncate(1);
+    }
+
 
     // Expect only a single remaining encoding
     // on the last iteration
@@ -278,6 +354,28 @@ fn resolve_instruction_matches(
 }
 
 
+/// Lists the ruledefs and rules used by a match,
+/// including nested ones, in the order they appear
+fn get_rule_definition_order(
+    instr_match: &asm::InstructionMatch)
+    -> Vec<(usize, usize)>
+{
+    let mut order = vec![(
+        instr_match.ruledef_ref.0,
+        instr_match.rule_ref.0)];
+
+    for arg in &instr_match.args
+    {
+        if let asm::InstructionArgumentKind::Nested(ref nested_match) = arg.kind
+        {
+            order.extend(get_rule_definition_order(nested_match));
+        }
+    }
+
+    order
+}
+
+
 fn build_recursive_candidate_note(
     depth: usize,
     instr_match: &asm::InstructionMatch,
@@ -410,7 +508,7 @@ fn resolve_instruction_match_inner(
                     report,
                     expr.span(),
                     arg_value,
-                    param.typ)?;
+                    param.typ.clone())?;
 
                 eval_ctx.set_local(
                     &param.name,
@@ -519,6 +617,10 @@ pub fn check_and_constrain_argument(
                 |x| x.min_size() > size)
         }
 
+        // Already sized by the matcher
+        asm::RuleParameterType::RegisterRange { .. } =>
+            Ok(value),
+
         asm::RuleParameterType::RuledefRef(_) =>
             unreachable!(),
     }
@@ -44,6 +44,32 @@ pub enum ResolverNode<'ast>
     Align(&'ast asm::AstDirectiveAlign),
     Addr(&'ast asm::AstDirectiveAddr),
     Assert(&'ast asm::AstDirectiveAssert),
+    Diagnostic(&'ast asm::AstDirectiveDiagnostic),
+    Patch(&'ast asm::AstDirectivePatch),
+    ExpectSize(&'ast asm::AstDirectiveExpectSize),
+}
+
+
+impl<'ast> ResolverNode<'ast>
+{
+    pub fn span(&self) -> Option<diagn::Span>
+    {
+        match self
+        {
+            ResolverNode::None => None,
+            ResolverNode::Symbol(node) => Some(node.decl_span),
+            ResolverNode::Instruction(node) => Some(node.span),
+            ResolverNode::DataElement(node, elem_index) =>
+                Some(node.elems[*elem_index].span()),
+            ResolverNode::Res(node) => Some(node.header_span),
+            ResolverNode::Align(node) => Some(node.header_span),
+            ResolverNode::Addr(node) => Some(node.header_span),
+            ResolverNode::Assert(node) => Some(node.header_span),
+            ResolverNode::Diagnostic(node) => Some(node.header_span),
+            ResolverNode::Patch(node) => Some(node.header_span),
+            ResolverNode::ExpectSize(node) => Some(node.header_span),
+        }
+    }
 }
 
 
@@ -213,14 +239,42 @@ impl<'ast, 'decls> ResolveIterator<'ast, 'decls>
                 file_handle_ctx = Some(ast_assert.header_span.file_handle);
             }
 
+            asm::AstAny::DirectiveDiagnostic(ast_diagnostic) =>
+            {
+                self.index += 1;
+                node = ResolverNode::Diagnostic(ast_diagnostic);
+                file_handle_ctx = Some(ast_diagnostic.header_span.file_handle);
+            }
+
+            asm::AstAny::DirectivePatch(ast_patch) =>
+            {
+                self.index += 1;
+                node = ResolverNode::Patch(ast_patch);
+                file_handle_ctx = Some(ast_patch.header_span.file_handle);
+            }
+
+            asm::AstAny::DirectiveExpectSize(ast_expect_size) =>
+            {
+                self.index += 1;
+                node = ResolverNode::ExpectSize(ast_expect_size);
+                file_handle_ctx = Some(ast_expect_size.header_span.file_handle);
+            }
+
+            asm::AstAny::DirectiveAlias(..) |
             asm::AstAny::DirectiveBits(..) |
+            asm::AstAny::DirectiveCommentStyle(..) |
+            asm::AstAny::DirectiveDisable(..) |
+            asm::AstAny::DirectiveExpectDigest(..) |
             asm::AstAny::DirectiveFn(..) |
             asm::AstAny::DirectiveIf(..) |
             asm::AstAny::DirectiveInclude(..) |
             asm::AstAny::DirectiveLabelAlign(..) |
             asm::AstAny::DirectiveNoEmit(..) |
             asm::AstAny::DirectiveOnce(..) |
-            asm::AstAny::DirectiveRuledef(..) =>
+            asm::AstAny::DirectiveRegion(..) |
+            asm::AstAny::DirectiveRepeat(..) |
+            asm::AstAny::DirectiveRuledef(..) |
+            asm::AstAny::DirectiveUsing(..) =>
             {
                 self.index += 1;
                 node = ResolverNode::None;
@@ -407,7 +461,7 @@ impl<'ast, 'decls> ResolveIterator<'ast, 'decls>
 }
 
 
-fn bits_until_alignment(
+pub fn bits_until_alignment(
     position: usize,
     alignment: usize)
     -> usize
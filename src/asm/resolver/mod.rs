@@ -7,6 +7,7 @@ pub use iter::{
     ResolverContext,
     ResolverNode,
     BankData,
+    bits_until_alignment,
 };
 
 mod constant;
@@ -22,6 +23,9 @@ mod res;
 mod align;
 mod addr;
 mod assert;
+mod diagnostic;
+mod patch;
+mod expect_size;
 
 mod directive_if;
 pub use directive_if::{
@@ -29,6 +33,20 @@ pub use directive_if::{
     check_leftover_ifs,
 };
 
+mod directive_alias;
+pub use directive_alias::expand_aliases;
+
+mod directive_repeat;
+pub use directive_repeat::{
+    resolve_repeats,
+    check_leftover_repeats,
+};
+
+mod unknown_symbols;
+pub use unknown_symbols::check_unknown_symbols;
+
+mod unstable_symbols;
+
 mod eval;
 pub use eval::{
     eval,
@@ -76,7 +94,8 @@ pub fn resolve_iteratively(
     ast: &asm::AstTopLevel,
     decls: &asm::ItemDecls,
     defs: &mut asm::ItemDefs,
-    max_iterations: usize)
+    max_iterations: usize,
+    mut maybe_profile: Option<&mut asm::AssemblyProfile>)
     -> Result<usize, ()>
 {
     let mut iter_count = 0;
@@ -88,6 +107,15 @@ pub fn resolve_iteratively(
         let is_first_iteration = iter_count == 1;
         let is_last_iteration = iter_count == max_iterations;
 
+        // Only needed to report unstable symbols
+        // if the last iteration fails
+        let maybe_snapshot = {
+            if is_last_iteration
+                { Some(unstable_symbols::snapshot_symbols(defs)) }
+            else
+                { None }
+        };
+
         let resolution_state = resolve_once(
             report,
             opts,
@@ -97,7 +125,8 @@ pub fn resolve_iteratively(
             defs,
             iter_count,
             is_first_iteration,
-            is_last_iteration)?;
+            is_last_iteration,
+            maybe_profile.as_deref_mut())?;
 
         if let asm::ResolutionState::Resolved = resolution_state
         {
@@ -110,12 +139,21 @@ pub fn resolve_iteratively(
         }
         else if is_last_iteration
         {
+            unstable_symbols::report_unstable_symbols(
+                report,
+                decls,
+                defs,
+                maybe_snapshot.as_ref().unwrap(),
+                iter_count);
+
             return Err(());
         }
     }
 
     // Attempt another resolve pass
     // as if it were the last iteration
+    let snapshot = unstable_symbols::snapshot_symbols(defs);
+
     let resolution_state = resolve_once(
         report,
         opts,
@@ -125,7 +163,8 @@ pub fn resolve_iteratively(
         defs,
         iter_count + 1,
         false,
-        true)?;
+        true,
+        maybe_profile.as_deref_mut())?;
 
     if let asm::ResolutionState::Resolved = resolution_state
     {
@@ -133,6 +172,13 @@ pub fn resolve_iteratively(
     }
     else
     {
+        unstable_symbols::report_unstable_symbols(
+            report,
+            decls,
+            defs,
+            &snapshot,
+            iter_count + 1);
+
         Err(())
     }
 }
@@ -147,7 +193,8 @@ pub fn resolve_once(
     defs: &mut asm::ItemDefs,
     iteration_index: usize,
     is_first_iteration: bool,
-    is_last_iteration: bool)
+    is_last_iteration: bool,
+    mut maybe_profile: Option<&mut asm::AssemblyProfile>)
     -> Result<asm::ResolutionState, ()>
 {
     if opts.debug_iterations
@@ -168,117 +215,174 @@ pub fn resolve_once(
 
     while let Some(ctx) = iter.next(report, decls, defs)?
     {
-        match ctx.node
+        if let Some(profile) = maybe_profile.as_deref_mut()
         {
-            asm::ResolverNode::None => {}
-            
-            asm::ResolverNode::Symbol(ast_symbol) =>
-            {
-                match ast_symbol.kind
-                {
-                    asm::AstSymbolKind::Constant(_) =>
-                        resolution_state.merge(
-                            resolve_constant(
-                                report,
-                                opts,
-                                fileserver,
-                                ast_symbol,
-                                decls,
-                                defs,
-                                &ctx)?),
-
-                    asm::AstSymbolKind::Label =>
-                        resolution_state.merge(
-                            label::resolve_label(
-                                report,
-                                opts,
-                                ast_symbol,
-                                decls,
-                                defs,
-                                &ctx)?),
-                }
-            }
-        
-            asm::ResolverNode::Instruction(ast_instr) =>
-            {
-                resolution_state.merge(
-                    instruction::resolve_instruction(
-                        report,
-                        opts,
-                        fileserver,
-                        ast_instr,
-                        decls,
-                        defs,
-                        &ctx)?);
-            }
-        
-            asm::ResolverNode::DataElement(ast_data, elem_index) =>
-            {
-                resolution_state.merge(
-                    data_block::resolve_data_element(
-                        report,
-                        opts,
-                        fileserver,
-                        ast_data,
-                        elem_index,
-                        decls,
-                        defs,
-                        &ctx)?);
-            }
-        
-            asm::ResolverNode::Res(ast_res) =>
+            if let Some(span) = ctx.node.span()
             {
-                resolution_state.merge(
-                    res::resolve_res(
-                        report,
-                        opts,
-                        fileserver,
-                        ast_res,
-                        decls,
-                        defs,
-                        &ctx)?);
-            }
-        
-            asm::ResolverNode::Align(ast_align) =>
-            {
-                resolution_state.merge(
-                    align::resolve_align(
-                        report,
-                        opts,
-                        fileserver,
-                        ast_align,
-                        decls,
-                        defs,
-                        &ctx)?);
+                profile.count_evaluation(span);
             }
+        }
+
+        resolution_state.merge(
+            resolve_node(
+                report,
+                opts,
+                fileserver,
+                decls,
+                defs,
+                &ctx)?);
+    }
+
+    Ok(resolution_state)
+}
+
+
+/// Resolves a single node yielded by the `ResolveIterator`
+pub fn resolve_node(
+    report: &mut diagn::Report,
+    opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    decls: &asm::ItemDecls,
+    defs: &mut asm::ItemDefs,
+    ctx: &ResolverContext)
+    -> Result<asm::ResolutionState, ()>
+{
+    match ctx.node
+    {
+        asm::ResolverNode::None => Ok(asm::ResolutionState::Resolved),
         
-            asm::ResolverNode::Addr(ast_addr) =>
+        asm::ResolverNode::Symbol(ast_symbol) =>
+        {
+            match ast_symbol.kind
             {
-                resolution_state.merge(
-                    addr::resolve_addr(
+                asm::AstSymbolKind::Constant(_) =>
+                    resolve_constant(
                         report,
                         opts,
                         fileserver,
-                        ast_addr,
+                        ast_symbol,
                         decls,
                         defs,
-                        &ctx)?);
-            }
-        
-            asm::ResolverNode::Assert(ast_assert) =>
-            {
-                resolution_state.merge(
-                    assert::resolve_assert(
+                        ctx),
+
+                asm::AstSymbolKind::Label =>
+                    label::resolve_label(
                         report,
                         opts,
-                        fileserver,
-                        ast_assert,
+                        ast_symbol,
                         decls,
                         defs,
-                        &ctx)?);
+                        ctx),
             }
         }
+    
+        asm::ResolverNode::Instruction(ast_instr) =>
+        {
+            instruction::resolve_instruction(
+                report,
+                opts,
+                fileserver,
+                ast_instr,
+                decls,
+                defs,
+                ctx)
+        }
+    
+        asm::ResolverNode::DataElement(ast_data, elem_index) =>
+        {
+            data_block::resolve_data_element(
+                report,
+                opts,
+                fileserver,
+                ast_data,
+                elem_index,
+                decls,
+                defs,
+                ctx)
+        }
+    
+        asm::ResolverNode::Res(ast_res) =>
+        {
+            res::resolve_res(
+                report,
+                opts,
+                fileserver,
+                ast_res,
+                decls,
+                defs,
+                ctx)
+        }
+    
+        asm::ResolverNode::Align(ast_align) =>
+        {
+            align::resolve_align(
+                report,
+                opts,
+                fileserver,
+                ast_align,
+                decls,
+                defs,
+                ctx)
+        }
+    
+        asm::ResolverNode::Addr(ast_addr) =>
+        {
+            addr::resolve_addr(
+                report,
+                opts,
+                fileserver,
+                ast_addr,
+                decls,
+                defs,
+                ctx)
+        }
+    
+        asm::ResolverNode::Assert(ast_assert) =>
+        {
+            assert::resolve_assert(
+                report,
+                opts,
+                fileserver,
+                ast_assert,
+                decls,
+                defs,
+                ctx)
+        }
+    
+        asm::ResolverNode::Diagnostic(ast_diagnostic) =>
+        {
+            diagnostic::resolve_diagnostic(
+                report,
+                opts,
+                fileserver,
+                ast_diagnostic,
+                decls,
+                defs,
+                ctx)
+        }
+    
+        asm::ResolverNode::Patch(ast_patch) =>
+        {
+            patch::resolve_patch(
+                report,
+                opts,
+                fileserver,
+                ast_patch,
+                decls,
+                defs,
+                ctx)
+        }
+    
+        asm::ResolverNode::ExpectSize(ast_expect_size) =>
+        {
+            expect_size::resolve_expect_size(
+                report,
+                opts,
+                fileserver,
+                ast_expect_size,
+                decls,
+                defs,
+                ctx)
+        }
     }
-
-    Ok(resolution_state)
 }
\ No newline at end of file
@@ -0,0 +1,63 @@
+use crate::*;
+
+
+pub fn resolve_patch(
+    report: &mut diagn::Report,
+    opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    ast_patch: &asm::AstDirectivePatch,
+    decls: &asm::ItemDecls,
+    defs: &mut asm::ItemDefs,
+    ctx: &asm::ResolverContext)
+    -> Result<asm::ResolutionState, ()>
+{
+    // Patches don't affect the layout of the output,
+    // so they only need to be evaluated once, at the end
+    if !ctx.is_last_iteration
+    {
+        return Ok(asm::ResolutionState::Resolved);
+    }
+
+    let addr = asm::resolver::eval(
+        report,
+        opts,
+        fileserver,
+        decls,
+        defs,
+        ctx,
+        &mut expr::EvalContext::new(),
+        &ast_patch.addr_expr)?
+        .expect_bigint(
+            report,
+            ast_patch.addr_expr.span())?
+        .clone();
+
+    report.push_parent(
+        "failed to resolve patch value",
+        ast_patch.value_expr.span());
+
+    let maybe_value = asm::resolver::eval(
+        report,
+        opts,
+        fileserver,
+        decls,
+        defs,
+        ctx,
+        &mut expr::EvalContext::new(),
+        &ast_patch.value_expr);
+
+    report.pop_parent();
+
+    let encoding = maybe_value?
+        .expect_sized_bigint(
+            report,
+            ast_patch.value_expr.span())?
+        .clone();
+
+    let patch = defs.patch_directives.get_mut(ast_patch.item_ref.unwrap());
+    patch.bank_ref = ctx.bank_ref;
+    patch.addr = addr;
+    patch.encoding = encoding;
+
+    Ok(asm::ResolutionState::Resolved)
+}
@@ -39,14 +39,45 @@ pub fn resolve_res(
         }
     };
 
+    let fill_value = {
+        match ast_res.fill_expr
+        {
+            None => None,
+            Some(ref fill_expr) => Some(resolve_fill_value(
+                report,
+                opts,
+                fileserver,
+                fill_expr,
+                decls,
+                defs,
+                ctx)?),
+        }
+    };
+
     let bank = defs.bankdefs.get(ctx.bank_ref);
     let res = defs.res_directives.get_mut(item_ref);
     let prev_value = res.reserve_size;
+    let prev_fill_value = res.fill_value.take();
     
     res.reserve_size =
         <u32 as TryInto<usize>>::try_into(value).unwrap() *
         bank.addr_unit;
 
+    res.fill_value = fill_value;
+
+
+    if res.fill_value != prev_fill_value
+    {
+        if ctx.is_last_iteration
+        {
+            report.error_span(
+                "fill value did not converge",
+                ast_res.fill_expr.as_ref().unwrap().span());
+        }
+
+        return Ok(asm::ResolutionState::Unresolved);
+    }
+
 
     if res.reserve_size != prev_value
     {
@@ -68,4 +99,56 @@ pub fn resolve_res(
 
     
     Ok(asm::ResolutionState::Resolved)
-}
\ No newline at end of file
+}
+
+/// Evaluates the value of each unit of a `#fill`
+/// or a filled `#align`, which must fit in the
+/// bank's `addr_unit`
+pub fn resolve_fill_value(
+    report: &mut diagn::Report,
+    opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    fill_expr: &expr::Expr,
+    decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    ctx: &asm::ResolverContext)
+    -> Result<util::BigInt, ()>
+{
+    let value = asm::resolver::eval(
+        report,
+        opts,
+        fileserver,
+        decls,
+        defs,
+        ctx,
+        &mut expr::EvalContext::new(),
+        fill_expr)?;
+
+    let value = value.expect_error_or_bigint(
+        report,
+        fill_expr.span())?;
+
+    let addr_unit = defs.bankdefs.get(ctx.bank_ref).addr_unit;
+
+    let mut bigint = {
+        match value
+        {
+            expr::Value::Integer(bigint) => bigint,
+            _ => util::BigInt::new(0, None),
+        }
+    };
+
+    if bigint.sign() < 0 || bigint.min_size() > addr_unit
+    {
+        report.error_span(
+            format!(
+                "fill value doesn't fit in the bank's {}-bit `addr_unit`",
+                addr_unit),
+            fill_expr.span());
+
+        return Err(());
+    }
+
+    bigint.size = Some(addr_unit);
+    Ok(bigint)
+}
@@ -0,0 +1,358 @@
+use crate::*;
+
+
+/// Resolves every node that references a symbol which
+/// was never declared, so that all of their errors are
+/// reported at once, instead of stopping resolution
+/// at the first one.
+pub fn check_unknown_symbols(
+    report: &mut diagn::Report,
+    opts: &asm::AssemblyOptions,
+    fileserver: &mut dyn util::FileServer,
+    ast: &asm::AstTopLevel,
+    decls: &asm::ItemDecls,
+    defs: &mut asm::ItemDefs)
+    -> Result<(), ()>
+{
+    let mut had_error = false;
+
+    let mut iter = asm::ResolveIterator::new(
+        ast,
+        defs,
+        true,
+        false);
+
+    while let Some(ctx) = iter.next(report, decls, defs)?
+    {
+        let mut unknown = Vec::new();
+
+        match ctx.node
+        {
+            asm::ResolverNode::Symbol(ast_symbol) =>
+            {
+                if let asm::AstSymbolKind::Constant(ref constant) = ast_symbol.kind
+                {
+                    collect_unknown(
+                        decls,
+                        ctx.symbol_ctx,
+                        &constant.expr,
+                        &mut Vec::new(),
+                        &mut unknown);
+                }
+            }
+
+            asm::ResolverNode::Instruction(ast_instr) =>
+            {
+                let instr = defs.instructions.get(ast_instr.item_ref.unwrap());
+
+                // An argument could be an expression in one match
+                // and an exact token in another, so only consider
+                // it when no match avoids unknown symbols
+                let all_matches_unknown = instr.matches
+                    .iter()
+                    .all(|mtch| {
+                        let mut unknown = Vec::new();
+                        collect_unknown_in_match(
+                            decls,
+                            ctx.symbol_ctx,
+                            mtch,
+                            &mut unknown);
+                        unknown.len() > 0
+                    });
+
+                if instr.matches.len() > 0 && all_matches_unknown
+                {
+                    collect_unknown_in_match(
+                        decls,
+                        ctx.symbol_ctx,
+                        &instr.matches[0],
+                        &mut unknown);
+                }
+            }
+
+            asm::ResolverNode::DataElement(ast_data, elem_index) =>
+                collect_unknown(
+                    decls,
+                    ctx.symbol_ctx,
+                    &ast_data.elems[elem_index],
+                    &mut Vec::new(),
+                    &mut unknown),
+
+            asm::ResolverNode::Res(ast_res) =>
+            {
+                collect_unknown(
+                    decls,
+                    ctx.symbol_ctx,
+                    &ast_res.expr,
+                    &mut Vec::new(),
+                    &mut unknown);
+
+                if let Some(ref fill_expr) = ast_res.fill_expr
+                {
+                    collect_unknown(
+                        decls,
+                        ctx.symbol_ctx,
+                        fill_expr,
+                        &mut Vec::new(),
+                        &mut unknown);
+                }
+            }
+
+            asm::ResolverNode::Align(ast_align) =>
+            {
+                collect_unknown(
+                    decls,
+                    ctx.symbol_ctx,
+                    &ast_align.expr,
+                    &mut Vec::new(),
+                    &mut unknown);
+
+                if let Some(ref fill_expr) = ast_align.fill_expr
+                {
+                    collect_unknown(
+                        decls,
+                        ctx.symbol_ctx,
+                        fill_expr,
+                        &mut Vec::new(),
+                        &mut unknown);
+                }
+            }
+
+            asm::ResolverNode::Addr(ast_addr) =>
+                collect_unknown(
+                    decls,
+                    ctx.symbol_ctx,
+                    &ast_addr.expr,
+                    &mut Vec::new(),
+                    &mut unknown),
+
+            asm::ResolverNode::Assert(ast_assert) =>
+            {
+                collect_unknown(
+                    decls,
+                    ctx.symbol_ctx,
+                    &ast_assert.condition_expr,
+                    &mut Vec::new(),
+                    &mut unknown);
+
+                if let Some(ref message_expr) = ast_assert.message_expr
+                {
+                    collect_unknown(
+                        decls,
+                        ctx.symbol_ctx,
+                        message_expr,
+                        &mut Vec::new(),
+                        &mut unknown);
+                }
+            }
+
+            asm::ResolverNode::Diagnostic(ast_diagnostic) =>
+                collect_unknown(
+                    decls,
+                    ctx.symbol_ctx,
+                    &ast_diagnostic.message_expr,
+                    &mut Vec::new(),
+                    &mut unknown),
+
+            asm::ResolverNode::Patch(ast_patch) =>
+            {
+                collect_unknown(
+                    decls,
+                    ctx.symbol_ctx,
+                    &ast_patch.addr_expr,
+                    &mut Vec::new(),
+                    &mut unknown);
+
+                collect_unknown(
+                    decls,
+                    ctx.symbol_ctx,
+                    &ast_patch.value_expr,
+                    &mut Vec::new(),
+                    &mut unknown);
+            }
+
+            asm::ResolverNode::None |
+            asm::ResolverNode::ExpectSize(_) => {}
+        }
+
+        if unknown.len() == 0
+        {
+            continue;
+        }
+
+        // Resolving the node reports its errors the same way
+        // the full resolution would. A reference that's never
+        // evaluated, like in an untaken branch, isn't an error.
+        let maybe_state = asm::resolver::resolve_node(
+            report,
+            opts,
+            fileserver,
+            decls,
+            defs,
+            &ctx);
+
+        if maybe_state.is_err()
+        {
+            had_error = true;
+        }
+    }
+
+    match had_error
+    {
+        false => Ok(()),
+        true => Err(()),
+    }
+}
+
+
+fn collect_unknown_in_match<'expr>(
+    decls: &asm::ItemDecls,
+    symbol_ctx: &util::SymbolContext,
+    mtch: &'expr asm::InstructionMatch,
+    unknown: &mut Vec<&'expr expr::Expr>)
+{
+    for arg in &mtch.args
+    {
+        match arg.kind
+        {
+            asm::InstructionArgumentKind::Expr(ref expr) =>
+                collect_unknown(
+                    decls,
+                    symbol_ctx,
+                    expr,
+                    &mut Vec::new(),
+                    unknown),
+
+            asm::InstructionArgumentKind::Nested(ref nested) =>
+                collect_unknown_in_match(
+                    decls,
+                    symbol_ctx,
+                    nested,
+                    unknown),
+        }
+    }
+}
+
+
+/// Collects the variables in `expr` that aren't builtins,
+/// locals assigned earlier in the same expression, or
+/// declared symbols. Doesn't look into `asm` blocks.
+fn collect_unknown<'expr>(
+    decls: &asm::ItemDecls,
+    symbol_ctx: &util::SymbolContext,
+    expr: &'expr expr::Expr,
+    locals: &mut Vec<&'expr str>,
+    unknown: &mut Vec<&'expr expr::Expr>)
+{
+    let collect = |expr, locals: &mut Vec<&'expr str>, unknown: &mut Vec<&'expr expr::Expr>|
+        collect_unknown(decls, symbol_ctx, expr, locals, unknown);
+
+    match expr
+    {
+        expr::Expr::Literal(..) => {}
+
+        expr::Expr::Variable(_, hierarchy_level, hierarchy) =>
+        {
+            if *hierarchy_level == 0 && hierarchy.len() == 1
+            {
+                let name = hierarchy[0].as_str();
+
+                if name == "$" ||
+                    name == "pc" ||
+                    locals.contains(&name) ||
+                    expr::resolve_builtin_fn(name).is_some() ||
+                    asm::resolver::resolve_builtin_fn(name).is_some()
+                {
+                    return;
+                }
+            }
+
+            let maybe_decl = decls.symbols.try_get_by_name(
+                symbol_ctx,
+                *hierarchy_level,
+                hierarchy);
+
+            if maybe_decl.is_none()
+            {
+                unknown.push(expr);
+            }
+        }
+
+        expr::Expr::UnaryOp(_, _, _, inner) =>
+            collect(inner, locals, unknown),
+
+        expr::Expr::BinaryOp(_, _, expr::BinaryOp::Assign, lhs, rhs) =>
+        {
+            collect(rhs, locals, unknown);
+
+            match lhs.as_ref()
+            {
+                expr::Expr::Variable(_, 0, hierarchy) if hierarchy.len() == 1 =>
+                    locals.push(&hierarchy[0]),
+                _ => collect(lhs, locals, unknown),
+            }
+        }
+
+        expr::Expr::BinaryOp(_, _, _, lhs, rhs) =>
+        {
+            collect(lhs, locals, unknown);
+            collect(rhs, locals, unknown);
+        }
+
+        expr::Expr::TernaryOp(_, cond, true_branch, false_branch) =>
+        {
+            collect(cond, locals, unknown);
+            collect(true_branch, locals, unknown);
+            collect(false_branch, locals, unknown);
+        }
+
+        expr::Expr::Slice(_, _, left, right, inner) =>
+        {
+            collect(left, locals, unknown);
+            collect(right, locals, unknown);
+            collect(inner, locals, unknown);
+        }
+
+        expr::Expr::SliceShort(_, _, size, inner) =>
+        {
+            collect(size, locals, unknown);
+            collect(inner, locals, unknown);
+        }
+
+        expr::Expr::Block(_, exprs) =>
+        {
+            for expr in exprs
+            {
+                collect(expr, locals, unknown);
+            }
+        }
+
+        expr::Expr::Call(_, target, args) =>
+        {
+            collect(target, locals, unknown);
+
+            for arg in args
+            {
+                collect(arg, locals, unknown);
+            }
+        }
+
+        expr::Expr::Match(_, value, arms, default) =>
+        {
+            collect(value, locals, unknown);
+
+            for arm in arms
+            {
+                collect(&arm.pattern, locals, unknown);
+                collect(&arm.value, locals, unknown);
+            }
+
+            if let Some(default) = default
+            {
+                collect(default, locals, unknown);
+            }
+        }
+
+        expr::Expr::Asm(..) => {}
+    }
+}
@@ -0,0 +1,89 @@
+use crate::*;
+
+
+/// The values of every symbol at the start of
+/// an iteration, indexed by their `ItemRef`
+pub struct SymbolSnapshot
+{
+    values: Vec<Option<expr::Value>>,
+}
+
+
+pub fn snapshot_symbols(
+    defs: &asm::ItemDefs)
+    -> SymbolSnapshot
+{
+    SymbolSnapshot {
+        values: defs.symbols.defs
+            .iter()
+            .map(|s| s.as_ref().map(|s| s.value.clone()))
+            .collect(),
+    }
+}
+
+
+/// Reports which symbols changed value since the given
+/// snapshot, to help pinpoint feedback loops after
+/// the iteration limit is reached
+pub fn report_unstable_symbols(
+    report: &mut diagn::Report,
+    decls: &asm::ItemDecls,
+    defs: &asm::ItemDefs,
+    snapshot: &SymbolSnapshot,
+    iter_count: usize)
+{
+    let mut unstable = Vec::new();
+
+    for (index, maybe_symbol) in defs.symbols.defs.iter().enumerate()
+    {
+        let Some(symbol) = maybe_symbol
+            else { continue };
+
+        let prev_value = snapshot.values
+            .get(index)
+            .and_then(|v| v.as_ref());
+
+        if prev_value == Some(&symbol.value)
+        {
+            continue;
+        }
+
+        let symbol_decl = decls.symbols.get(symbol.item_ref);
+
+        let descr = {
+            match (prev_value.and_then(|v| v.get_bigint()), symbol.value.get_bigint())
+            {
+                (Some(prev), Some(cur)) =>
+                    format!(
+                        "`{}` changed from {} to {}",
+                        symbol_decl.name,
+                        prev.as_radix_string(16, 0, true),
+                        cur.as_radix_string(16, 0, true)),
+
+                _ =>
+                    format!(
+                        "`{}` was still changing",
+                        symbol_decl.name),
+            }
+        };
+
+        unstable.push((descr, symbol_decl.span));
+    }
+
+    let Some(first) = unstable.first()
+        else { return };
+
+    let mut msg = diagn::Message::error_span(
+        format!(
+            "resolution did not converge after {} iteration{}",
+            iter_count,
+            if iter_count == 1 { "" } else { "s" }),
+        first.1);
+
+    for (descr, span) in unstable
+    {
+        msg.inner.push(diagn::Message::note_span(descr, span));
+    }
+
+    report.message(msg);
+}
@@ -6,4 +6,6 @@ pub use self::report::{
     Report,
     Message,
     MessageKind,
+    Diagnostic,
+    DiagnosticSecondary,
 };
\ No newline at end of file
@@ -7,6 +7,7 @@ pub struct Report
 	messages: Vec<Message>,
 	parents: Vec<Message>,
 	parent_cap: Vec<usize>,
+	warnings_as_errors: bool,
 }
 
 
@@ -21,6 +22,28 @@ pub struct Message
 }
 
 
+/// A top-level message in structured form, for tools
+/// that present diagnostics on their own
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic
+{
+	pub kind: MessageKind,
+	pub descr: String,
+	pub span: Option<diagn::Span>,
+	/// Every nested message, flattened in print order
+	pub secondary: Vec<DiagnosticSecondary>,
+}
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiagnosticSecondary
+{
+	pub kind: MessageKind,
+	pub descr: String,
+	pub span: Option<diagn::Span>,
+}
+
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum MessageKind
 {
@@ -147,6 +170,36 @@ impl Message
 	}
 
 
+	/// Turns this and every inner warning into an error
+	pub fn promote_warnings(&mut self)
+	{
+		if let MessageKind::Warning = self.kind
+		{
+			self.kind = MessageKind::Error;
+		}
+
+		for msg in &mut self.inner
+		{
+			msg.promote_warnings();
+		}
+	}
+
+
+	fn collect_secondary(&self, secondary: &mut Vec<DiagnosticSecondary>)
+	{
+		for msg in &self.inner
+		{
+			secondary.push(DiagnosticSecondary {
+				kind: msg.kind,
+				descr: msg.descr.clone(),
+				span: msg.span,
+			});
+
+			msg.collect_secondary(secondary);
+		}
+	}
+
+
 	pub fn fuse_topmost(msgs: Vec<Message>) -> Message
 	{
 		let mut topmost = Message {
@@ -200,10 +253,30 @@ impl Report
 			messages: Vec::new(),
 			parents: Vec::new(),
 			parent_cap: Vec::new(),
+			warnings_as_errors: false,
 		}
 	}
 
 
+	/// Reports every warning added from now
+	/// on as an error instead
+	pub fn set_warnings_as_errors(&mut self, value: bool)
+	{
+		self.warnings_as_errors = value;
+	}
+
+
+	fn apply_severity(&self, mut msg: Message) -> Message
+	{
+		if self.warnings_as_errors
+		{
+			msg.promote_warnings();
+		}
+
+		msg
+	}
+
+
 	pub fn transfer_to(&mut self, other: &mut Report)
 	{
 		for msg in &self.messages
@@ -321,6 +394,7 @@ impl Report
 	
 	pub fn message(&mut self, msg: Message)
 	{
+		let msg = self.apply_severity(msg);
 		self.messages.push(self.wrap_in_parents(msg));
 	}
 	
@@ -329,6 +403,7 @@ impl Report
 		&mut self,
 		msg: Message)
 	{
+		let msg = self.apply_severity(msg);
 		self.messages.push(self.wrap_in_parents_dedup(msg));
 	}
 	
@@ -352,7 +427,7 @@ impl Report
 		
 		for msg in msgs
 		{
-			self.messages.push(msg);
+			self.messages.push(self.apply_severity(msg));
 		}
 	}
 
@@ -452,6 +527,27 @@ impl Report
 	}
 	
 	
+	/// Lists every reported message as structured data,
+	/// in the same order as `print_all`
+	pub fn diagnostics(&self) -> Vec<Diagnostic>
+	{
+		self.messages
+			.iter()
+			.map(|msg| {
+				let mut secondary = Vec::new();
+				msg.collect_secondary(&mut secondary);
+
+				Diagnostic {
+					kind: msg.kind,
+					descr: msg.descr.clone(),
+					span: msg.span,
+					secondary,
+				}
+			})
+			.collect()
+	}
+
+
 	pub fn len(&self) -> usize
 	{
 		self.messages.len()
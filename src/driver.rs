@@ -1,9 +1,14 @@
 use crate::*;
 
 
+const STDIN_FILENAME: &str = "-";
+const STDIN_DEFAULT_NAME: &str = "<stdin>";
+
+
 struct Command
 {
 	pub input_filenames: Vec<String>,
+	pub stdin_name: String,
 	pub output_groups: Vec<CommandOutput>,
 	pub opts: asm::AssemblyOptions,
 	pub quiet: bool,
@@ -21,38 +26,83 @@ struct CommandOutput
 }
 
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub enum OutputFormat
 {
 	Binary,
 	Annotated {
 		base: usize,
 		group: usize,
+		decimal: bool,
 	},
 	BinStr,
 	HexStr,
 	BinDump,
 	HexDump,
-	Mif,
+	Mif {
+		address_unit: Option<usize>,
+		line_len: usize,
+	},
 	IntelHex {
 		address_unit: usize,
+		line_len: usize,
+		fill: bool,
+	},
+	Srec {
+		header: Option<String>,
+		line_len: usize,
+		fill: bool,
+	},
+	ReadMemH {
+		address_unit: Option<usize>,
+		line_len: usize,
+	},
+	ReadMemB {
+		address_unit: Option<usize>,
+		line_len: usize,
 	},
 	DecComma,
 	HexComma,
 	DecSpace,
 	HexSpace,
-	DecC,
-	HexC,
-	LogiSim8,
-	LogiSim16,
-	AddressSpan,
+	CArray {
+		radix: usize,
+		name: String,
+		line_len: usize,
+	},
+	LogiSim {
+		address_unit: Option<usize>,
+		rle: bool,
+	},
+	AddressSpan {
+		base: usize,
+		width: usize,
+	},
+	FileUsage,
+	Listing {
+		line_len: usize,
+	},
 	TCGame {
 		base: usize,
 		group: usize,
 	},
 
-	Symbols,
+	Symbols {
+		base: usize,
+		width: usize,
+	},
 	SymbolsMesenMlb,
+	SymbolsVice,
+	SymbolsCHeader {
+		guard: Option<String>,
+		base: usize,
+		width: usize,
+	},
+
+	DeclsJson,
+	ObjectJson,
+
+	BankTar,
 }
 
 
@@ -138,11 +188,36 @@ fn assemble_with_command(
 		return Err(());
 	}
 
+	let mut input_filenames = command.input_filenames.clone();
+
+	for filename in &mut input_filenames
+	{
+		if filename == STDIN_FILENAME
+		{
+			let contents = read_stdin(report)?;
+
+			// Resolve includes relative to the directory
+			// of the given name, or else the current one
+			let base_dir = std::path::Path::new(&command.stdin_name)
+				.parent()
+				.map(|p| p.to_string_lossy().into_owned())
+				.unwrap_or_default();
+
+			fileserver.add_virtual(
+				report,
+				&command.stdin_name,
+				&base_dir,
+				contents)?;
+
+			*filename = command.stdin_name.clone();
+		}
+	}
+
 	if !command.quiet
 	{
 		print_version_short();
 
-		for filename in &command.input_filenames
+		for filename in &input_filenames
 		{
 			println!("assembling `{}`...", filename);
 		}
@@ -152,7 +227,12 @@ fn assemble_with_command(
 		report,
 		&command.opts,
 		fileserver,
-		&command.input_filenames);
+		&input_filenames);
+
+	if let Some(ref profile) = assembly.profile
+	{
+		print!("{}", profile.format_summary(fileserver));
+	}
 
 	let output = assembly.output
 		.as_ref()
@@ -164,14 +244,15 @@ fn assemble_with_command(
 
 	for output_group in &command.output_groups
 	{
-		if let Some(format) = output_group.format
+		if let Some(ref format) = output_group.format
 		{
 			let formatted = format_output(
 				fileserver,
+				&command.opts,
 				decls,
 				defs,
 				output,
-				format);
+				format.clone());
 
 			if output_group.printout
 			{
@@ -212,6 +293,29 @@ fn assemble_with_command(
 }
 
 
+fn read_stdin(
+	report: &mut diagn::Report)
+	-> Result<Vec<u8>, ()>
+{
+	let mut contents = Vec::new();
+
+	use std::io::Read;
+	match std::io::stdin().read_to_end(&mut contents)
+	{
+		Ok(_) => Ok(contents),
+		Err(err) =>
+		{
+			report.error(
+				format!(
+					"could not read from stdin: {}",
+					err));
+
+			Err(())
+		}
+	}
+}
+
+
 fn make_opts() -> getopts::Options
 {
 	let asm_opts = asm::AssemblyOptions::new();
@@ -240,6 +344,15 @@ fn make_opts() -> getopts::Options
 		getopts::HasArg::Maybe,
 		getopts::Occur::Optional);
 
+	opts.opt(
+		"", "max-include-depth",
+		&format!(
+			"The max nesting depth of `#include` directives. (Default: {})",
+			asm_opts.max_include_depth),
+		"NUM",
+		getopts::HasArg::Yes,
+		getopts::Occur::Optional);
+
 	opts.optflag(
 		"p", "print",
 		"Print the output to the screen instead of writing to a file.");
@@ -248,6 +361,10 @@ fn make_opts() -> getopts::Options
 		"q", "quiet",
 		"Suppress progress reports.");
 
+	opts.optflag(
+		"", "warnings-as-errors",
+		"Treat every warning as an error.");
+
 	opts.opt(
 		"d", "define",
 		"Defines a constant.",
@@ -262,10 +379,46 @@ fn make_opts() -> getopts::Options
 		getopts::HasArg::Maybe,
 		getopts::Occur::Optional);
 
+	opts.opt(
+		"", "symbol-prefix",
+		"Prefixes every symbol name in symbol outputs.",
+		"PREFIX",
+		getopts::HasArg::Yes,
+		getopts::Occur::Optional);
+
+	opts.opt(
+		"", "stdin-name",
+		"The filename to show for input read from stdin.",
+		"NAME",
+		getopts::HasArg::Yes,
+		getopts::Occur::Optional);
+
+	opts.opt(
+		"", "gap-fill",
+		"Fills output gaps between banks with the given byte.",
+		"VALUE",
+		getopts::HasArg::Yes,
+		getopts::Occur::Optional);
+
+	opts.optflag(
+		"", "first-rule-wins",
+		"Pick the first-defined rule among ambiguous matches.");
+
+	opts.opt(
+		"", "build-date",
+		"Sets the value returned by `builddate()`. [now/TIMESTAMP]",
+		"VALUE",
+		getopts::HasArg::Yes,
+		getopts::Occur::Optional);
+
     opts.optflag(
 		"", "debug-iters",
 		"Print debug info for the resolution iterations.");
 
+	opts.optflag(
+		"", "debug-profile",
+		"Print the time spent in each assembly phase.");
+
 	opts.optflag(
 		"", "debug-no-optimize-static",
 		"Prevent optimization of statically-known values.");
@@ -297,6 +450,7 @@ fn parse_command(
 
 	let mut command = Command {
 		input_filenames: Vec::new(),
+		stdin_name: STDIN_DEFAULT_NAME.to_string(),
 		output_groups: Vec::new(),
 		opts: asm::AssemblyOptions::new(),
 		quiet: false,
@@ -350,6 +504,12 @@ fn parse_command(
 		command.show_version |= parsed.opt_present("v");
 		command.show_help |= parsed.opt_present("h");
 
+		command.opts.warnings_as_errors |=
+			parsed.opt_present("warnings-as-errors");
+
+		command.opts.first_rule_wins |=
+			parsed.opt_present("first-rule-wins");
+
 		for define_arg in parsed.opt_strs("d")
 		{
 			command.opts.driver_symbol_defs.push(
@@ -361,6 +521,9 @@ fn parse_command(
 		command.opts.debug_iterations |=
 			parsed.opt_present("debug-iters");
 
+		command.opts.profile |=
+			parsed.opt_present("debug-profile");
+
 		command.opts.optimize_statically_known &=
 			!parsed.opt_present("debug-no-optimize-static");
 
@@ -383,6 +546,24 @@ fn parse_command(
 			};
 		}
 
+		if let Some(symbol_prefix) = parsed.opt_str("symbol-prefix")
+		{
+			command.opts.symbol_prefix = symbol_prefix;
+		}
+
+		if let Some(stdin_name) = parsed.opt_str("stdin-name")
+		{
+			command.stdin_name = stdin_name;
+		}
+
+		if let Some(build_date) = parsed.opt_str("build-date")
+		{
+			command.opts.build_date = Some(
+				parse_build_date_arg(
+					report,
+					&build_date)?);
+		}
+
 		if let Some(t) = parsed.opt_str("t")
 		{
 			command.opts.max_iterations = {
@@ -398,6 +579,29 @@ fn parse_command(
 			};
 		}
 
+		if let Some(gap_fill) = parsed.opt_str("gap-fill")
+		{
+			command.opts.gap_fill = Some(
+				parse_gap_fill_arg(
+					report,
+					&gap_fill)?);
+		}
+
+		if let Some(depth) = parsed.opt_str("max-include-depth")
+		{
+			command.opts.max_include_depth = {
+				match depth.parse::<usize>()
+				{
+					Err(_) =>
+					{
+						report.error("invalid argument for `--max-include-depth`");
+						return Err(());
+					}
+					Ok(depth) => depth,
+				}
+			};
+		}
+
 
 		// Add the input filenames to the main command
 		for input_filename in parsed.free.into_iter()
@@ -421,6 +625,7 @@ fn parse_command(
 				group.format = Some(OutputFormat::Annotated {
 					base: 16,
 					group: 2,
+					decimal: false,
 				});
 			}
 			else
@@ -433,10 +638,28 @@ fn parse_command(
 			group.output_filename.is_none() &&
 			command.input_filenames.len() >= 1
 		{
+			let input_filename = {
+				if command.input_filenames[0] == STDIN_FILENAME
+				{
+					if command.stdin_name == STDIN_DEFAULT_NAME
+					{
+						report.error(
+							"cannot derive output filename for input from stdin; use `-o` or `--stdin-name`");
+						return Err(());
+					}
+
+					&command.stdin_name
+				}
+				else
+				{
+					&command.input_filenames[0]
+				}
+			};
+
 			group.output_filename = Some(derive_output_filename(
 				report,
-				group.format.unwrap(),
-				&command.input_filenames[0])?);
+				group.format.clone().unwrap(),
+				input_filename)?);
 		}
 	}
 
@@ -445,6 +668,61 @@ fn parse_command(
 }
 
 
+/// Parses either `now`, for the current Unix time,
+/// or a fixed Unix timestamp.
+fn parse_build_date_arg(
+	report: &mut diagn::Report,
+	arg: &str)
+	-> Result<u64, ()>
+{
+	if arg == "now"
+	{
+		let now = std::time::SystemTime::now()
+			.duration_since(std::time::UNIX_EPOCH)
+			.map(|duration| duration.as_secs())
+			.unwrap_or(0);
+
+		return Ok(now);
+	}
+
+	match arg.parse::<u64>()
+	{
+		Ok(timestamp) => Ok(timestamp),
+		Err(_) =>
+		{
+			report.error("invalid argument for `--build-date`");
+			Err(())
+		}
+	}
+}
+
+
+/// Parses a byte value, in decimal or with a `0x` prefix.
+fn parse_gap_fill_arg(
+	report: &mut diagn::Report,
+	arg: &str)
+	-> Result<u8, ()>
+{
+	let maybe_value = {
+		match arg.strip_prefix("0x")
+		{
+			Some(hex) => u8::from_str_radix(hex, 16),
+			None => arg.parse::<u8>(),
+		}
+	};
+
+	match maybe_value
+	{
+		Ok(value) => Ok(value),
+		Err(_) =>
+		{
+			report.error("invalid argument for `--gap-fill`");
+			Err(())
+		}
+	}
+}
+
+
 fn derive_output_filename(
 	report: &mut diagn::Report,
 	format: OutputFormat,
@@ -456,6 +734,11 @@ fn derive_output_filename(
 		{
 			OutputFormat::Binary => "bin",
 			OutputFormat::SymbolsMesenMlb => "mlb",
+			OutputFormat::SymbolsVice => "lbl",
+			OutputFormat::SymbolsCHeader { .. } => "h",
+			OutputFormat::DeclsJson => "json",
+			OutputFormat::ObjectJson => "json",
+			OutputFormat::BankTar => "tar",
 			_ => "txt",
 		}
 	};
@@ -520,13 +803,12 @@ pub fn parse_output_format(
 
 	let get_arg_usize = &mut |
 		param_id: &str,
-		def: usize,
 		validate: &mut dyn FnMut(usize)
 		-> bool|
 	{
 		match params.get(param_id)
 		{
-			None => Ok(def),
+			None => Ok(None),
 			Some(value) =>
 			{
 				match value.parse::<usize>()
@@ -536,7 +818,7 @@ pub fn parse_output_format(
 						if validate(v)
 						{
 							params.remove(param_id);
-							return Ok(v);
+							return Ok(Some(v));
 						}
 					}
 					Err(_) => {}
@@ -554,6 +836,11 @@ pub fn parse_output_format(
 		}
 	};
 
+	let check_any = &mut |_: usize| -> bool
+	{
+		true
+	};
+
 	let check_nonzero = &mut |value: usize| -> bool
 	{
 		value > 0
@@ -569,29 +856,56 @@ pub fn parse_output_format(
 		[2, 16].contains(&base)
 	};
 
+	let check_2_8_10_or_16 = &mut |base: usize| -> bool
+	{
+		[2, 8, 10, 16].contains(&base)
+	};
+
+	// C has no portable binary or unambiguous octal literals
+	let check_10_or_16 = &mut |base: usize| -> bool
+	{
+		[10, 16].contains(&base)
+	};
+
 	let check_8_16_or_32 = &mut |base: usize| -> bool
 	{
 		[8, 16, 32].contains(&base)
 	};
 
+	// The byte count of an Intel HEX record is a single byte
+	let check_intelhex_record_len = &mut |len: usize| -> bool
+	{
+		len >= 1 && len <= 255
+	};
+
+	// The byte count of an S-record is a single byte, and
+	// also covers up to 4 address bytes and the checksum
+	let check_srec_record_len = &mut |len: usize| -> bool
+	{
+		len >= 1 && len <= 250
+	};
+
 	let format = {
 		match format_id
 		{
 			"binary" => OutputFormat::Binary,
 
 			"annotated" => OutputFormat::Annotated {
-				base: get_arg_usize("base", 16, check_valid_base)?,
-				group: get_arg_usize("group", 2, check_nonzero)?,
+				base: get_arg_usize("base", check_valid_base)?.unwrap_or(16),
+				group: get_arg_usize("group", check_nonzero)?.unwrap_or(2),
+				decimal: get_arg_flag(report, format_id, &mut params, "dec")?,
 			},
 
 			"annotatedhex" => OutputFormat::Annotated {
 				base: 16,
 				group: 2,
+				decimal: false,
 			},
 
 			"annotatedbin" => OutputFormat::Annotated {
 				base: 2,
 				group: 8,
+				decimal: false,
 			},
 
 			"binstr" => OutputFormat::BinStr,
@@ -600,9 +914,27 @@ pub fn parse_output_format(
 			"bindump" => OutputFormat::BinDump,
 			"hexdump" => OutputFormat::HexDump,
 
-			"mif" => OutputFormat::Mif,
+			"mif" => OutputFormat::Mif {
+				address_unit: get_arg_usize("addr_unit", check_nonzero)?,
+				line_len: get_arg_usize("line_len", check_nonzero)?.unwrap_or(1),
+			},
 			"intelhex" => OutputFormat::IntelHex {
-				address_unit: get_arg_usize("addr_unit", 8, check_8_16_or_32)?,
+				address_unit: get_arg_usize("addr_unit", check_8_16_or_32)?.unwrap_or(8),
+				line_len: get_arg_usize("line_len", check_intelhex_record_len)?.unwrap_or(32),
+				fill: get_arg_flag(report, format_id, &mut params, "fill")?,
+			},
+			"srec" => OutputFormat::Srec {
+				line_len: get_arg_usize("line_len", check_srec_record_len)?.unwrap_or(16),
+				header: get_srec_header_arg(report, format_id, &mut params)?,
+				fill: get_arg_flag(report, format_id, &mut params, "fill")?,
+			},
+			"readmemh" => OutputFormat::ReadMemH {
+				address_unit: get_arg_usize("addr_unit", check_nonzero)?,
+				line_len: get_arg_usize("line_len", check_nonzero)?.unwrap_or(1),
+			},
+			"readmemb" => OutputFormat::ReadMemB {
+				address_unit: get_arg_usize("addr_unit", check_nonzero)?,
+				line_len: get_arg_usize("line_len", check_nonzero)?.unwrap_or(1),
 			},
 
 			"deccomma" => OutputFormat::DecComma,
@@ -610,18 +942,45 @@ pub fn parse_output_format(
 			"decspace" => OutputFormat::DecSpace,
 			"hexspace" => OutputFormat::HexSpace,
 
-			"decc" => OutputFormat::DecC,
-			"hexc" => OutputFormat::HexC,
-			"c" => OutputFormat::HexC,
+			"decc" => OutputFormat::CArray {
+				line_len: get_arg_usize("line_len", check_nonzero)?.unwrap_or(16),
+				radix: 10,
+				name: get_arg_string(report, format_id, &mut params, "name")?
+					.unwrap_or("data".to_string()),
+			},
+			"hexc" | "c" => OutputFormat::CArray {
+				line_len: get_arg_usize("line_len", check_nonzero)?.unwrap_or(16),
+				radix: 16,
+				name: get_arg_string(report, format_id, &mut params, "name")?
+					.unwrap_or("data".to_string()),
+			},
 
-			"logisim8" => OutputFormat::LogiSim8,
-			"logisim16" => OutputFormat::LogiSim16,
+			"logisim" => OutputFormat::LogiSim {
+				address_unit: get_arg_usize("addr_unit", check_nonzero)?,
+				rle: get_arg_flag(report, format_id, &mut params, "rle")?,
+			},
+			"logisim8" => OutputFormat::LogiSim {
+				address_unit: Some(8),
+				rle: get_arg_flag(report, format_id, &mut params, "rle")?,
+			},
+			"logisim16" => OutputFormat::LogiSim {
+				address_unit: Some(16),
+				rle: get_arg_flag(report, format_id, &mut params, "rle")?,
+			},
 
-			"addrspan" => OutputFormat::AddressSpan,
+			"addrspan" => OutputFormat::AddressSpan {
+				base: get_arg_usize("base", check_2_8_10_or_16)?.unwrap_or(16),
+				width: get_arg_usize("width", check_any)?.unwrap_or(0),
+			},
+			"fileusage" => OutputFormat::FileUsage,
+
+			"listing" => OutputFormat::Listing {
+				line_len: get_arg_usize("line_len", check_nonzero)?.unwrap_or(4),
+			},
 
 			"tcgame" => OutputFormat::TCGame {
-				base: get_arg_usize("base", 16, check_2_or_16)?,
-				group: get_arg_usize("group", 2, check_nonzero)?,
+				base: get_arg_usize("base", check_2_or_16)?.unwrap_or(16),
+				group: get_arg_usize("group", check_nonzero)?.unwrap_or(2),
 			},
 
 			"tcgamebin" => OutputFormat::TCGame {
@@ -629,8 +988,22 @@ pub fn parse_output_format(
 				group: 8,
 			},
 
-			"symbols" => OutputFormat::Symbols,
+			"symbols" => OutputFormat::Symbols {
+				base: get_arg_usize("base", check_2_8_10_or_16)?.unwrap_or(16),
+				width: get_arg_usize("width", check_any)?.unwrap_or(0),
+			},
 			"mesen-mlb" => OutputFormat::SymbolsMesenMlb,
+			"vice" => OutputFormat::SymbolsVice,
+			"c-header" => OutputFormat::SymbolsCHeader {
+				base: get_arg_usize("base", check_10_or_16)?.unwrap_or(16),
+				width: get_arg_usize("width", check_any)?.unwrap_or(0),
+				guard: get_arg_string(report, format_id, &mut params, "guard")?,
+			},
+
+			"decls-json" => OutputFormat::DeclsJson,
+			"object-json" => OutputFormat::ObjectJson,
+
+			"tar-banks" => OutputFormat::BankTar,
 
 			_ =>
 			{
@@ -662,6 +1035,86 @@ pub fn parse_output_format(
 }
 
 
+/// Reads a parameter given without a value, like `dec`
+/// in `annotated,dec`
+fn get_arg_flag(
+	report: &mut diagn::Report,
+	format_id: &str,
+	params: &mut std::collections::HashMap<String, String>,
+	param_id: &str)
+	-> Result<bool, ()>
+{
+	match params.remove(param_id)
+	{
+		None => Ok(false),
+		Some(value) if value.is_empty() => Ok(true),
+		Some(value) =>
+		{
+			report.error(
+				format!(
+					"invalid format argument `{},{}:{}`",
+					format_id,
+					param_id,
+					value));
+
+			Err(())
+		}
+	}
+}
+
+
+fn get_arg_string(
+	report: &mut diagn::Report,
+	format_id: &str,
+	params: &mut std::collections::HashMap<String, String>,
+	param_id: &str)
+	-> Result<Option<String>, ()>
+{
+	match params.remove(param_id)
+	{
+		None => Ok(None),
+		Some(value) if !value.is_empty() => Ok(Some(value)),
+		Some(_) =>
+		{
+			report.error(
+				format!(
+					"missing value for format argument `{},{}`",
+					format_id,
+					param_id));
+
+			Err(())
+		}
+	}
+}
+
+
+fn get_srec_header_arg(
+	report: &mut diagn::Report,
+	format_id: &str,
+	params: &mut std::collections::HashMap<String, String>)
+	-> Result<Option<String>, ()>
+{
+	let maybe_header = get_arg_string(report, format_id, params, "header")?;
+
+	if let Some(ref header) = maybe_header
+	{
+		if header.len() > util::SREC_HEADER_MAX_LEN
+		{
+			report.error(
+				format!(
+					"format argument `{},header` is too long (max {} bytes, got {})",
+					format_id,
+					util::SREC_HEADER_MAX_LEN,
+					header.len()));
+
+			return Err(());
+		}
+	}
+
+	Ok(maybe_header)
+}
+
+
 fn parse_define_arg(
 	report: &mut diagn::Report,
 	raw_str: &str)
@@ -743,6 +1196,7 @@ fn parse_define_arg(
 
 pub fn format_output(
 	fileserver: &dyn util::FileServer,
+	opts: &asm::AssemblyOptions,
 	decls: &asm::ItemDecls,
 	defs: &asm::ItemDefs,
 	output: &util::BitVec,
@@ -755,8 +1209,8 @@ pub fn format_output(
 			OutputFormat::Binary =>
 				return output.format_binary(),
 
-			OutputFormat::Annotated { base, group } =>
-				output.format_annotated(fileserver, base, group),
+			OutputFormat::Annotated { base, group, decimal } =>
+				output.format_annotated(fileserver, base, group, decimal),
 
 			OutputFormat::TCGame { base, group } =>
 				output.format_tcgame(fileserver, base, group),
@@ -767,9 +1221,27 @@ pub fn format_output(
 			OutputFormat::BinDump => output.format_bindump(),
 			OutputFormat::HexDump => output.format_hexdump(),
 
-			OutputFormat::Mif => output.format_mif(),
-			OutputFormat::IntelHex { address_unit } =>
-				output.format_intelhex(address_unit),
+			OutputFormat::Mif { address_unit, line_len } =>
+			{
+				let word_size = address_unit.unwrap_or(get_output_addr_unit(defs));
+
+				output.format_mif(
+					word_size,
+					get_output_end(defs).map(|end| end / word_size),
+					line_len)
+			}
+			OutputFormat::IntelHex { address_unit, line_len, fill } =>
+				output.format_intelhex(address_unit, line_len, fill),
+			OutputFormat::Srec { header, line_len, fill } =>
+				output.format_srec(header.as_deref(), line_len, fill),
+			OutputFormat::ReadMemH { address_unit, line_len } =>
+				output.format_readmemh(
+					address_unit.unwrap_or(get_output_addr_unit(defs)),
+					line_len),
+			OutputFormat::ReadMemB { address_unit, line_len } =>
+				output.format_readmemb(
+					address_unit.unwrap_or(get_output_addr_unit(defs)),
+					line_len),
 
 			OutputFormat::DecComma => output.format_separator(10, ", "),
 			OutputFormat::HexComma => output.format_separator(16, ", "),
@@ -777,20 +1249,130 @@ pub fn format_output(
 			OutputFormat::DecSpace => output.format_separator(10, " "),
 			OutputFormat::HexSpace => output.format_separator(16, " "),
 
-			OutputFormat::DecC => output.format_c_array(10),
-			OutputFormat::HexC => output.format_c_array(16),
+			OutputFormat::CArray { radix, name, line_len } =>
+				output.format_c_array(radix, &name, line_len),
+
+			OutputFormat::LogiSim { address_unit, rle } =>
+				output.format_logisim(
+					address_unit.unwrap_or(get_output_addr_unit(defs)),
+					rle),
+
+			OutputFormat::AddressSpan { base, width } =>
+				output.format_addrspan(fileserver, base, width),
+			OutputFormat::FileUsage => output.format_file_usage(fileserver),
+
+			OutputFormat::Listing { line_len } =>
+				output.format_listing(fileserver, line_len),
+
+			OutputFormat::Symbols { base, width } =>
+				decls.symbols.format_default(
+					decls,
+					defs,
+					&opts.symbol_prefix,
+					base,
+					width),
+			OutputFormat::SymbolsMesenMlb =>
+				decls.symbols.format_mesen_mlb(decls, defs, &opts.symbol_prefix),
+			OutputFormat::SymbolsVice =>
+				decls.symbols.format_vice(decls, defs, &opts.symbol_prefix),
+			OutputFormat::SymbolsCHeader { guard, base, width } =>
+				decls.symbols.format_c_header(
+					decls,
+					defs,
+					&opts.symbol_prefix,
+					guard.as_deref(),
+					base,
+					width),
+
+			OutputFormat::DeclsJson => decls.format_json(Some(defs)),
+			OutputFormat::ObjectJson =>
+				asm::output::format_object_json(
+					decls,
+					defs,
+					output,
+					&opts.symbol_prefix),
+
+			OutputFormat::BankTar =>
+				return format_bank_tar(decls, defs, output),
+		}
+	};
+
+	text.bytes().collect()
+}
 
-			OutputFormat::LogiSim8 => output.format_logisim(8),
-			OutputFormat::LogiSim16 => output.format_logisim(16),
 
-			OutputFormat::AddressSpan => output.format_addrspan(fileserver),
+/// Packs the binary output of each bank into a tar
+/// archive, with one `NAME.bin` entry per bank.
+/// The default bank is only included if it was used.
+fn format_bank_tar(
+	decls: &asm::ItemDecls,
+	defs: &asm::ItemDefs,
+	output: &util::BitVec)
+	-> Vec<u8>
+{
+	let mut entries = Vec::new();
 
-			OutputFormat::Symbols => decls.symbols.format_default(decls, defs),
-			OutputFormat::SymbolsMesenMlb => decls.symbols.format_mesen_mlb(decls, defs),
+	for i in 0..defs.bankdefs.defs.len()
+	{
+		let bank_ref = util::ItemRef::new(i);
+
+		let Some(range) = asm::output::get_bank_output_range(defs, bank_ref)
+			else { continue };
+
+		if i == 0 && range.is_empty()
+		{
+			continue;
 		}
-	};
 
-	text.bytes().collect()
+		let name = decls.bankdefs
+			.get(bank_ref)
+			.name
+			.trim_start_matches('#');
+
+		entries.push((
+			format!("{}.bin", name),
+			output.format_binary_range(range)));
+	}
+
+	util::make_tar(&entries)
+}
+
+
+/// Gets the `addr_unit` shared by all user-defined banks
+/// that produce output, or 8 if they disagree or there are none.
+fn get_output_addr_unit(
+	defs: &asm::ItemDefs)
+	-> usize
+{
+	let mut addr_units = (1..defs.bankdefs.defs.len())
+		.map(|i| defs.bankdefs.get(util::ItemRef::new(i)))
+		.filter(|bankdef| bankdef.output_offset.is_some())
+		.map(|bankdef| bankdef.addr_unit);
+
+	match addr_units.next()
+	{
+		Some(first) if addr_units.all(|u| u == first) => first,
+		_ => 8,
+	}
+}
+
+
+/// Returns the bit offset where the only bank that is
+/// written to the output ends, if it has a fixed size
+fn get_output_end(
+	defs: &asm::ItemDefs)
+	-> Option<usize>
+{
+	let mut output_banks = (1..defs.bankdefs.defs.len())
+		.map(|i| defs.bankdefs.get(util::ItemRef::new(i)))
+		.filter(|bankdef| bankdef.output_offset.is_some());
+
+	match (output_banks.next(), output_banks.next())
+	{
+		(Some(bankdef), None) => bankdef.size
+			.map(|size| bankdef.output_offset.unwrap() + size),
+		_ => None,
+	}
 }
 
 
@@ -9,6 +9,7 @@ pub fn resolve_builtin_fn(
     {
         "assert" => Some(eval_builtin_assert),
         "le" => Some(eval_builtin_le),
+        "bswap" => Some(eval_builtin_bswap),
         "ascii" => Some(eval_builtin_ascii),
         "utf8" => Some(eval_builtin_utf8),
         "utf16be" => Some(eval_builtin_utf16be),
@@ -16,11 +17,36 @@ pub fn resolve_builtin_fn(
         "utf32be" => Some(eval_builtin_utf32be),
         "utf32le" => Some(eval_builtin_utf32le),
         "strlen" => Some(eval_builtin_strlen),
+        "parity" => Some(eval_builtin_parity),
+        "hamming" => Some(eval_builtin_hamming),
+        "lo" => Some(eval_builtin_lo),
+        "hi" => Some(eval_builtin_hi),
+        "bcd" => Some(eval_builtin_bcd),
         _ => None,
     }
 }
 
 
+/// Resolves a variable naming a builtin function.
+/// Providers try this only after their own symbols,
+/// so that user symbols can shadow builtin names.
+pub fn resolve_builtin_fn_variable(
+    query: &expr::EvalVariableQuery)
+    -> Option<expr::Value>
+{
+    if query.hierarchy_level != 0 || query.hierarchy.len() != 1
+    {
+        return None;
+    }
+
+    let name = &query.hierarchy[0];
+
+    resolve_builtin_fn(name)?;
+
+    Some(expr::Value::ExprBuiltInFunction(name.clone()))
+}
+
+
 pub fn get_static_size_builtin_fn(
     name: &str,
     provider: &expr::StaticallyKnownProvider,
@@ -31,6 +57,12 @@ pub fn get_static_size_builtin_fn(
         match name.as_ref()
         {
             "le" => get_static_size_builtin_le,
+            "bswap" => get_static_size_builtin_le,
+            "parity" => get_static_size_builtin_parity,
+            "hamming" => get_static_size_builtin_hamming,
+            "lo" => get_static_size_builtin_lo_hi,
+            "hi" => get_static_size_builtin_lo_hi,
+            "bcd" => get_static_size_builtin_bcd,
             _ => return None,
         }
     };
@@ -50,6 +82,7 @@ pub fn get_statically_known_value_builtin_fn(
     {
         "assert" => false,
         "le" => true,
+        "bswap" => true,
         "ascii" => true,
         "utf8" => true,
         "utf16be" => true,
@@ -57,6 +90,11 @@ pub fn get_statically_known_value_builtin_fn(
         "utf32be" => true,
         "utf32le" => true,
         "strlen" => true,
+        "parity" => true,
+        "hamming" => true,
+        "lo" => true,
+        "hi" => true,
+        "bcd" => true,
         _ => false,
     }
 }
@@ -121,6 +159,26 @@ pub fn eval_builtin_assert(
 pub fn eval_builtin_le(
     query: &mut expr::EvalFunctionQuery)
     -> Result<expr::Value, ()>
+{
+    eval_builtin_byte_swap("le", query)
+}
+
+
+/// Same as `le`, but named for when the value's
+/// byte order isn't necessarily big-endian to begin with.
+pub fn eval_builtin_bswap(
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    eval_builtin_byte_swap("bswap", query)
+}
+
+
+/// Reverses the byte order of a value, using its own size.
+fn eval_builtin_byte_swap(
+    fn_name: &str,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
 {
     query.ensure_arg_number(1)?;
 
@@ -131,7 +189,9 @@ pub fn eval_builtin_le(
     if bigint.size.unwrap() % 8 != 0
     {
         query.report.push_parent(
-            "argument to `le` must have a size multiple of 8",
+            format!(
+                "argument to `{}` must have a size multiple of 8",
+                fn_name),
             query.args[0].span);
 
         query.report.note(format!(
@@ -180,10 +240,30 @@ pub fn eval_builtin_string_encoding(
 }
 
 
+/// Encodes one byte per character, rejecting
+/// any character beyond 0x7f
 pub fn eval_builtin_ascii(
     query: &mut expr::EvalFunctionQuery)
     -> Result<expr::Value, ()>
 {
+    query.ensure_arg_number(1)?;
+
+    let s = query.args[0].value.expect_string(
+        query.report,
+        query.args[0].span)?;
+
+    if let Some(c) = s.utf8_contents.chars().find(|c| !c.is_ascii())
+    {
+        query.report.error_span(
+            format!(
+                "character `{}` (U+{:04X}) is not in ASCII",
+                c,
+                c as u32),
+            query.args[0].span);
+
+        return Err(());
+    }
+
     eval_builtin_string_encoding("ascii", query)
 }
 
@@ -241,3 +321,398 @@ pub fn eval_builtin_strlen(
     Ok(expr::Value::make_integer(s.utf8_contents.len()))
 
 }
+
+
+/// Computes the even-parity bit of a sized value,
+/// which is 1 when the value has an odd number of set bits.
+pub fn eval_builtin_parity(
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    query.ensure_arg_number(1)?;
+
+    let bigint = query.args[0].value.expect_sized_bigint(
+        query.report,
+        query.args[0].span)?;
+
+    let mut parity = false;
+
+    for i in 0..bigint.size.unwrap()
+    {
+        parity ^= bigint.get_bit(i);
+    }
+
+    Ok(expr::Value::make_integer(
+        util::BigInt::new(parity as u8, Some(1))))
+}
+
+
+pub fn get_static_size_builtin_parity(
+    _provider: &expr::StaticallyKnownProvider,
+    args: &Vec<expr::Expr>)
+    -> Option<usize>
+{
+    if args.len() == 1
+    {
+        Some(1)
+    }
+    else
+    {
+        None
+    }
+}
+
+
+/// Computes the Hamming code parity bits for a data word
+/// of `data_bits` bits. The data bits are laid out, from
+/// least significant onward, in the codeword positions that
+/// aren't powers of two, and parity bit `i` covers every
+/// position with bit `i` set, for even parity.
+pub fn eval_builtin_hamming(
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    query.ensure_arg_number(2)?;
+
+    let data_bits = expect_builtin_size_arg(query, 1, 1)?;
+
+    let bigint = query.args[0].value.expect_bigint(
+        query.report,
+        query.args[0].span)?;
+
+    if bigint.sign() < 0
+    {
+        query.report.error_span(
+            "argument to `hamming` must not be negative",
+            query.args[0].span);
+
+        return Err(());
+    }
+
+    if bigint.min_size() > data_bits
+    {
+        query.report.push_parent(
+            "argument to `hamming` doesn't fit in the data width",
+            query.args[0].span);
+
+        query.report.note(format!(
+            "data width is {}, got size {}",
+            data_bits,
+            bigint.min_size()));
+
+        query.report.pop_parent();
+
+        return Err(());
+    }
+
+    let parity_bits = get_hamming_parity_bit_count(data_bits);
+
+    let mut result = util::BigInt::new(0, Some(parity_bits));
+    let mut position: usize = 0;
+
+    // Data bits above the value's size are all zero
+    // and don't affect any parity bit
+    for data_index in 0..bigint.min_size()
+    {
+        // Skip the positions reserved for parity bits
+        position += 1;
+        while position.is_power_of_two()
+        {
+            position += 1;
+        }
+
+        if !bigint.get_bit(data_index)
+        {
+            continue;
+        }
+
+        for parity_index in 0..parity_bits
+        {
+            if position & (1 << parity_index) != 0
+            {
+                let bit = result.get_bit(parity_index);
+                result.set_bit(parity_index, !bit);
+            }
+        }
+    }
+
+    Ok(expr::Value::make_integer(result))
+}
+
+
+pub fn get_static_size_builtin_hamming(
+    _provider: &expr::StaticallyKnownProvider,
+    args: &Vec<expr::Expr>)
+    -> Option<usize>
+{
+    if args.len() == 2
+    {
+        let data_bits = try_get_static_builtin_size_arg(&args[1], 1)?;
+
+        Some(get_hamming_parity_bit_count(data_bits))
+    }
+    else
+    {
+        None
+    }
+}
+
+
+pub fn eval_builtin_lo(
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    eval_builtin_lo_hi("lo", query)
+}
+
+
+pub fn eval_builtin_hi(
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    eval_builtin_lo_hi("hi", query)
+}
+
+
+/// Extracts the low or high byte of a value of the given
+/// width in bits, which defaults to 16.
+fn eval_builtin_lo_hi(
+    fn_name: &str,
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    query.ensure_min_max_arg_number(1, 2)?;
+
+    let width = {
+        if query.args.len() == 2
+        {
+            let width = query.args[1].value.expect_usize(
+                query.report,
+                query.args[1].span)?;
+
+            if width < 8
+            {
+                query.report.error_span(
+                    format!(
+                        "width for `{}` must be at least 8 bits",
+                        fn_name),
+                    query.args[1].span);
+
+                return Err(());
+            }
+
+            width
+        }
+        else
+        {
+            16
+        }
+    };
+
+    let bigint = query.args[0].value.expect_bigint(
+        query.report,
+        query.args[0].span)?;
+
+    if bigint.min_size() > width
+    {
+        query.report.push_parent(
+            format!(
+                "argument to `{}` doesn't fit in the width",
+                fn_name),
+            query.args[0].span);
+
+        query.report.note(format!(
+            "width is {}, got size {}",
+            width,
+            bigint.min_size()));
+
+        query.report.pop_parent();
+
+        return Err(());
+    }
+
+    let (left, right) = {
+        if fn_name == "hi"
+        {
+            (width, width - 8)
+        }
+        else
+        {
+            (8, 0)
+        }
+    };
+
+    Ok(expr::Value::make_integer(
+        bigint.slice(left, right)))
+}
+
+
+pub fn get_static_size_builtin_lo_hi(
+    _provider: &expr::StaticallyKnownProvider,
+    args: &Vec<expr::Expr>)
+    -> Option<usize>
+{
+    if args.len() == 1 || args.len() == 2
+    {
+        Some(8)
+    }
+    else
+    {
+        None
+    }
+}
+
+
+/// Converts a value to packed binary-coded decimal,
+/// with 4 bits for each of the given number of digits.
+pub fn eval_builtin_bcd(
+    query: &mut expr::EvalFunctionQuery)
+    -> Result<expr::Value, ()>
+{
+    query.ensure_arg_number(2)?;
+
+    let digits = expect_builtin_size_arg(query, 1, 4)?;
+
+    let bigint = query.args[0].value.expect_bigint(
+        query.report,
+        query.args[0].span)?;
+
+    if bigint.sign() < 0
+    {
+        query.report.error_span(
+            "argument to `bcd` must not be negative",
+            query.args[0].span);
+
+        return Err(());
+    }
+
+    let ten = util::BigInt::from(10);
+
+    let mut remaining = bigint.clone();
+    let mut result = util::BigInt::new(0, Some(digits * 4));
+
+    for digit_index in 0..digits
+    {
+        if remaining.sign() == 0
+        {
+            break;
+        }
+
+        let digit = remaining
+            .checked_mod(query.report, query.args[0].span, &ten)?
+            .checked_into::<usize>(query.report, query.args[0].span)?;
+
+        remaining = remaining.checked_div(
+            query.report,
+            query.args[0].span,
+            &ten)?;
+
+        for bit_index in 0..4
+        {
+            result.set_bit(
+                digit_index * 4 + bit_index,
+                (digit >> bit_index) & 1 != 0);
+        }
+    }
+
+    if remaining.sign() != 0
+    {
+        query.report.push_parent(
+            "argument to `bcd` has too many decimal digits",
+            query.args[0].span);
+
+        query.report.note(format!(
+            "digit count is {}, got {}",
+            digits,
+            bigint.to_string().len()));
+
+        query.report.pop_parent();
+
+        return Err(());
+    }
+
+    Ok(expr::Value::make_integer(result))
+}
+
+
+pub fn get_static_size_builtin_bcd(
+    _provider: &expr::StaticallyKnownProvider,
+    args: &Vec<expr::Expr>)
+    -> Option<usize>
+{
+    if args.len() == 2
+    {
+        let digits = try_get_static_builtin_size_arg(&args[1], 4)?;
+
+        Some(digits * 4)
+    }
+    else
+    {
+        None
+    }
+}
+
+
+/// The number of parity bits `r` needed for `data_bits`,
+/// the smallest one where `2^r >= data_bits + r + 1`.
+fn get_hamming_parity_bit_count(
+    data_bits: usize)
+    -> usize
+{
+    let mut parity_bits = 2;
+
+    while (1 << parity_bits) < data_bits + parity_bits + 1
+    {
+        parity_bits += 1;
+    }
+
+    parity_bits
+}
+
+
+/// Reads a nonzero count argument, such as a digit count
+/// or a bit width, which sizes the result at `unit_bits`
+/// bits per count, and rejects counts whose result would
+/// exceed the supported integer size.
+fn expect_builtin_size_arg(
+    query: &mut expr::EvalFunctionQuery,
+    arg_index: usize,
+    unit_bits: usize)
+    -> Result<usize, ()>
+{
+    let count = query.args[arg_index].value.expect_nonzero_usize(
+        query.report,
+        query.args[arg_index].span)?;
+
+    match count.checked_mul(unit_bits)
+    {
+        Some(bits) if (bits as u64) < util::BIGINT_MAX_BITS =>
+            Ok(count),
+
+        _ =>
+        {
+            query.report.error_span(
+                "value is out of supported range",
+                query.args[arg_index].span);
+
+            Err(())
+        }
+    }
+}
+
+
+fn try_get_static_builtin_size_arg(
+    arg: &expr::Expr,
+    unit_bits: usize)
+    -> Option<usize>
+{
+    let count = arg.try_eval_usize()?;
+
+    match count.checked_mul(unit_bits)
+    {
+        Some(bits) if count != 0 && (bits as u64) < util::BIGINT_MAX_BITS =>
+            Some(count),
+
+        _ => None,
+    }
+}
@@ -278,6 +278,11 @@ pub fn dummy_eval_var(
 	query: &mut EvalVariableQuery)
 	-> Result<expr::Value, ()>
 {
+	if let Some(builtin) = expr::resolve_builtin_fn_variable(query)
+	{
+		return Ok(builtin);
+	}
+
 	query.report.error_span(
 		"cannot reference variables in this context",
 		query.span);
@@ -419,14 +424,10 @@ impl expr::Expr
 					span,
 				};
 
+				// Builtin functions are resolved by the provider,
+				// after any symbols that would shadow them
 				if hierarchy_level == 0 && hierarchy.len() == 1
 				{
-					if let Some(_) = expr::resolve_builtin_fn(&hierarchy[0])
-					{
-						return Ok(expr::Value::ExprBuiltInFunction(
-							hierarchy[0].clone()));
-					}
-
 					if let Ok(local_value) = ctx.get_local(&hierarchy[0])
 					{
 						return Ok(local_value);
@@ -579,6 +580,13 @@ impl expr::Expr
 											span,
 											rhs)?)),
 								
+								expr::BinaryOp::Pow =>
+									Ok(expr::Value::make_integer(
+										lhs.checked_pow(
+											report,
+											span,
+											rhs)?)),
+								
 								expr::BinaryOp::Shl =>
 									Ok(expr::Value::make_integer(
 										lhs.checked_shl(
@@ -596,6 +604,16 @@ impl expr::Expr
 								expr::BinaryOp::And  => Ok(expr::Value::make_integer(lhs & rhs)),
 								expr::BinaryOp::Or   => Ok(expr::Value::make_integer(lhs | rhs)),
 								expr::BinaryOp::Xor  => Ok(expr::Value::make_integer(lhs ^ rhs)),
+
+								// Comparisons always act on the mathematical value
+								// of the operands, regardless of their sizes.
+								// Arguments bound to `s` and `i` typed parameters
+								// keep the sign they were written with, so they
+								// compare as signed (`-1 < 0`), while `u` typed
+								// arguments are never negative and so compare
+								// as unsigned. Slicing (`x`8`) or concatenation
+								// produce non-negative values, and thus discard
+								// any sign.
 								expr::BinaryOp::Eq   => Ok(expr::Value::Bool(lhs == rhs)),
 								expr::BinaryOp::Ne   => Ok(expr::Value::Bool(lhs != rhs)),
 								expr::BinaryOp::Lt   => Ok(expr::Value::Bool(lhs <  rhs)),
@@ -742,6 +760,52 @@ impl expr::Expr
 				}
 			}
 			
+			&expr::Expr::Match(_, ref value_expr, ref arms, ref else_arm) =>
+			{
+				let value = propagate!(
+					value_expr.eval_with_ctx(report, ctx, provider)?);
+
+				for arm in arms
+				{
+					let pattern = propagate!(
+						arm.pattern.eval_with_ctx(report, ctx, provider)?);
+
+					let is_match = {
+						match (&value, &pattern)
+						{
+							(expr::Value::Bool(value), expr::Value::Bool(pattern)) =>
+								value == pattern,
+
+							_ =>
+							{
+								match (value.get_bigint(), pattern.get_bigint())
+								{
+									(Some(value), Some(pattern)) => value == pattern,
+									_ => return Err(report.error_span(
+										"invalid pattern type for matched value",
+										arm.pattern.span())),
+								}
+							}
+						}
+					};
+
+					if is_match
+					{
+						return Ok(propagate!(
+							arm.value.eval_with_ctx(report, ctx, provider)?));
+					}
+				}
+
+				match else_arm
+				{
+					Some(else_arm) => Ok(propagate!(
+						else_arm.eval_with_ctx(report, ctx, provider)?)),
+					None => Err(report.error_span(
+						"value not covered by any `match` arm",
+						value_expr.span())),
+				}
+			}
+			
 			&expr::Expr::Asm(span, ref ast) =>
 			{
 				let mut query = EvalAsmBlockQuery {
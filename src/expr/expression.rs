@@ -13,6 +13,7 @@ pub enum Expr
 	SliceShort(diagn::Span, diagn::Span, Box<Expr>, Box<Expr>),
 	Block(diagn::Span, Vec<Expr>),
 	Call(diagn::Span, Box<Expr>, Vec<Expr>),
+	Match(diagn::Span, Box<Expr>, Vec<MatchArm>, Option<Box<Expr>>),
 	Asm(diagn::Span, asm::AstTopLevel),
 }
 
@@ -32,6 +33,16 @@ pub enum Value
 }
 
 
+/// An arm of a `match` expression, selected when
+/// the matched value equals `pattern`
+#[derive(Clone, Debug)]
+pub struct MatchArm
+{
+	pub pattern: Expr,
+	pub value: Expr,
+}
+
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ExprString
 {
@@ -53,7 +64,7 @@ pub enum BinaryOp
 {
 	Assign,
 	
-	Add, Sub, Mul, Div, Mod,
+	Add, Sub, Mul, Div, Mod, Pow,
 	Shl, Shr,
 	And, Or, Xor,
 	
@@ -88,6 +99,7 @@ impl Expr
 			&Expr::SliceShort(span, ..) => span,
 			&Expr::Block     (span, ..) => span,
 			&Expr::Call      (span, ..) => span,
+			&Expr::Match     (span, ..) => span,
 			&Expr::Asm       (span, ..) => span,
 		}
 	}
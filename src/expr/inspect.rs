@@ -158,6 +158,30 @@ impl expr::Expr
 				}
 			}
 
+			expr::Expr::Match(_, _, ref arms, ref else_arm) =>
+			{
+				let mut size = None;
+
+				let values = arms
+					.iter()
+					.map(|arm| &arm.value)
+					.chain(else_arm.iter().map(|e| e.as_ref()));
+
+				for value in values
+				{
+					let value_size = value.get_static_size(provider)?;
+
+					if size.is_some() && size != Some(value_size)
+					{
+						return None;
+					}
+
+					size = Some(value_size);
+				}
+
+				size
+			}
+
 			expr::Expr::Asm(..) => None,
 		}
 	}
@@ -270,11 +294,104 @@ impl expr::Expr
 				}
 			}
 
+			expr::Expr::Match(_, ref value, ref arms, ref else_arm) =>
+			{
+				if !value.is_value_statically_known(provider)
+				{
+					return false;
+				}
+
+				for arm in arms
+				{
+					if !arm.pattern.is_value_statically_known(provider) ||
+						!arm.value.is_value_statically_known(provider)
+					{
+						return false;
+					}
+				}
+
+				match else_arm
+				{
+					Some(else_arm) => else_arm.is_value_statically_known(provider),
+					None => true,
+				}
+			}
+
 			expr::Expr::Asm(..) => false,
 		}
 	}
 	
 	
+	/// Finds the first variable reference, in evaluation order,
+	/// for which `predicate` returns true, and returns its span.
+	pub fn find_variable(
+		&self,
+		predicate: &mut dyn FnMut(usize, &[String]) -> bool)
+		-> Option<diagn::Span>
+	{
+		match self
+		{
+			expr::Expr::Variable(span, hierarchy_level, ref hierarchy) =>
+			{
+				if predicate(*hierarchy_level, hierarchy)
+				{
+					Some(*span)
+				}
+				else
+				{
+					None
+				}
+			}
+
+			expr::Expr::Literal(..) => None,
+
+			expr::Expr::UnaryOp(_, _, _, ref inner) =>
+				inner.find_variable(predicate),
+
+			expr::Expr::BinaryOp(_, _, _, ref lhs, ref rhs) =>
+				lhs.find_variable(predicate)
+					.or_else(|| rhs.find_variable(predicate)),
+
+			expr::Expr::TernaryOp(_, ref condition, ref true_branch, ref false_branch) =>
+				condition.find_variable(predicate)
+					.or_else(|| true_branch.find_variable(predicate))
+					.or_else(|| false_branch.find_variable(predicate)),
+
+			expr::Expr::Slice(_, _, ref left_expr, ref right_expr, ref inner) =>
+				left_expr.find_variable(predicate)
+					.or_else(|| right_expr.find_variable(predicate))
+					.or_else(|| inner.find_variable(predicate)),
+
+			expr::Expr::SliceShort(_, _, ref size_expr, ref inner) =>
+				size_expr.find_variable(predicate)
+					.or_else(|| inner.find_variable(predicate)),
+
+			expr::Expr::Block(_, ref exprs) =>
+				exprs
+					.iter()
+					.find_map(|e| e.find_variable(predicate)),
+
+			expr::Expr::Call(_, ref func, ref args) =>
+				func.find_variable(predicate)
+					.or_else(|| args
+						.iter()
+						.find_map(|e| e.find_variable(predicate))),
+
+			expr::Expr::Match(_, ref value, ref arms, ref else_arm) =>
+				value.find_variable(predicate)
+					.or_else(|| arms
+						.iter()
+						.find_map(|arm| arm.pattern.find_variable(predicate)
+							.or_else(|| arm.value.find_variable(predicate))))
+					.or_else(|| else_arm
+						.as_ref()
+						.and_then(|e| e.find_variable(predicate))),
+
+			expr::Expr::Asm(..) => None,
+		}
+	}
+
+
 	pub fn returned_value_span(&self) -> diagn::Span
 	{
 		match self
@@ -3,6 +3,7 @@ pub use self::expression::{
     Expr,
     Value,
     ExprString,
+    MatchArm,
     UnaryOp,
     BinaryOp,
 };
@@ -38,6 +39,7 @@ pub use self::eval::{
 mod builtin_fn;
 pub use self::builtin_fn::{
     resolve_builtin_fn,
+    resolve_builtin_fn_variable,
     eval_builtin_fn,
     get_static_size_builtin_fn,
     get_statically_known_value_builtin_fn,
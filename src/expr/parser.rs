@@ -320,7 +320,33 @@ impl<'a, 'src> ExpressionParser<'a, 'src>
 				(syntax::TokenKind::Slash,    expr::BinaryOp::Div),
 				(syntax::TokenKind::Percent,  expr::BinaryOp::Mod)
 			],
-			|s| s.parse_slice())
+			|s| s.parse_power())
+	}
+	
+	
+	/// Parses `**`, which is right-associative,
+	/// so `2 ** 3 ** 2` is `2 ** (3 ** 2)`
+	fn parse_power(&mut self) -> Result<expr::Expr, ()>
+	{
+		let lhs = self.parse_slice()?;
+		
+		if self.walker.next_linebreak().is_some()
+			{ return Ok(lhs); }
+		
+		let tk_op = match self.walker.maybe_expect(syntax::TokenKind::DoubleAsterisk)
+		{
+			Some(tk) => tk,
+			None => return Ok(lhs)
+		};
+		
+		let rhs = self.parse_power()?;
+		
+		Ok(expr::Expr::BinaryOp(
+			lhs.span().join(rhs.span()),
+			tk_op.span,
+			expr::BinaryOp::Pow,
+			Box::new(lhs),
+			Box::new(rhs)))
 	}
 	
 	
@@ -384,6 +410,9 @@ impl<'a, 'src> ExpressionParser<'a, 'src>
 	
 	fn parse_unary(&mut self) -> Result<expr::Expr, ()>
 	{
+		if let Some(expr) = self.maybe_parse_anonymous_label()
+			{ return Ok(expr); }
+
 		self.parse_unary_ops(
 			&[
 				(syntax::TokenKind::Exclamation, expr::UnaryOp::Not),
@@ -393,6 +422,46 @@ impl<'a, 'src> ExpressionParser<'a, 'src>
 	}
 	
 	
+	/// Parses a reference to an anonymous label, like `++`
+	/// or `-`, which is a run of the same sign that
+	/// ends the current operand.
+	fn maybe_parse_anonymous_label(&mut self) -> Option<expr::Expr>
+	{
+		let tk_first = self.walker.next_nth_useful_token(0);
+		if tk_first.kind != syntax::TokenKind::Plus &&
+			tk_first.kind != syntax::TokenKind::Minus
+			{ return None; }
+
+		let mut count = 1;
+		while !self.walker.next_linebreak_after(count) &&
+			self.walker.next_useful_is(count, tk_first.kind)
+			{ count += 1; }
+
+		if !self.walker.next_linebreak_after(count)
+		{
+			match self.walker.next_nth_useful_token(count).kind
+			{
+				syntax::TokenKind::Comma |
+				syntax::TokenKind::ParenClose |
+				syntax::TokenKind::BracketClose |
+				syntax::TokenKind::BraceClose => {}
+				_ => return None,
+			}
+		}
+
+		let mut span = tk_first.span;
+		for _ in 0..count
+		{
+			let tk_sign = self.walker.maybe_expect(tk_first.kind)?;
+			span = span.join(tk_sign.span);
+		}
+
+		let name = self.walker.get_span_excerpt(tk_first.span).repeat(count);
+
+		Some(expr::Expr::Variable(span, 0, vec![name]))
+	}
+	
+	
 	fn parse_call(&mut self) -> Result<expr::Expr, ()>
 	{
 		let leaf = self.parse_leaf()?;
@@ -422,7 +491,10 @@ impl<'a, 'src> ExpressionParser<'a, 'src>
 	
 	fn parse_leaf(&mut self) -> Result<expr::Expr, ()>
 	{
-		if self.walker.next_useful_is(0, syntax::TokenKind::BraceOpen)
+		if self.next_is_match()
+			{ self.parse_match() }
+
+		else if self.walker.next_useful_is(0, syntax::TokenKind::BraceOpen)
 			{ self.parse_block() }
 	
 		else if self.walker.next_useful_is(0, syntax::TokenKind::ParenOpen)
@@ -439,6 +511,9 @@ impl<'a, 'src> ExpressionParser<'a, 'src>
 			
 		else if self.walker.next_useful_is(0, syntax::TokenKind::String)
 			{ self.parse_string() }
+			
+		else if self.walker.next_useful_is(0, syntax::TokenKind::Char)
+			{ self.parse_char() }
 	
 		else if self.walker.next_useful_is(0, syntax::TokenKind::KeywordAsm)
 			{ self.parse_asm() }
@@ -451,6 +526,8 @@ impl<'a, 'src> ExpressionParser<'a, 'src>
 			
 		else
 		{
+			self.walker.check_unterminated(self.report)?;
+
             self.report.error_span(
                 "expected expression",
                 self.walker.get_cursor_span());
@@ -486,6 +563,114 @@ impl<'a, 'src> ExpressionParser<'a, 'src>
 	}
 	
 	
+	/// `match` is only treated as a keyword when followed
+	/// by the matched expression and then the `{` of its
+	/// arms, so that it stays usable as a symbol or
+	/// function name.
+	fn next_is_match(&mut self) -> bool
+	{
+		let tk_match = self.walker.next_nth_useful_token(0);
+
+		if tk_match.kind != syntax::TokenKind::Identifier ||
+			self.walker.get_span_excerpt(tk_match.span) != "match"
+		{
+			return false;
+		}
+
+		let mut dummy_report = diagn::Report::new();
+		let mut lookahead = self.walker.clone();
+
+		if lookahead.expect(&mut dummy_report, syntax::TokenKind::Identifier).is_err()
+		{
+			return false;
+		}
+
+		let mut lookahead_parser = ExpressionParser::new(
+			&mut dummy_report,
+			&mut lookahead);
+
+		lookahead_parser.recursion_depth = self.recursion_depth;
+
+		lookahead_parser.parse_expr().is_ok() &&
+			lookahead.next_useful_is(0, syntax::TokenKind::BraceOpen)
+	}
+	
+	
+	fn parse_match(&mut self) -> Result<expr::Expr, ()>
+	{
+		let tk_match = self.walker.expect(
+			self.report,
+			syntax::TokenKind::Identifier)?;
+
+		let value = self.parse_expr()?;
+
+		self.walker.expect(self.report, syntax::TokenKind::BraceOpen)?;
+
+		let mut arms = Vec::new();
+		let mut else_arm = None;
+
+		while !self.walker.next_useful_is(0, syntax::TokenKind::BraceClose)
+		{
+			let tk_else = self.walker.next_nth_useful_token(0);
+
+			if tk_else.kind == syntax::TokenKind::Identifier &&
+				self.walker.get_span_excerpt(tk_else.span) == "else" &&
+				self.walker.next_useful_is(1, syntax::TokenKind::HeavyArrowRight)
+			{
+				if else_arm.is_some()
+				{
+					self.report.error_span(
+						"duplicate `else` arm",
+						tk_else.span);
+					
+					return Err(());
+				}
+
+				self.walker.expect(self.report, syntax::TokenKind::Identifier)?;
+				self.walker.expect(self.report, syntax::TokenKind::HeavyArrowRight)?;
+				
+				else_arm = Some(Box::new(self.parse_expr()?));
+			}
+			else
+			{
+				if else_arm.is_some()
+				{
+					self.report.error_span(
+						"`else` arm must be the last one",
+						self.walker.next_nth_useful_token(0).span);
+					
+					return Err(());
+				}
+
+				let pattern = self.parse_expr()?;
+				self.walker.expect(self.report, syntax::TokenKind::HeavyArrowRight)?;
+				let value = self.parse_expr()?;
+
+				arms.push(expr::MatchArm {
+					pattern,
+					value,
+				});
+			}
+			
+			if self.walker.maybe_expect_linebreak().is_some()
+				{ continue; }
+				
+			if self.walker.next_useful_is(0, syntax::TokenKind::BraceClose)
+				{ break; }
+				
+			self.walker.expect(self.report, syntax::TokenKind::Comma)?;
+		}
+		
+		let tk_close = self.walker.expect(self.report, syntax::TokenKind::BraceClose)?;
+
+		Ok(expr::Expr::Match(
+			tk_match.span.join(tk_close.span),
+			Box::new(value),
+			arms,
+			else_arm))
+	}
+	
+	
 	fn parse_parenthesized(&mut self) -> Result<expr::Expr, ()>
 	{
 		self.walker.expect(self.report, syntax::TokenKind::ParenOpen)?;
@@ -608,6 +793,24 @@ impl<'a, 'src> ExpressionParser<'a, 'src>
 	}
 	
 	
+	fn parse_char(&mut self) -> Result<expr::Expr, ()>
+	{
+		let tk_char = self.walker.expect(self.report, syntax::TokenKind::Char)?;
+
+		let c = syntax::excerpt_as_char(
+			self.report,
+			tk_char.span,
+			self.walker.get_span_excerpt(tk_char.span))?;
+		
+		let expr = expr::Expr::Literal(
+			tk_char.span,
+			expr::Value::make_integer(
+				util::BigInt::new(c as u32, None)));
+
+		Ok(expr)
+	}
+	
+	
 	fn parse_asm(&mut self) -> Result<expr::Expr, ()>
 	{
 		let tk_asm = self.walker.expect(
@@ -9,17 +9,29 @@ pub fn excerpt_as_string_contents(
 {
 	assert!(excerpt.len() >= 2);
 	
-	let mut chars = excerpt[1..(excerpt.len() - 1)].chars().peekable();
+	let contents = &excerpt[1..(excerpt.len() - 1)];
+	let mut chars = contents.char_indices().peekable();
 	
 	let mut result = String::new();
 	
-	while let Some(c) = chars.peek().map(|c| *c)
+	while let Some((index, c)) = chars.next()
 	{
-		chars.next();
-		
 		let unescaped = if c == '\\'
 		{
-			match chars.next()
+			// Reports the escape sequence from its backslash
+			// up to the last character consumed so far
+			let mut invalid = |chars: &mut std::iter::Peekable<std::str::CharIndices>|
+			{
+				let end = chars
+					.peek()
+					.map_or(contents.len(), |&(i, _)| i);
+
+				report.error_span(
+					"invalid escape sequence",
+					escape_span(span, index, end));
+			};
+
+			match chars.next().map(|(_, c)| c)
 			{
 				Some('0')  => '\0',
 				Some('t')  => '\t',
@@ -37,15 +49,15 @@ pub fn excerpt_as_string_contents(
 					{
 						byte <<= 4;
 						
-						byte += match chars.next().map(|c| c.to_digit(16))
+						byte += match chars.next().map(|(_, c)| c.to_digit(16))
 						{
 							Some(Some(d)) => d as u8,
-							_ => return Err(report.error_span("invalid escape sequence", span))
+							_ => return Err(invalid(&mut chars))
 						};
 					}
 					
 					if byte > 0x7f
-						{ return Err(report.error_span("invalid escape sequence", span)); }
+						{ return Err(invalid(&mut chars)); }
 					
 					byte as char
 				}
@@ -54,27 +66,27 @@ pub fn excerpt_as_string_contents(
 				{
 					let mut codepoint = 0u32;
 					
-					if chars.next() != Some('{')
-						{ return Err(report.error_span("invalid escape sequence", span)); }
+					if chars.next().map(|(_, c)| c) != Some('{')
+						{ return Err(invalid(&mut chars)); }
 					
 					let mut i = 0;
 					loop
 					{
 						if i > 6
-							{ return Err(report.error_span("invalid escape sequence", span)); }
+							{ return Err(invalid(&mut chars)); }
 							
 						i += 1;
 						
-						let digit = match chars.next()
+						let digit = match chars.next().map(|(_, c)| c)
 						{
 							Some('}') => break,
 							Some(c) => match c.to_digit(16)
 							{
 								Some(d) => d,
-								None => return Err(report.error_span("invalid escape sequence", span))
+								None => return Err(invalid(&mut chars))
 							}
 							
-							None => return Err(report.error_span("invalid escape sequence", span))
+							None => return Err(invalid(&mut chars))
 						};
 						
 						codepoint <<= 4;						
@@ -85,12 +97,12 @@ pub fn excerpt_as_string_contents(
 					match char::from_u32(codepoint)
 					{
 						Some(c) => c,
-						None => return Err(report.error_span("invalid escape sequence", span))
+						None => return Err(invalid(&mut chars))
 					}
 				}
 				
 				Some(_) |
-				None => return Err(report.error_span("invalid escape sequence", span))
+				None => return Err(invalid(&mut chars))
 			}
 		}
 		else
@@ -103,6 +115,56 @@ pub fn excerpt_as_string_contents(
 }
 
 
+/// Narrows the span of a quoted excerpt to the escape
+/// sequence between the given indices of its contents
+fn escape_span(
+	span: diagn::Span,
+	start: usize,
+	end: usize)
+	-> diagn::Span
+{
+	match span.location()
+	{
+		None => span,
+		Some((excerpt_start, _)) => diagn::Span::new(
+			span.file_handle,
+			excerpt_start + 1 + start,
+			excerpt_start + 1 + end),
+	}
+}
+
+
+
+/// Decodes a character literal, such as `'a'` or `'\n'`,
+/// into its Unicode code point
+pub fn excerpt_as_char(
+	report: &mut diagn::Report,
+	span: diagn::Span,
+	excerpt: &str)
+	-> Result<char, ()>
+{
+	let contents = excerpt_as_string_contents(
+		report,
+		span,
+		excerpt)?;
+
+	let mut chars = contents.chars();
+
+	match (chars.next(), chars.next())
+	{
+		(Some(c), None) => Ok(c),
+		_ =>
+		{
+			report.error_span(
+				"character literal must contain exactly one character",
+				span);
+
+			Err(())
+		}
+	}
+}
+
+
 
 pub fn excerpt_as_usize(
 	report: &mut diagn::Report,
@@ -130,8 +192,8 @@ pub fn excerpt_as_usize(
 			None => 
 			{
 				report.error_span(
-					"invalid digits",
-					span);
+					format!("invalid digit `{}` for base {}", c, radix),
+					digit_span(span, &chars, index - 1));
 				
 				return Err(());
 			}
@@ -198,8 +260,8 @@ pub fn excerpt_as_bigint(
 				if let Some(report) = report
 				{
 					report.error_span(
-						"invalid digits",
-						span);
+						format!("invalid digit `{}` for base {}", c, radix),
+						digit_span(span, &chars, index - 1));
 				}
 				
 				return Err(());
@@ -242,6 +304,33 @@ pub fn excerpt_as_bigint(
 }
 
 
+/// Narrows the span of a number excerpt to
+/// the character at `index`
+fn digit_span(
+	span: diagn::Span,
+	chars: &[char],
+	index: usize)
+	-> diagn::Span
+{
+	match span.location()
+	{
+		None => span,
+		Some((start, _)) =>
+		{
+			let offset: usize = chars[..index]
+				.iter()
+				.map(|c| c.len_utf8())
+				.sum();
+
+			diagn::Span::new(
+				span.file_handle,
+				start + offset,
+				start + offset + chars[index].len_utf8())
+		}
+	}
+}
+
+
 fn parse_radix(chars: &[char], index: usize) -> (usize, usize)
 {
 	if chars[index] == '0' && index + 1 < chars.len()
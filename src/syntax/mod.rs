@@ -2,6 +2,7 @@ mod token;
 pub use self::token::{
     Token,
     TokenKind,
+    CommentStyles,
     decide_next_token,
     is_whitespace,
 };
@@ -12,6 +13,7 @@ pub use self::walker::Walker;
 mod excerpt;
 pub use self::excerpt::{
     excerpt_as_string_contents,
+    excerpt_as_char,
     excerpt_as_usize,
     excerpt_as_bigint,
 };
\ No newline at end of file
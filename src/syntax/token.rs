@@ -15,10 +15,13 @@ pub enum TokenKind
 	Error,
 	Whitespace,
 	Comment,
+	CommentUnterminated,
 	LineBreak,
 	Identifier,
 	Number,
 	String,
+	StringUnterminated,
+	Char,
 	KeywordAsm,
 	KeywordTrue,
 	KeywordFalse,
@@ -40,6 +43,7 @@ pub enum TokenKind
 	Plus,
 	Minus,
 	Asterisk,
+	DoubleAsterisk,
 	Slash,
 	Percent,
 	Question,
@@ -70,6 +74,7 @@ impl TokenKind
 	{
 		self == TokenKind::Whitespace ||
 		self == TokenKind::Comment ||
+		self == TokenKind::CommentUnterminated ||
 		self == TokenKind::LineBreak
 	}
 	
@@ -93,6 +98,7 @@ impl TokenKind
 		self == TokenKind::Plus ||
 		self == TokenKind::Minus ||
 		self == TokenKind::Asterisk ||
+		self == TokenKind::DoubleAsterisk ||
 		self == TokenKind::Slash ||
 		self == TokenKind::Percent ||
 		self == TokenKind::Exclamation ||
@@ -113,10 +119,13 @@ impl TokenKind
 			TokenKind::Error => "error",
 			TokenKind::Whitespace => "whitespace",
 			TokenKind::Comment => "comment",
+			TokenKind::CommentUnterminated => "unterminated comment",
 			TokenKind::LineBreak => "line break",
 			TokenKind::Identifier => "identifier",
 			TokenKind::Number => "number",
 			TokenKind::String => "string",
+			TokenKind::StringUnterminated => "unterminated string",
+			TokenKind::Char => "character",
 			TokenKind::KeywordAsm => "`asm` keyword",
 			TokenKind::KeywordTrue => "`true` keyword",
 			TokenKind::KeywordFalse => "`false` keyword",
@@ -138,6 +147,7 @@ impl TokenKind
 			TokenKind::Plus => "`+`",
 			TokenKind::Minus => "`-`",
 			TokenKind::Asterisk => "`*`",
+			TokenKind::DoubleAsterisk => "`**`",
 			TokenKind::Slash => "`/`",
 			TokenKind::Percent => "`%`",
 			TokenKind::Question => "`?`",
@@ -164,17 +174,46 @@ impl TokenKind
 }
 
 
+/// The comment styles recognized by the tokenizer,
+/// which a file can choose with `#commentstyle`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CommentStyles
+{
+	/// `; line` and `;* block *;` comments
+	pub semicolon: bool,
+	/// `// line` comments
+	pub double_slash: bool,
+	/// `/* block */` comments, which can be nested
+	pub slash_asterisk: bool,
+}
+
+
+impl CommentStyles
+{
+	pub fn new() -> CommentStyles
+	{
+		CommentStyles {
+			semicolon: true,
+			double_slash: false,
+			slash_asterisk: true,
+		}
+	}
+}
+
+
 pub fn decide_next_token(
-	src: &str)
+	src: &str,
+	comment_styles: CommentStyles)
 	-> (TokenKind, usize)
 {
 	check_for_whitespace(src).unwrap_or_else(||
-	check_for_comment   (src).unwrap_or_else(||
+	check_for_comment   (src, comment_styles).unwrap_or_else(||
 	check_for_number    (src).unwrap_or_else(||
 	check_for_identifier(src).unwrap_or_else(||
 	check_for_special   (src).unwrap_or_else(||
 	check_for_string    (src).unwrap_or_else(||
-	(TokenKind::Error, 1)))))))
+	check_for_char_literal(src).unwrap_or_else(||
+	(TokenKind::Error, src.chars().next().map_or(1, |c| c.len_utf8())))))))))
 }
 
 
@@ -290,6 +329,18 @@ impl<'a> CharWalker<'a>
 	}
 
 
+	/// Like `consume_until_char`, but skips over
+	/// characters escaped by a backslash
+	pub fn consume_until_unescaped_char(&mut self, wanted: char)
+	{
+		while !self.ended() && self.current != wanted
+		{
+			self.consume_char('\\');
+			self.advance();
+		}
+	}
+
+
 	pub fn consume_until_char(&mut self, wanted: char)
 	{
 		while !self.ended() && self.current != wanted
@@ -315,11 +366,58 @@ fn check_for_whitespace(src: &str) -> Option<(TokenKind, usize)>
 }
 
 
-fn check_for_comment(src: &str) -> Option<(TokenKind, usize)>
+fn check_for_comment(
+	src: &str,
+	comment_styles: CommentStyles)
+	-> Option<(TokenKind, usize)>
 {
 	let mut walker = CharWalker::new(src);
+
+	if comment_styles.double_slash &&
+		walker.consume_str("//")
+	{
+		walker.consume_until_char('\n');
+		return Some((TokenKind::Comment, walker.length));
+	}
+
+	if comment_styles.slash_asterisk &&
+		walker.consume_str("/*")
+	{
+		let mut nesting = 0;
+
+		loop
+		{
+			if walker.ended()
+			{
+				// Runs until the end, so that the
+				// error can be reported at the start
+				return Some((TokenKind::CommentUnterminated, walker.length));
+			}
+
+			else if walker.consume_str("/*")
+			{
+				nesting += 1;
+			}
+			
+			else if walker.consume_str("*/")
+			{
+				if nesting == 0
+					{ break; }
+
+				nesting -= 1;
+			}
+
+			else
+			{
+				walker.advance();
+			}
+		}
+
+		return Some((TokenKind::Comment, walker.length));
+	}
 	
-	if !walker.consume_char(';')
+	if !comment_styles.semicolon ||
+		!walker.consume_char(';')
 		{ return None; }
 
 	if walker.consume_char('*')
@@ -443,18 +541,45 @@ fn check_for_string(src: &str) -> Option<(TokenKind, usize)>
 	if !walker.consume_char('\"')
 		{ return None; }
 		
-	walker.consume_until_char('\"');
+	walker.consume_until_unescaped_char('\"');
 		
+	// Runs until the end, so that the error
+	// can be reported at the start
 	if !walker.consume_char('\"')
-		{ return None; }
+		{ return Some((TokenKind::StringUnterminated, walker.length)); }
 		
 	Some((TokenKind::String, walker.length))
 }
 
 
+fn check_for_char_literal(src: &str) -> Option<(TokenKind, usize)>
+{
+	let mut walker = CharWalker::new(src);
+
+	if !walker.consume_char('\'')
+		{ return None; }
+
+	// Skip over escaped quotes, such as in `'\''`
+	while !walker.ended() &&
+		walker.current != '\'' &&
+		walker.current != '\n'
+	{
+		if walker.consume_char('\\') && walker.current == '\n'
+			{ break; }
+
+		walker.advance();
+	}
+		
+	if !walker.consume_char('\'')
+		{ return None; }
+		
+	Some((TokenKind::Char, walker.length))
+}
+
+
 fn check_for_special(src: &str) -> Option<(TokenKind, usize)>
 {
-	static TOKENS: [(&str, TokenKind); 40] =
+	static TOKENS: [(&str, TokenKind); 41] =
 	[
 		("\n",  TokenKind::LineBreak),
 		("(",   TokenKind::ParenOpen),
@@ -473,6 +598,7 @@ fn check_for_special(src: &str) -> Option<(TokenKind, usize)>
 		("#",   TokenKind::Hash),
 		("+",   TokenKind::Plus),
 		("-",   TokenKind::Minus),
+		("**",  TokenKind::DoubleAsterisk),
 		("*",   TokenKind::Asterisk),
 		("/",   TokenKind::Slash),
 		("%",   TokenKind::Percent),
@@ -520,20 +646,39 @@ pub fn is_whitespace(c: char) -> bool
 }
 
 
+/// Also accepts non-ASCII letters, like in `café`,
+/// approximating Unicode's `XID_Start`
 fn is_identifier_start(c: char) -> bool
 {
 	(c >= 'a' && c <= 'z') ||
 	(c >= 'A' && c <= 'Z') ||
-	c == '_'
+	c == '_' ||
+	(!c.is_ascii() && c.is_alphabetic())
 }
 
 
+/// Also accepts non-ASCII letters and digits,
+/// approximating Unicode's `XID_Continue`
 fn is_identifier_mid(c: char) -> bool
 {
 	(c >= 'a' && c <= 'z') ||
 	(c >= 'A' && c <= 'Z') ||
 	(c >= '0' && c <= '9') ||
-	c == '_'
+	c == '_' ||
+	(!c.is_ascii() && (c.is_alphanumeric() || is_combining_mark(c)))
+}
+
+
+/// Whether the character is in one of the common blocks
+/// of combining marks, which can follow a letter, as in
+/// a decomposed `é`
+fn is_combining_mark(c: char) -> bool
+{
+	('\u{0300}'..='\u{036f}').contains(&c) ||
+	('\u{1ab0}'..='\u{1aff}').contains(&c) ||
+	('\u{1dc0}'..='\u{1dff}').contains(&c) ||
+	('\u{20d0}'..='\u{20ff}').contains(&c) ||
+	('\u{fe20}'..='\u{fe2f}').contains(&c)
 }
 
 
@@ -12,6 +12,12 @@ pub struct Walker<'src>
     cursor_index: usize,
     /// The walker ignores characters from this byte index onward.
     cursor_limit: usize,
+
+    comment_styles: syntax::CommentStyles,
+
+    /// The first unterminated block comment or string
+    /// seen while tokenizing, to be reported by the parser
+    unterminated: std::cell::Cell<Option<(syntax::TokenKind, diagn::Span)>>,
 }
 
 
@@ -30,6 +36,9 @@ impl<'src> Walker<'src>
 
             cursor_index: 0,
             cursor_limit: src.len(),
+
+            comment_styles: syntax::CommentStyles::new(),
+            unterminated: std::cell::Cell::new(None),
 		};
 		
 		walker
@@ -51,10 +60,51 @@ impl<'src> Walker<'src>
 
             cursor_index: 0,
             cursor_limit: src.len(),
+
+            comment_styles: self.comment_styles,
+            unterminated: std::cell::Cell::new(None),
 		};
 		
 		walker
     }
+
+
+    /// Sets the comment styles recognized from
+    /// the cursor onward.
+    pub fn set_comment_styles(
+        &mut self,
+        comment_styles: syntax::CommentStyles)
+    {
+        self.comment_styles = comment_styles;
+    }
+	
+	
+    /// Reports an unterminated block comment or string,
+    /// if one was seen, at the span of its opening.
+    pub fn check_unterminated(
+        &self,
+        report: &mut diagn::Report)
+        -> Result<(), ()>
+    {
+        match self.unterminated.get()
+        {
+            None => Ok(()),
+            Some((kind, span)) =>
+            {
+                report.error_span(
+                    match kind
+                    {
+                        syntax::TokenKind::StringUnterminated =>
+                            "unterminated string literal",
+                        _ =>
+                            "unterminated block comment",
+                    },
+                    span);
+
+                Err(())
+            }
+        }
+    }
 	
 	
 	pub fn is_over(&self) -> bool
@@ -242,7 +292,9 @@ impl<'src> Walker<'src>
         }
 
         let src_next = &self.src[byte_index..self.cursor_limit];
-        let (kind, length) = syntax::decide_next_token(src_next);
+        let (kind, length) = syntax::decide_next_token(
+            src_next,
+            self.comment_styles);
 
         let end = byte_index + length;
 
@@ -250,6 +302,27 @@ impl<'src> Walker<'src>
             self.file_handle,
             self.span_offset + byte_index,
             self.span_offset + end);
+
+        if self.unterminated.get().is_none()
+        {
+            // Point at the opening of a block comment, since it
+            // can span many lines, but at the whole of a string
+            match kind
+            {
+                syntax::TokenKind::CommentUnterminated =>
+                    self.unterminated.set(Some((
+                        kind,
+                        diagn::Span::new(
+                            self.file_handle,
+                            self.span_offset + byte_index,
+                            self.span_offset + byte_index + 2)))),
+
+                syntax::TokenKind::StringUnterminated =>
+                    self.unterminated.set(Some((kind, span))),
+
+                _ => {}
+            }
+        }
         
         syntax::Token {
             kind,
@@ -316,6 +389,38 @@ impl<'src> Walker<'src>
     }
 
 
+    /// Whether the line ends right after skipping
+    /// the next `nth` useful tokens.
+    pub fn next_linebreak_after(
+        &self,
+        mut nth: usize)
+        -> bool
+    {
+        let mut byte_index = self.cursor_index;
+
+        loop
+        {
+            let token = self.token_at(byte_index);
+
+            if byte_index >= self.cursor_limit
+                { return nth == 0; }
+
+            if token.kind == syntax::TokenKind::LineBreak
+                { return nth == 0; }
+
+            if !token.kind.is_ignorable()
+            {
+                if nth == 0
+                    { return false; }
+                
+                nth -= 1;
+            }
+
+            byte_index += token.span.length();
+        }
+    }
+
+
     fn next_useful_token(
         &self)
         -> syntax::Token
@@ -386,6 +491,10 @@ impl<'src> Walker<'src>
 			Some(token) => Ok(token),
 			None =>
 			{
+				// An unterminated comment or string might have
+				// swallowed the expected token, so report it instead
+				self.check_unterminated(report)?;
+
 				report.error_span(
                     format!("expected {}", kind.printable()),
                     self.get_cursor_span());
@@ -32,7 +32,7 @@ fn test_example(filename: &str, hash: &[u8])
 
     println!(
         "{}",
-        output.format_annotated(&fileserver, 16, 2));
+        output.format_annotated(&fileserver, 16, 2, false));
     
     assert_eq!(output_hash[..], *hash);
 }
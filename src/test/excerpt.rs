@@ -76,4 +76,33 @@ fn test_escape_sequences()
 	test("\\u{",        Fail(("test", 1, "invalid")));
 	test("\\u{0",       Fail(("test", 1, "invalid")));
 	test("\\u{0g}",     Fail(("test", 1, "invalid")));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_escape_sequences_mixed()
+{
+	test("a\\nb\\x41\\u{42}\\\"\\\\", Pass("a\nbAB\"\\"));
+}
+
+
+#[test]
+fn test_escape_sequence_error_span()
+{
+	let mut report = diagn::Report::new();
+	let mut fileserver = util::FileServerMock::new();
+	fileserver.add("test", "#d \"ab\\x4g\"");
+
+	use util::FileServer;
+	let file_handle = fileserver.get_handle_unwrap("test");
+	
+	let result = syntax::excerpt_as_string_contents(
+		&mut report,
+		diagn::Span::new(file_handle, 3, 11),
+		"\"ab\\x4g\"");
+
+	assert!(result.is_err());
+
+	let diagnostics = report.diagnostics();
+	assert_eq!(diagnostics.len(), 1);
+	assert_eq!(diagnostics[0].span, Some(diagn::Span::new(file_handle, 6, 10)));
+}
@@ -67,9 +67,28 @@ fn test_literals()
 	test("0o10a", Fail(("test", 1, "invalid")));
 	test("0x10g", Fail(("test", 1, "invalid")));
 	
+	test("0b1010",  Pass(expr::Value::make_integer(util::BigInt::new(0b1010, Some(4)))));
+	test("0o17",    Pass(expr::Value::make_integer(util::BigInt::new(0o17, Some(6)))));
+	test("0o1_7",   Pass(expr::Value::make_integer(util::BigInt::new(0o17, Some(6)))));
+	test("0xff_00", Pass(expr::Value::make_integer(util::BigInt::new(0xff00, Some(16)))));
+	test("0b1020",  Fail(("test", 1, "invalid digit `2` for base 2")));
+	test("0o1_9",   Fail(("test", 1, "invalid digit `9` for base 8")));
+	
 	test("8'5",   Pass(expr::Value::make_integer(util::BigInt::new(0x8, None))));
 	test("8'0x0", Pass(expr::Value::make_integer(util::BigInt::new(0x8, None))));
 	test("8 xxx", Pass(expr::Value::make_integer(util::BigInt::new(0x8, None))));
+	
+	test("'A'",      Pass(expr::Value::make_integer(util::BigInt::new(65, None))));
+	test("'A' + 1",  Pass(expr::Value::make_integer(util::BigInt::new(66, None))));
+	test("'\\n'",    Pass(expr::Value::make_integer(util::BigInt::new(10, None))));
+	test("'\\''",    Pass(expr::Value::make_integer(util::BigInt::new(39, None))));
+	test("'\\\\'",   Pass(expr::Value::make_integer(util::BigInt::new(92, None))));
+	test("'\\0'",    Pass(expr::Value::make_integer(util::BigInt::new(0, None))));
+	test("'\\x41'",  Pass(expr::Value::make_integer(util::BigInt::new(0x41, None))));
+	test("'\\t'",    Pass(expr::Value::make_integer(util::BigInt::new(9, None))));
+	test("'ab'",     Fail(("test", 1, "exactly one character")));
+	test("''",       Fail(("test", 1, "exactly one character")));
+	test("'\\q'",    Fail(("test", 1, "invalid escape sequence")));
 }
 
 
@@ -153,7 +172,21 @@ fn test_ops_arithmetic()
 	test("-4 >> 4", Pass(expr::Value::make_integer(util::BigInt::new(-1, None))));
 	test("-4 >> 5", Pass(expr::Value::make_integer(util::BigInt::new(-1, None))));
 	
+	test("0xff00 >> 8", Pass(expr::Value::make_integer(util::BigInt::new(0xff, None))));
+	test("0xff << 8", Pass(expr::Value::make_integer(util::BigInt::new(0xff00, None))));
+	
+	test("1 << -1", Fail(("test", 1, "out of supported range")));
+	test("1 >> -1", Fail(("test", 1, "out of supported range")));
+	
 	test("123`0 + 2", Pass(expr::Value::make_integer(util::BigInt::new(2, None))));
+	
+	test("2 ** 10", Pass(expr::Value::make_integer(util::BigInt::new(1024, None))));
+	test("0x1 ** 16", Pass(expr::Value::make_integer(util::BigInt::new(1, None))));
+	test("2 ** 0", Pass(expr::Value::make_integer(util::BigInt::new(1, None))));
+	test("0 ** 0", Pass(expr::Value::make_integer(util::BigInt::new(1, None))));
+	test("-3 ** 3", Pass(expr::Value::make_integer(util::BigInt::new(-27, None))));
+	test("-1 ** (1 << 1000)", Pass(expr::Value::make_integer(util::BigInt::new(1, None))));
+	test("-1 ** ((1 << 1000) + 1)", Pass(expr::Value::make_integer(util::BigInt::new(-1, None))));
 }
 
 
@@ -451,6 +484,10 @@ fn test_ops_arith_errors()
 	test("2 << (1 << 1000)", Fail(("test", 1, "out of supported range")));
 	test("2 >> (1 << 1000)", Fail(("test", 1, "out of supported range")));
 	test("2 >> (1 << 1000)", Fail(("test", 1, "out of supported range")));
+	
+	test("2 ** -1",    Fail(("test", 1, "negative exponent")));
+	test("2 ** 1000000000", Fail(("test", 1, "out of supported range")));
+	test("2 ** (1 << 1000)", Fail(("test", 1, "out of supported range")));
 }
 
 
@@ -483,6 +520,13 @@ fn test_precedence()
 	test("0b110 == 0b110 &  0b11 == 0b11", Fail(("test", 1, "argument")));
 	test("0b110 == 0b110 || 0b11 == 0b11", Pass(expr::Value::Bool(true)));
 	test("0b110 == 0b110 |  0b11 == 0b11", Fail(("test", 1, "argument")));
+	
+	test("2 ** 3 ** 2",   Pass(expr::Value::make_integer(util::BigInt::new(512, None))));
+	test("(2 ** 3) ** 2", Pass(expr::Value::make_integer(util::BigInt::new(64, None))));
+	test("3 * 2 ** 2",    Pass(expr::Value::make_integer(util::BigInt::new(12, None))));
+	test("2 ** 2 * 3",    Pass(expr::Value::make_integer(util::BigInt::new(12, None))));
+	test("2 ** 3 + 1",    Pass(expr::Value::make_integer(util::BigInt::new(9, None))));
+	test("1 << 2 ** 2",   Pass(expr::Value::make_integer(util::BigInt::new(16, None))));
 }
 
 
@@ -531,4 +575,22 @@ fn test_assignment()
 	test("0 = 1",     Fail(("test", 1, "invalid")));
 	test("x + 1 = 2", Fail(("test", 1, "invalid")));
 	test("{x} = 1",   Fail(("test", 1, "invalid")));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_invalid_digit_span()
+{
+	let mut report = diagn::Report::new();
+	let mut fileserver = util::FileServerMock::new();
+	fileserver.add("test", "1 + 0b1020");
+
+	use crate::util::FileServer;
+	let file_handle = fileserver.get_handle_unwrap("test");
+	let chars = fileserver.get_str(&mut report, None, file_handle).unwrap();
+	let mut walker = syntax::Walker::new(&chars, file_handle, 0);
+	assert!(expr::parse(&mut report, &mut walker).is_err());
+
+	let diagnostics = report.diagnostics();
+	assert_eq!(diagnostics.len(), 1);
+	assert_eq!(diagnostics[0].span, Some(diagn::Span::new(file_handle, 8, 9)));
+}
@@ -1,5 +1,6 @@
 use crate::*;
 use sha2::*;
+use util::FileServer;
 
 
 fn load_file_and_assemble_str_to_binary(filename: &str, hash: &[u8])
@@ -32,4 +33,954 @@ fn test_assemble_str_to_binary()
             32, 116, 119, 229, 149, 159, 100, 110, 138, 69, 217,
             176, 220, 115, 186, 132, 102, 96, 201, 46, 16
         ]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_iter_resolved_instructions()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerReal::new();
+
+    let opts = asm::AssemblyOptions::new();
+    
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["examples/basic.asm"]);
+
+    let instrs = assembly
+        .iter_resolved_instructions(&mut report)
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert_eq!(instrs.len(), 7);
+    assert!(instrs
+        .windows(2)
+        .all(|w| w[0].address < w[1].address));
+
+    let jnz = instrs
+        .iter()
+        .find(|i| i.src.starts_with("jnz"))
+        .unwrap();
+
+    assert_eq!(jnz.address, util::BigInt::from(9));
+    assert_eq!(jnz.bytes(), vec![0x40, 0x00, 0x06]);
+    assert_eq!(jnz.args.len(), 1);
+    assert_eq!(jnz.args[0].excerpt, ".loop");
+}
+
+
+#[test]
+fn test_profile()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerReal::new();
+
+    let mut opts = asm::AssemblyOptions::new();
+    opts.profile = true;
+    
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["examples/basic.asm"]);
+
+    assert!(!assembly.error);
+
+    let profile = assembly.profile.as_ref().unwrap();
+
+    for name in [
+        "parse",
+        "decls",
+        "defs",
+        "resolve_constants",
+        "resolve_ifs_repeats",
+        "match_all",
+        "resolve_iteratively",
+        "build_output",
+    ]
+    {
+        let phase = profile.get_phase(name).unwrap();
+        assert!(phase.duration >= std::time::Duration::ZERO);
+    }
+
+    assert!(!profile.node_evaluations.is_empty());
+    assert!(profile.node_evaluations.values().all(|&c| c >= 1));
+
+    let summary = profile.format_summary(&fileserver);
+    assert!(summary.contains("resolve_iteratively"));
+    assert!(summary.contains("basic.asm"));
+}
+
+
+#[test]
+fn test_profile_disabled()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerReal::new();
+
+    let opts = asm::AssemblyOptions::new();
+    
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["examples/basic.asm"]);
+
+    assert!(assembly.profile.is_none());
+}
+
+
+#[test]
+fn test_warning_and_error_directives()
+{
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("warning.asm", "#warning \"careful\"\n#d8 0x12");
+    fileserver.add("error.asm", "#error \"broken\"\n#d8 0x12");
+
+    let opts = asm::AssemblyOptions::new();
+
+    let mut report = diagn::Report::new();
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["warning.asm"]);
+
+    assert!(report.stop_at_errors().is_ok());
+    assert_eq!(report.len_with_inner(), 1);
+    assert_eq!(format!("{:x}", assembly.output.unwrap()), "12");
+
+    let mut report = diagn::Report::new();
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["error.asm"]);
+
+    assert!(report.stop_at_errors().is_err());
+    assert!(assembly.output.is_none());
+}
+
+
+#[test]
+fn test_warnings_as_errors()
+{
+    let mut report = diagn::Report::new();
+    report.warning("some warning");
+    assert!(report.stop_at_errors().is_ok());
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "#d8 0x12");
+    let file_handle = fileserver.get_handle_unwrap("main.asm");
+
+    let mut report = diagn::Report::new();
+    report.set_warnings_as_errors(true);
+    report.push_parent("some parent", diagn::Span::new(file_handle, 0, 3));
+    report.warning("some warning");
+    report.pop_parent();
+    let mut outer = diagn::Message::warning("outer warning");
+    outer.inner.push(diagn::Message::warning("inner warning"));
+    report.message(outer);
+    assert!(report.stop_at_errors().is_err());
+
+    let mut msgs = Vec::<u8>::new();
+    report.print_all(&mut msgs, &fileserver, false);
+    let msgs = String::from_utf8(msgs).unwrap();
+    assert!(!msgs.contains("warning:"));
+    assert!(msgs.contains("error: some warning"));
+    assert!(msgs.contains("error: inner warning"));
+}
+
+
+#[test]
+fn test_warnings_as_errors_assemble()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "#d8 0x12\n#warning \"check this\"");
+
+    let mut opts = asm::AssemblyOptions::new();
+    opts.warnings_as_errors = true;
+    
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"]);
+
+    assert!(assembly.error);
+    assert!(assembly.output.is_none());
+    assert!(report.has_error_at(&mut fileserver, "main.asm", 1, "check this"));
+
+    let diagnostics = report.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, diagn::MessageKind::Error);
+}
+
+
+#[test]
+fn test_diagnostics()
+{
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "#d8 abc\n#d8 def");
+    let file_handle = fileserver.get_handle_unwrap("main.asm");
+
+    let mut report = diagn::Report::new();
+    report.error_span("unknown symbol `abc`", diagn::Span::new(file_handle, 4, 7));
+    report.push_parent("failed to resolve", diagn::Span::new(file_handle, 8, 15));
+    report.note_span("unknown symbol `def`", diagn::Span::new(file_handle, 12, 15));
+    report.pop_parent();
+
+    let diagnostics = report.diagnostics();
+    assert_eq!(diagnostics.len(), 2);
+
+    assert_eq!(diagnostics[0].kind, diagn::MessageKind::Error);
+    assert_eq!(diagnostics[0].descr, "unknown symbol `abc`");
+    assert_eq!(diagnostics[0].span, Some(diagn::Span::new(file_handle, 4, 7)));
+    assert!(diagnostics[0].secondary.is_empty());
+
+    assert_eq!(diagnostics[1].kind, diagn::MessageKind::Error);
+    assert_eq!(diagnostics[1].descr, "failed to resolve");
+    assert_eq!(diagnostics[1].span, Some(diagn::Span::new(file_handle, 8, 15)));
+    assert_eq!(diagnostics[1].secondary, vec![
+        diagn::DiagnosticSecondary {
+            kind: diagn::MessageKind::Note,
+            descr: "unknown symbol `def`".to_string(),
+            span: Some(diagn::Span::new(file_handle, 12, 15)),
+        },
+    ]);
+}
+
+
+#[test]
+fn test_size_bounds()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "
+        #subruledef reg
+        {
+            a => 0x0
+            b => 0x00
+        }
+
+        #ruledef
+        {
+            jmp {addr: u8} => 0x10 @ addr
+            jmp {addr: u16} => 0x11 @ addr
+            jmp.far {addr: u32} => 0x12 @ addr
+            ld {r: reg} => 0x20 @ r
+            nop => 0x00
+        }
+
+        nop");
+
+    let opts = asm::AssemblyOptions::new();
+
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"]);
+
+    assert!(!assembly.error);
+
+    let defs = assembly.defs.as_ref().unwrap();
+    assert_eq!(defs.get_mnemonic_size_bounds("jmp"), Some((16, 24)));
+    assert_eq!(defs.get_mnemonic_size_bounds("JMP"), Some((16, 24)));
+    assert_eq!(defs.get_mnemonic_size_bounds("jmp.far"), Some((40, 40)));
+    assert_eq!(defs.get_mnemonic_size_bounds("ld"), Some((12, 16)));
+    assert_eq!(defs.get_mnemonic_size_bounds("nop"), Some((8, 8)));
+    assert_eq!(defs.get_mnemonic_size_bounds("xyz"), None);
+}
+
+
+#[test]
+fn test_virtual_file()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("lib/defs.asm", "#ruledef\n{\n    nop => 0x00\n}");
+    fileserver.add_virtual(
+        &mut report,
+        "<stdin>",
+        "lib",
+        "#include \"defs.asm\"\nnop\nunknown".into())
+        .unwrap();
+
+    let opts = asm::AssemblyOptions::new();
+
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["<stdin>"]);
+
+    assert!(assembly.error);
+
+    let diagnostics = report.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+
+    let span = diagnostics[0].span.unwrap();
+    assert_eq!(fileserver.get_filename(span.file_handle), "<stdin>");
+
+    let mut msgs = Vec::<u8>::new();
+    report.print_all(&mut msgs, &fileserver, false);
+    let msgs = String::from_utf8(msgs).unwrap();
+    assert!(msgs.contains("<stdin>:3:"));
+}
+
+
+#[test]
+fn test_assemble_entry_point()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "
+        #ruledef
+        {
+            jmp {addr: u8} => 0x10 @ addr
+            jmp {addr: u16} => 0x11 @ addr
+            nop => 0x00
+        }
+
+        jmp end
+        nop
+        end:
+        #d8 end");
+
+    let mut opts = asm::AssemblyOptions::new();
+    opts.max_iterations = 4;
+
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"]);
+
+    assert!(!assembly.error);
+    assert!(!report.has_errors());
+
+    let output = assembly.output.unwrap();
+    assert_eq!(output.format_binary(), vec![0x10, 0x03, 0x00, 0x03]);
+    assert_eq!(output.format_hexstr(), "10030003");
+    assert_eq!(output.format_binstr(), "00010000000000110000000000000011");
+}
+
+
+#[test]
+fn test_assemble_entry_point_max_iterations()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "
+        #ruledef
+        {
+            jmp {addr: u8} => 0x10 @ addr
+            jmp {addr: u16} => 0x11 @ addr
+            nop => 0x00
+        }
+
+        jmp end
+        nop
+        end:
+        #d8 end");
+
+    let mut opts = asm::AssemblyOptions::new();
+    opts.max_iterations = 3;
+
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"]);
+
+    assert!(assembly.error);
+    assert!(assembly.output.is_none());
+
+    let diagnostics = report.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].descr, "instruction encoding did not converge");
+}
+
+
+#[test]
+fn test_multiple_roots()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("a.asm", "#d8 0x12\nlabel_a:\n#d8 label_b");
+    fileserver.add("b.asm", "#d8 label_a\nlabel_b:");
+
+    let opts = asm::AssemblyOptions::new();
+
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["a.asm", "b.asm"]);
+
+    assert!(!assembly.error);
+    assert_eq!(
+        assembly.output.unwrap().format_hexstr(),
+        "120301");
+}
+
+
+#[test]
+fn test_format_bank_tar()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "
+        #bankdef header { addr = 0x0, size = 0x4, outp = 0, fill }
+        #bankdef code { addr = 0x8000, outp = 8 * 0x4 }
+
+        #bank header
+        #d8 0x12, 0x34
+
+        #bank code
+        #d8 0xaa, 0xbb, 0xcc
+    ");
+
+    let opts = asm::AssemblyOptions::new();
+
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"]);
+
+    assert!(!assembly.error);
+
+    let tar = driver::format_output(
+        &fileserver,
+        &opts,
+        assembly.decls.as_ref().unwrap(),
+        assembly.defs.as_ref().unwrap(),
+        assembly.output.as_ref().unwrap(),
+        driver::OutputFormat::BankTar);
+
+    let read_entry = |offset: usize|
+    {
+        let header = &tar[offset..(offset + 512)];
+
+        let name = String::from_utf8(
+            header[0..100]
+                .iter()
+                .copied()
+                .take_while(|b| *b != 0)
+                .collect())
+            .unwrap();
+
+        let size = usize::from_str_radix(
+            std::str::from_utf8(&header[124..135]).unwrap(),
+            8)
+            .unwrap();
+
+        (name, size, &tar[(offset + 512)..(offset + 512 + size)])
+    };
+
+    // Two entries of one block each, after their
+    // headers, then two end-of-archive blocks
+    assert_eq!(tar.len(), 512 * 6);
+
+    assert_eq!(
+        read_entry(0),
+        ("header.bin".to_string(), 4, &[0x12, 0x34, 0x00, 0x00][..]));
+
+    assert_eq!(
+        read_entry(1024),
+        ("code.bin".to_string(), 3, &[0xaa, 0xbb, 0xcc][..]));
+
+    assert!(tar[(512 * 4)..].iter().all(|b| *b == 0));
+}
+
+
+#[test]
+fn test_byte_runs()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "
+        #d8 0x12, 0x34, 0x34
+        #res 100
+        #d8 0xff, 0xff, 0xff, 0x56
+    ");
+
+    let opts = asm::AssemblyOptions::new();
+
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"]);
+
+    assert!(!assembly.error);
+
+    let runs = assembly.get_byte_runs(&mut report, 1).unwrap();
+
+    assert_eq!(
+        runs,
+        vec![
+            asm::ByteRun { value: 0x12, start: 0, length: 1 },
+            asm::ByteRun { value: 0x34, start: 1, length: 2 },
+            asm::ByteRun { value: 0x00, start: 3, length: 100 },
+            asm::ByteRun { value: 0xff, start: 103, length: 3 },
+            asm::ByteRun { value: 0x56, start: 106, length: 1 },
+        ]);
+
+    let long_runs = assembly.get_byte_runs(&mut report, 3).unwrap();
+
+    assert_eq!(
+        long_runs,
+        vec![
+            asm::ByteRun { value: 0x00, start: 3, length: 100 },
+            asm::ByteRun { value: 0xff, start: 103, length: 3 },
+        ]);
+}
+
+
+#[test]
+fn test_intelhex_round_trip()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "
+        #bankdef low { addr = 0x0, size = 0x100, outp = 0 }
+        #bankdef high { addr = 0x10000, outp = 8 * 0x10000 }
+
+        #bank low
+        #d8 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08
+        #d8 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10
+        #d8 0x11, 0x12
+
+        #bank high
+        #d8 0xaa, 0xbb
+    ");
+
+    let opts = asm::AssemblyOptions::new();
+
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"]);
+
+    assert!(!assembly.error);
+
+    let output = assembly.output.as_ref().unwrap();
+    let hex = output.format_intelhex(8, 16, false);
+
+    assert!(hex.ends_with(":00000001FF"));
+
+    // Decode every record back into a memory image,
+    // checking lengths and checksums along the way
+    let mut decoded = std::collections::BTreeMap::new();
+    let mut addr_upper = 0;
+
+    for line in hex.lines()
+    {
+        let bytes = (1..line.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&line[i..(i + 2)], 16).unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(line.starts_with(':'));
+        assert_eq!(bytes.len(), bytes[0] as usize + 5);
+        assert!(bytes[0] <= 16);
+        assert_eq!(bytes.iter().fold(0u8, |a, b| a.wrapping_add(*b)), 0);
+
+        let addr = ((bytes[1] as usize) << 8) | bytes[2] as usize;
+        let data = &bytes[4..(bytes.len() - 1)];
+
+        match bytes[3]
+        {
+            0 =>
+            {
+                for (i, b) in data.iter().enumerate()
+                {
+                    decoded.insert((addr_upper << 16) + addr + i, *b);
+                }
+            }
+            4 => addr_upper = ((data[0] as usize) << 8) | data[1] as usize,
+            1 => {}
+            _ => panic!("unexpected record type"),
+        }
+    }
+
+    let binary = output.format_binary();
+
+    let expected = binary
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| **b != 0)
+        .map(|(i, b)| (i, *b))
+        .collect::<std::collections::BTreeMap<_, _>>();
+
+    assert_eq!(decoded, expected);
+    assert_eq!(decoded.get(&0x10001), Some(&0xbb));
+}
+
+
+#[test]
+fn test_srec_record_len_clamped()
+{
+    let mut output = util::BitVec::new();
+    output.write_bigint(0, &util::BigInt::from_bytes_be(&[0x55; 300]));
+
+    let srec = output.format_srec(None, 1000, false);
+    let lines = srec.lines().collect::<Vec<_>>();
+
+    // S1 records hold at most 252 data bytes
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("S1FF0000"));
+    assert!(lines[1].starts_with("S13300FC"));
+    assert_eq!(lines[2], "S9030000FC");
+}
+
+
+#[test]
+fn test_resolution_deterministic()
+{
+    let src = "
+        #ruledef
+        {
+            jmp {x: u4} => 0x1 @ x
+            jmp {x: u8} => 0x22 @ x
+        }
+
+        jmp b
+        #res 13
+        a:
+        jmp a
+        jmp b
+        b:
+    ";
+
+    let assemble = |optimize: bool|
+    {
+        let mut report = diagn::Report::new();
+
+        let mut fileserver = util::FileServerMock::new();
+        fileserver.add("main.asm", src);
+
+        let mut opts = asm::AssemblyOptions::new();
+        opts.optimize_statically_known = optimize;
+        opts.optimize_instruction_matching = optimize;
+
+        let assembly = asm::assemble(
+            &mut report,
+            &opts,
+            &mut fileserver,
+            &["main.asm"]);
+
+        assert!(!assembly.error);
+
+        (
+            assembly.output.unwrap().format_hexstr(),
+            assembly.iterations_taken.unwrap(),
+        )
+    };
+
+    let first = assemble(true);
+
+    assert_eq!(first.0, "2212000000000000000000000000001f2212");
+
+    // Repeated runs, and runs without the optimizations that
+    // skip work on later iterations, reach the same fixpoint
+    for _ in 0..3
+    {
+        assert_eq!(assemble(true), first);
+    }
+
+    assert_eq!(assemble(false).0, first.0);
+}
+
+
+#[test]
+fn test_binary_gap_fill()
+{
+    let assemble = |src: &str|
+    {
+        let mut report = diagn::Report::new();
+
+        let mut fileserver = util::FileServerMock::new();
+        fileserver.add("main.asm", src);
+
+        let mut opts = asm::AssemblyOptions::new();
+        opts.gap_fill = Some(0xee);
+
+        let assembly = asm::assemble(
+            &mut report,
+            &opts,
+            &mut fileserver,
+            &["main.asm"]);
+
+        assembly.output.map(|output| output.format_binary())
+    };
+
+    // The gap between banks takes the fill byte, while
+    // unused space within a `fill` bank stays zeroed
+    assert_eq!(
+        assemble("
+            #bankdef a { addr = 0x0, size = 0x3, outp = 0, fill }
+            #bankdef b { addr = 0x0, size = 0x4, outp = 8 * 0x5 }
+            #bank a
+            #d8 0x11
+            #bank b
+            #d8 0x22, 0x33
+        "),
+        Some(vec![0x11, 0x00, 0x00, 0xee, 0xee, 0x22, 0x33]));
+
+    // Without `fill`, unused space at the end
+    // of the last bank isn't emitted
+    assert_eq!(
+        assemble("
+            #bankdef a { addr = 0x0, size = 0x10, outp = 0 }
+            #d8 0x11, 0x22
+        "),
+        Some(vec![0x11, 0x22]));
+
+    // Overlapping banks are still rejected
+    assert_eq!(
+        assemble("
+            #bankdef a { addr = 0x0, size = 0x4, outp = 0 }
+            #bankdef b { addr = 0x0, size = 0x4, outp = 8 * 0x2 }
+            #bank a
+            #d8 0x11
+            #bank b
+            #d8 0x22
+        "),
+        None);
+}
+
+
+#[test]
+fn test_check_only()
+{
+    let assemble = |src: &str|
+    {
+        let mut report = diagn::Report::new();
+
+        let mut fileserver = util::FileServerMock::new();
+        fileserver.add("main.asm", src);
+
+        let mut opts = asm::AssemblyOptions::new();
+        opts.check_only = true;
+
+        let assembly = asm::assemble(
+            &mut report,
+            &opts,
+            &mut fileserver,
+            &["main.asm"]);
+
+        (assembly, report, fileserver)
+    };
+
+    // A valid program resolves without producing output
+    let (assembly, report, _) = assemble("
+        #ruledef
+        {
+            halt => 0x55
+        }
+        halt
+        label:
+        #d8 label
+    ");
+
+    assert!(!assembly.error);
+    assert!(!report.has_errors());
+    assert!(assembly.defs.is_some());
+    assert!(assembly.output.is_none());
+
+    // Errors from matching and resolution are still reported
+    let (assembly, report, mut fileserver) = assemble("
+        #ruledef
+        {
+            ld {x: u8} => 0x11 @ x
+        }
+        ld 0x100
+    ");
+
+    assert!(assembly.error);
+    assert!(report.has_error_at(
+        &mut fileserver, "main.asm", 5, "out of range"));
+    assert!(assembly.output.is_none());
+}
+
+
+#[test]
+fn test_file_usage()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "
+        #ruledef
+        {
+            ld {x: u8} => 0x55 @ x
+        }
+        ld 0x11
+        #include \"include.asm\"
+        #d 0xaabb
+    ");
+    fileserver.add("include.asm", "
+        ld 0x22
+        ld 0x33
+        #d8 0xcc
+    ");
+
+    let opts = asm::AssemblyOptions::new();
+
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"]);
+
+    assert!(!report.has_errors());
+
+    let usage = assembly.output.unwrap().file_usage(&fileserver);
+
+    assert_eq!(usage, vec![
+        util::BitVecFileUsage {
+            filename: "main.asm".to_string(),
+            bits: 32,
+        },
+        util::BitVecFileUsage {
+            filename: "include.asm".to_string(),
+            bits: 40,
+        },
+    ]);
+}
+
+
+#[test]
+fn test_assemble_incremental()
+{
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "
+        #include \"include.asm\"
+        ld 0x11
+    ");
+    fileserver.add("include.asm", "
+        #ruledef
+        {
+            ld {x: u8} => 0x55 @ x
+        }
+        #d8 0xaa
+    ");
+
+    let opts = asm::AssemblyOptions::new();
+
+    let mut report = diagn::Report::new();
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"]);
+
+    assert!(!assembly.error);
+    assert!(assembly.parse_cache.contains("main.asm"));
+    assert!(assembly.parse_cache.contains("include.asm"));
+
+    // Also change the include behind the cache's back, which
+    // only shows up in the output if it gets parsed again
+    fileserver.add("main.asm", "
+        #include \"include.asm\"
+        ld 0x22
+    ");
+    fileserver.add("include.asm", "
+        #ruledef
+        {
+            ld {x: u8} => 0x66 @ x
+        }
+        #d8 0xbb
+    ");
+
+    let mut report = diagn::Report::new();
+    let assembly = asm::assemble_incremental(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"],
+        assembly,
+        &["main.asm"]);
+
+    assert!(!assembly.error);
+    assert_eq!(
+        assembly.output.as_ref().unwrap().format_binary(),
+        vec![0xaa, 0x55, 0x22]);
+
+    let mut report = diagn::Report::new();
+    let assembly = asm::assemble_incremental(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"],
+        assembly,
+        &["include.asm"]);
+
+    assert!(!assembly.error);
+    assert_eq!(
+        assembly.output.as_ref().unwrap().format_binary(),
+        vec![0xbb, 0x66, 0x22]);
+}
+
+
+#[test]
+fn test_non_converging_symbols()
+{
+    let mut report = diagn::Report::new();
+
+    let mut fileserver = util::FileServerMock::new();
+    fileserver.add("main.asm", "#d8 0\n#res (end > 1) ? 0 : 2\nend:\nsize = end * 2");
+    let file_handle = fileserver.get_handle_unwrap("main.asm");
+
+    let mut opts = asm::AssemblyOptions::new();
+    opts.max_iterations = 5;
+
+    let assembly = asm::assemble(
+        &mut report,
+        &opts,
+        &mut fileserver,
+        &["main.asm"]);
+
+    assert!(assembly.error);
+
+    let diagnostics = report.diagnostics();
+    let summary = diagnostics
+        .iter()
+        .find(|d| d.descr == "resolution did not converge after 5 iterations")
+        .unwrap();
+
+    assert_eq!(summary.kind, diagn::MessageKind::Error);
+    assert_eq!(summary.span, Some(diagn::Span::new(file_handle, 29, 33)));
+    assert_eq!(summary.secondary, vec![
+        diagn::DiagnosticSecondary {
+            kind: diagn::MessageKind::Note,
+            descr: "`end` changed from 0x3 to 0x1".to_string(),
+            span: Some(diagn::Span::new(file_handle, 29, 33)),
+        },
+        diagn::DiagnosticSecondary {
+            kind: diagn::MessageKind::Note,
+            descr: "`size` changed from 0x6 to 0x2".to_string(),
+            span: Some(diagn::Span::new(file_handle, 34, 38)),
+        },
+    ]);
+}
@@ -0,0 +1,114 @@
+use crate::*;
+
+
+fn test(src: &str, expected: (syntax::TokenKind, usize))
+{
+	let result = syntax::decide_next_token(
+		src,
+		syntax::CommentStyles::new());
+
+	assert_eq!(result, expected);
+}
+
+
+#[test]
+fn test_char_literals()
+{
+	test("'a'",      (syntax::TokenKind::Char, 3));
+	test("'a', 'b'", (syntax::TokenKind::Char, 3));
+	test("'\\n'",    (syntax::TokenKind::Char, 4));
+	test("'\\''",    (syntax::TokenKind::Char, 4));
+	test("'\\\\'",   (syntax::TokenKind::Char, 4));
+	test("'\\x41'",  (syntax::TokenKind::Char, 6));
+	test("'ab'",     (syntax::TokenKind::Char, 4));
+	test("''",       (syntax::TokenKind::Char, 2));
+
+	test("'a",       (syntax::TokenKind::Error, 1));
+	test("'a\n'",    (syntax::TokenKind::Error, 1));
+	test("'\\\n'",   (syntax::TokenKind::Error, 1));
+}
+
+
+#[test]
+fn test_string_literals()
+{
+	test("\"abc\"",        (syntax::TokenKind::String, 5));
+	test("\"a\\\"b\", 1",  (syntax::TokenKind::String, 6));
+	test("\"a\\\\\", 1",   (syntax::TokenKind::String, 5));
+	test("\"a\nb\"",       (syntax::TokenKind::String, 5));
+
+	test("\"abc",          (syntax::TokenKind::StringUnterminated, 4));
+	test("\"abc\\\"",      (syntax::TokenKind::StringUnterminated, 6));
+}
+
+
+#[test]
+fn test_double_asterisk()
+{
+	test("**",     (syntax::TokenKind::DoubleAsterisk, 2));
+	test("** 2",   (syntax::TokenKind::DoubleAsterisk, 2));
+	test("* *",    (syntax::TokenKind::Asterisk, 1));
+	test("***",    (syntax::TokenKind::DoubleAsterisk, 2));
+}
+
+
+#[test]
+fn test_unicode_identifiers()
+{
+	test("café:",          (syntax::TokenKind::Identifier, 5));
+	test("метка: nop",     (syntax::TokenKind::Identifier, 10));
+	test("_ñ1 = 2",        (syntax::TokenKind::Identifier, 4));
+	test("cafe\u{301}",    (syntax::TokenKind::Identifier, 6));
+	test("日本語",          (syntax::TokenKind::Identifier, 9));
+
+	test("→",              (syntax::TokenKind::Error, 3));
+	test("café→",          (syntax::TokenKind::Identifier, 5));
+}
+
+
+#[test]
+fn test_block_comments()
+{
+	test("/* a */ 1",              (syntax::TokenKind::Comment, 7));
+	test("/* a /* b */ c */ 1",    (syntax::TokenKind::Comment, 17));
+	test("/* a\nb */",             (syntax::TokenKind::Comment, 9));
+	test("/**/",                   (syntax::TokenKind::Comment, 4));
+
+	test("/* a",                   (syntax::TokenKind::CommentUnterminated, 4));
+	test("/* a /* b */ c",         (syntax::TokenKind::CommentUnterminated, 14));
+
+	let no_block_comments = syntax::CommentStyles {
+		semicolon: true,
+		double_slash: false,
+		slash_asterisk: false,
+	};
+
+	assert_eq!(
+		syntax::decide_next_token("/* a */", no_block_comments),
+		(syntax::TokenKind::Slash, 1));
+}
+
+
+#[test]
+fn test_unterminated_string_span()
+{
+	let mut report = diagn::Report::new();
+	let mut fileserver = util::FileServerMock::new();
+	fileserver.add("test", "x = \"abc");
+
+	use util::FileServer;
+	let file_handle = fileserver.get_handle_unwrap("test");
+
+	let mut walker = syntax::Walker::new(
+		"x = \"abc",
+		file_handle,
+		0);
+
+	walker.skip_ignorable();
+	assert!(walker.next_useful_is(2, syntax::TokenKind::StringUnterminated));
+	assert!(walker.check_unterminated(&mut report).is_err());
+
+	let diagnostics = report.diagnostics();
+	assert_eq!(diagnostics.len(), 1);
+	assert_eq!(diagnostics[0].span, Some(diagn::Span::new(file_handle, 4, 8)));
+}
@@ -33,6 +33,37 @@ impl BigInt
     }
 
 
+    /// Formats the value in the given radix, with the
+    /// digits zero-padded to at least `width`, and with
+    /// a `0x`, `0o`, or `0b` prefix unless in decimal
+    pub fn as_radix_string(
+        &self,
+        radix: usize,
+        width: usize,
+        prefixed: bool)
+        -> String
+    {
+        let digits = self.bigint
+            .magnitude()
+            .to_str_radix(radix as u32);
+
+        let prefix = match radix
+        {
+            2 if prefixed => "0b",
+            8 if prefixed => "0o",
+            16 if prefixed => "0x",
+            _ => "",
+        };
+
+        format!(
+            "{}{}{:0>width$}",
+            if self.bigint.sign() == num_bigint::Sign::Minus { "-" } else { "" },
+            prefix,
+            digits,
+            width = width)
+    }
+
+
     pub fn from_bytes_be(bytes: &[u8]) -> BigInt
     {
         let bigint = num_bigint::BigInt::from_signed_bytes_be(&bytes);
@@ -268,6 +299,53 @@ impl BigInt
     }
 
 
+    pub fn checked_pow(
+        &self,
+        report: &mut diagn::Report,
+        span: diagn::Span,
+        rhs: &BigInt)
+        -> Result<BigInt, ()>
+    {
+        if rhs.sign() < 0
+        {
+            report.error_span(
+                "negative exponent",
+                span);
+            
+            return Err(());
+        }
+
+        let maybe_rhs_u32: Result<u32, _> = (&rhs.bigint).try_into();
+
+        // Bases of 0, 1, and -1 never grow
+        let result_too_large = {
+            match maybe_rhs_u32
+            {
+                Err(_) => self.bigint.bits() > 1,
+                Ok(exp) =>
+                    self.bigint.bits() > 1 &&
+                    self.bigint.bits() * (exp as u64) >= BIGINT_MAX_BITS,
+            }
+        };
+
+        if result_too_large
+        {
+            report.error_span(
+                "value is out of supported range",
+                span);
+            
+            return Err(());
+        }
+
+        // Only the parity of a huge exponent matters
+        // for the bases that never grow
+        let exp = maybe_rhs_u32.unwrap_or(
+            if rhs.get_bit(0) { 1 } else { 2 });
+
+        Ok(self.bigint.pow(exp).into())
+    }
+
+
     pub fn checked_shl(
         &self,
         report: &mut diagn::Report,
@@ -417,6 +495,22 @@ impl BigInt
     }
 
 
+    /// Reinterprets the lowest `size` bits
+    /// as a two's complement value
+    pub fn as_twos_complement(&self, size: usize) -> BigInt
+    {
+        if size > 0 && self.get_bit(size - 1)
+        {
+            let modulus = num_bigint::BigInt::from(1) << size;
+            BigInt::new(&self.bigint - modulus, Some(size))
+        }
+        else
+        {
+            BigInt::new(self.bigint.clone(), Some(size))
+        }
+    }
+
+
     pub fn convert_le(&self) -> BigInt
     {
         let Some(size) = self.size
@@ -501,6 +595,18 @@ impl std::ops::Neg for &BigInt
 }
 
 
+impl std::ops::Add for &BigInt
+{
+    type Output = BigInt;
+
+
+    fn add(self, rhs: &BigInt) -> Self::Output
+    {
+        (&self.bigint + &rhs.bigint).into()
+    }
+}
+
+
 impl std::ops::BitAnd for &BigInt
 {
     type Output = BigInt;
@@ -564,6 +670,15 @@ impl std::cmp::Ord for BigInt
 }
 
 
+impl std::fmt::Display for BigInt
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
+    {
+        std::fmt::Display::fmt(&self.bigint, f)
+    }
+}
+
+
 impl std::fmt::LowerHex for BigInt
 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error>
@@ -17,6 +17,9 @@ pub struct BitVecSpan
     pub offset: Option<usize>,
     pub size: usize,
     pub span: diagn::Span,
+    /// Whether the value was written as a negative
+    /// number, to be displayed in two's complement
+    pub signed: bool,
 }
 
 
@@ -84,7 +87,8 @@ impl BitVec
         span: diagn::Span,
         offset: usize,
         addr: util::BigInt,
-        bigint: &util::BigInt)
+        bigint: &util::BigInt,
+        signed: bool)
 	{
         self.write_bigint(
             offset,
@@ -94,7 +98,8 @@ impl BitVec
             Some(offset),
             bigint.size.unwrap(),
             addr,
-            span);
+            span,
+            signed);
     }
 	
 	
@@ -103,13 +108,15 @@ impl BitVec
         offset: Option<usize>,
         size: usize,
         addr: util::BigInt,
-        span: diagn::Span)
+        span: diagn::Span,
+        signed: bool)
 	{
         self.spans.push(BitVecSpan {
             offset,
             size,
             addr,
             span,
+            signed,
         });
     }
     
@@ -25,6 +25,33 @@ impl util::BitVec
     }
 
 
+	/// Formats the bits from `range` as bytes, padding
+	/// the last byte with zeroes if needed.
+	pub fn format_binary_range(
+		&self,
+		range: std::ops::Range<usize>)
+		-> Vec<u8>
+	{
+		let mut result = Vec::new();
+
+		let mut index = range.start;
+		while index < range.end
+		{
+			let mut byte: u8 = 0;
+			for _ in 0..8
+			{
+				byte <<= 1;
+				byte |= if index < range.end && self.read_bit(index) { 1 } else { 0 };
+				index += 1;
+			}
+
+			result.push(byte);
+		}
+
+		result
+	}
+
+
 	pub fn format_binstr(&self) -> String
 	{
 		self.format_str(1)
@@ -170,36 +197,83 @@ impl util::BitVec
     }
 
 
-	pub fn format_mif(&self) -> String
+	/// Formats as an Altera Memory Initialization File, with
+	/// `word_size` bits per word and at least `depth` words.
+	/// Runs of repeated words are collapsed into `[start..end]`
+	/// ranges, and other words are written up to
+	/// `words_per_line` per line.
+	pub fn format_mif(
+		&self,
+		word_size: usize,
+		depth: Option<usize>,
+		words_per_line: usize)
+		-> String
 	{
 		let mut result = String::new();
 
-		let byte_num = self.len() / 8 + if self.len() % 8 != 0 { 1 } else { 0 };
+		let word_num = self.len().div_ceil(word_size);
+		let depth = std::cmp::max(word_num, depth.unwrap_or(0));
 
-		result.push_str(&format!("DEPTH = {};\n", byte_num));
-		result.push_str("WIDTH = 8;\n");
+		result.push_str(&format!("DEPTH = {};\n", depth));
+		result.push_str(&format!("WIDTH = {};\n", word_size));
 		result.push_str("ADDRESS_RADIX = HEX;\n");
 		result.push_str("DATA_RADIX = HEX;\n");
 		result.push_str("\n");
 		result.push_str("CONTENT\n");
 		result.push_str("BEGIN\n");
 
-		let addr_max_width = format!("{:x}", byte_num - 1).len();
+		// Words past the end of the output
+		// are padded up to the depth as zeroes
+		let words = (0..depth)
+			.map(|i| {
+				if i < word_num
+					{ self.format_word_hex(i * word_size, word_size).to_uppercase() }
+				else
+					{ "0".repeat((word_size + 3) / 4) }
+			})
+			.collect::<Vec<_>>();
+
+		let addr_max_width = format!("{:x}", std::cmp::max(1, depth) - 1).len();
+
+		let get_run_len = |start: usize| {
+			let mut len = 1;
+			while start + len < words.len() &&
+				words[start + len] == words[start]
+			{
+				len += 1;
+			}
+			len
+		};
 
 		let mut index = 0;
-		while index < self.len()
+		while index < words.len()
 		{
-			result.push_str(&format!(" {:1$X}: ", index / 8, addr_max_width));
+			let run_len = get_run_len(index);
+			if run_len > 1
+			{
+				result.push_str(&format!(
+					" [{:X}..{:X}]: {};\n",
+					index,
+					index + run_len - 1,
+					words[index]));
+
+				index += run_len;
+				continue;
+			}
 
-			let mut byte: u8 = 0;
-			for _ in 0..8
+			result.push_str(&format!(" {:1$X}:", index, addr_max_width));
+
+			let mut words_in_line = 0;
+			while index < words.len() &&
+				words_in_line < words_per_line &&
+				(words_in_line == 0 || get_run_len(index) == 1)
 			{
-				byte <<= 1;
-				byte |= if self.read_bit(index) { 1 } else { 0 };
+				result.push_str(&format!(" {}", words[index]));
 				index += 1;
+				words_in_line += 1;
 			}
 
-			result.push_str(&format!("{:02X};\n", byte));
+			result.push_str(";\n");
 		}
 
 		result.push_str("END;");
@@ -207,15 +281,40 @@ impl util::BitVec
 	}
 	
 	
-	pub fn format_intelhex(&self, address_unit: usize) -> String
+	/// Formats as Intel HEX, with up to `record_len` data
+	/// bytes per record. Emits records only for the regions
+	/// that were written to, unless `emit_holes` is set, in which
+	/// case regions left by `#res` or gaps are emitted as well.
+	pub fn format_intelhex(
+		&self,
+		address_unit: usize,
+		record_len: usize,
+		emit_holes: bool)
+		-> String
 	{	
 		let mut result = String::new();
 
+		let byte_num = (self.len() + 7) / 8;
+
+		let mut written = vec![false; byte_num];
+		for span in &self.spans
+		{
+			if let Some(offset) = span.offset
+			{
+				for index in offset..(offset + span.size)
+				{
+					written[index / 8] = true;
+				}
+			}
+		}
+
 		let mut read_index = 0;
 
 		let mut accum_index = 0;
 		let mut accum_bytes = Vec::<u8>::new();
 
+		let mut cur_addr_upper = 0;
+
 		let mut flush_bytes = |
 			read_index: usize,
 			accum_index: &mut usize,
@@ -223,11 +322,17 @@ impl util::BitVec
 		{
 			while let Some(0_u8) = accum_bytes.last()
 			{
+				if emit_holes
+					{ break; }
+
 				accum_bytes.pop();
 			}
 
 			while let Some(0_u8) = accum_bytes.first()
 			{
+				if emit_holes
+					{ break; }
+
 				accum_bytes.remove(0);
 				*accum_index += 8;
 			}
@@ -236,28 +341,25 @@ impl util::BitVec
 
 			if length > 0
 			{
-				let addr_hi = ((*accum_index / address_unit) >> 8) as u8;
-				let addr_lo = (*accum_index / address_unit) as u8;
-
-				result.push(':');
-				result.push_str(&format!("{:02X}", length));
-				result.push_str(&format!("{:02X}", addr_hi));
-				result.push_str(&format!("{:02X}", addr_lo));
-				result.push_str("00");
-
-				let mut checksum = 0_u8;
-				checksum = checksum.wrapping_add(length);
-				checksum = checksum.wrapping_add(addr_hi);
-				checksum = checksum.wrapping_add(addr_lo);
+				let addr = *accum_index / address_unit;
+				let addr_upper = addr >> 16;
 
-				for byte in accum_bytes.iter().copied()
+				// Emit an Extended Linear Address record
+				// whenever the upper 16 bits change
+				if addr_upper != cur_addr_upper
 				{
-					result.push_str(&format!("{:02X}", byte));
-					checksum = checksum.wrapping_add(byte);
+					result.push_str(&format_intelhex_record(
+						4,
+						0,
+						&[(addr_upper >> 8) as u8, addr_upper as u8]));
+
+					cur_addr_upper = addr_upper;
 				}
-				
-				result.push_str(&format!("{:02X}", (!checksum).wrapping_add(1)));
-				result.push('\n');
+
+				result.push_str(&format_intelhex_record(
+					0,
+					addr as u16,
+					accum_bytes));
 			}
 
 			accum_bytes.clear();
@@ -266,6 +368,8 @@ impl util::BitVec
 
 		while read_index < self.len()
 		{
+			let byte_index = read_index / 8;
+
 			let mut byte: u8 = 0;
 			for _ in 0..8
 			{
@@ -274,9 +378,24 @@ impl util::BitVec
 				read_index += 1;
 			}
 
+			// Skip over gaps that were never written to
+			if !emit_holes && !written[byte_index] && byte == 0
+			{
+				flush_bytes(read_index, &mut accum_index, &mut accum_bytes);
+				continue;
+			}
+
+			// Records can't cross a 64 KiB boundary
+			let byte_addr_upper = (byte_index * 8 / address_unit) >> 16;
+			let accum_addr_upper = (accum_index / address_unit) >> 16;
+			if accum_bytes.len() > 0 && byte_addr_upper != accum_addr_upper
+			{
+				flush_bytes(byte_index * 8, &mut accum_index, &mut accum_bytes);
+			}
+
 			accum_bytes.push(byte);
 
-			if accum_bytes.len() >= 32
+			if accum_bytes.len() >= record_len
 			{
 				flush_bytes(read_index, &mut accum_index, &mut accum_bytes);
 			}
@@ -289,6 +408,210 @@ impl util::BitVec
 	}
 
 
+	/// Formats as Motorola S-records, with up to `record_len`
+	/// data bytes each. Emits records only for the bytes that
+	/// were written to or are nonzero, unless `emit_holes` is set.
+	/// Uses the narrowest address width that fits the output,
+	/// and starts with an S0 record carrying `header`, if given,
+	/// which must fit in `SREC_HEADER_MAX_LEN` bytes. `record_len`
+	/// is clamped to what a record of that address width holds.
+	pub fn format_srec(
+		&self,
+		header: Option<&str>,
+		record_len: usize,
+		emit_holes: bool)
+		-> String
+	{
+		let mut result = String::new();
+
+		let byte_num = (self.len() + 7) / 8;
+
+		let mut written = vec![false; byte_num];
+		for span in &self.spans
+		{
+			if let Some(offset) = span.offset
+			{
+				for index in offset..(offset + span.size)
+				{
+					written[index / 8] = true;
+				}
+			}
+		}
+
+		let bytes = self.format_binary();
+
+		let max_addr = byte_num.saturating_sub(1);
+
+		let (data_type, end_type, addr_size) = {
+			if max_addr <= 0xffff
+				{ (1, 9, 2) }
+			else if max_addr <= 0xff_ffff
+				{ (2, 8, 3) }
+			else
+				{ (3, 7, 4) }
+		};
+
+		let record_len = record_len.clamp(1, SREC_COUNT_MAX - addr_size - 1);
+
+		if let Some(header) = header
+		{
+			result.push_str(&format_srec_record(
+				0,
+				0,
+				2,
+				header.as_bytes()));
+		}
+
+		let is_emitted = |index: usize|
+			emit_holes || written[index] || bytes[index] != 0;
+
+		let mut index = 0;
+		while index < byte_num
+		{
+			if !is_emitted(index)
+			{
+				index += 1;
+				continue;
+			}
+
+			let start = index;
+			while index < byte_num &&
+				index - start < record_len &&
+				is_emitted(index)
+			{
+				index += 1;
+			}
+
+			result.push_str(&format_srec_record(
+				data_type,
+				start,
+				addr_size,
+				&bytes[start..index]));
+		}
+
+		result.push_str(&format_srec_record(
+			end_type,
+			0,
+			addr_size,
+			&[]));
+
+		result
+	}
+
+
+	/// Formats as a Verilog `$readmemh` file
+	pub fn format_readmemh(
+		&self,
+		word_size: usize,
+		words_per_line: usize)
+		-> String
+	{
+		self.format_readmem(word_size, words_per_line, 4)
+	}
+
+
+	/// Formats as a Verilog `$readmemb` file
+	pub fn format_readmemb(
+		&self,
+		word_size: usize,
+		words_per_line: usize)
+		-> String
+	{
+		self.format_readmem(word_size, words_per_line, 1)
+	}
+
+
+	/// Writes up to `words_per_line` words per line, skipping
+	/// over words that were neither emitted nor hold a nonzero
+	/// value, and marking the word address with `@` after each gap.
+	fn format_readmem(
+		&self,
+		word_size: usize,
+		words_per_line: usize,
+		bits_per_digit: usize)
+		-> String
+	{
+		let mut result = String::new();
+
+		let word_num = (self.len() + word_size - 1) / word_size;
+
+		let mut emitted = vec![false; word_num];
+		for span in &self.spans
+		{
+			if let Some(offset) = span.offset
+			{
+				for index in offset..(offset + span.size)
+				{
+					emitted[index / word_size] = true;
+				}
+			}
+		}
+
+		let digit_num = (word_size + bits_per_digit - 1) / bits_per_digit;
+		let padding = digit_num * bits_per_digit - word_size;
+
+		let mut next_addr = 0;
+		let mut words_in_line = 0;
+		for word_index in 0..word_num
+		{
+			let mut bits = vec![false; padding];
+			for i in 0..word_size
+			{
+				bits.push(self.read_bit(word_index * word_size + i));
+			}
+
+			if !emitted[word_index] && !bits.contains(&true)
+			{
+				continue;
+			}
+
+			if word_index != next_addr
+			{
+				if words_in_line > 0
+				{
+					result.push('\n');
+					words_in_line = 0;
+				}
+
+				result.push_str(&format!("@{:x}\n", word_index));
+			}
+
+			if words_in_line > 0
+			{
+				result.push(' ');
+			}
+
+			for digit_bits in bits.chunks(bits_per_digit)
+			{
+				let mut digit = 0;
+				for bit in digit_bits
+				{
+					digit <<= 1;
+					digit |= if *bit { 1 } else { 0 };
+				}
+
+				result.push(std::char::from_digit(digit, 16).unwrap());
+			}
+
+			words_in_line += 1;
+			if words_in_line >= words_per_line
+			{
+				result.push('\n');
+				words_in_line = 0;
+			}
+
+			next_addr = word_index + 1;
+		}
+
+		if words_in_line > 0
+		{
+			result.push('\n');
+		}
+
+		result
+	}
+
+
 	pub fn format_separator(&self, radix: usize, separator: &str) -> String
 	{
 		let mut result = String::new();
@@ -324,20 +647,31 @@ impl util::BitVec
 	}
 
 
-	pub fn format_c_array(&self, radix: usize) -> String
+	/// Formats as a C array named `var_name`, with up to
+	/// `bytes_per_line` bytes per line, followed by a
+	/// `var_name_len` constant holding the byte count
+	pub fn format_c_array(
+		&self,
+		radix: usize,
+		var_name: &str,
+		bytes_per_line: usize)
+		-> String
 	{
 		let mut result = String::new();
 
-		result.push_str("const unsigned char data[] = {\n");
+		result.push_str(&format!("const unsigned char {}[] = {{\n", var_name));
 
 		let byte_num = self.len() / 8 + if self.len() % 8 != 0 { 1 } else { 0 };
-		let addr_max_width = format!("{:x}", byte_num - 1).len();
+		let addr_max_width = format!("{:x}", std::cmp::max(1, byte_num) - 1).len();
 
 		let mut index = 0;
-		result.push_str(&format!("\t/* 0x{:01$x} */ ", 0, addr_max_width));
-
 		while index < self.len()
 		{
+			if (index / 8) % bytes_per_line == 0
+			{
+				result.push_str(&format!("\t/* 0x{:01$x} */ ", index / 8, addr_max_width));
+			}
+
 			let mut byte: u8 = 0;
 			for _ in 0..8
 			{
@@ -355,51 +689,118 @@ impl util::BitVec
 
 			if index < self.len()
 			{
-				result.push_str(", ");
+				result.push_str(",");
 
-				if (index / 8) % 16 == 0
-				{
-					result.push_str(&format!("\n\t/* 0x{:01$x} */ ", index / 8, addr_max_width));
-				}
+				if (index / 8) % bytes_per_line == 0
+					{ result.push_str("\n"); }
+				else
+					{ result.push_str(" "); }
+			}
+			else
+			{
+				result.push_str("\n");
 			}
 		}
 
-		result.push_str("\n};");
+		result.push_str("};\n");
+		result.push_str(&format!("const unsigned {}_len = {};", var_name, byte_num));
 		result
 	}
 
 
 	// From: https://github.com/milanvidakovic/customasm/blob/master/src/asm/binary_output.rs#L84
-	pub fn format_logisim(&self, bits_per_chunk: usize) -> String
+	/// Formats as a Logisim memory image, with words of
+	/// `word_bits` size. If `rle` is set, runs of repeated
+	/// words are written as `count*value`.
+	pub fn format_logisim(&self, word_bits: usize, rle: bool) -> String
 	{
 		let mut result = String::new();
 		result.push_str("v2.0 raw\n");
 
+		let mut words = Vec::new();
+
 		let mut index = 0;
 		while index < self.len()
 		{
-			let mut value: u16 = 0;
-			for _ in 0..bits_per_chunk
+			words.push(self.format_word_hex(index, word_bits));
+			index += word_bits;
+		}
+
+		let words_per_line = std::cmp::max(1, 128 / word_bits);
+		let mut entries_in_line = 0;
+
+		let mut i = 0;
+		while i < words.len()
+		{
+			let mut run_len = 1;
+			if rle
 			{
-				value <<= 1;
-				value |= if self.read_bit(index) { 1 } else { 0 };
-				index += 1;
+				while i + run_len < words.len() &&
+					words[i + run_len] == words[i]
+				{
+					run_len += 1;
+				}
 			}
 
-			result.push_str(&format!("{:01$x} ", value, bits_per_chunk / 4));
-			if (index / 8) % 16 == 0
-				{ result.push('\n'); }
+			if run_len > 1
+				{ result.push_str(&format!("{}*{} ", run_len, words[i])); }
+			else
+				{ result.push_str(&format!("{} ", words[i])); }
+
+			i += run_len;
+
+			entries_in_line += 1;
+			if entries_in_line == words_per_line
+			{
+				result.push('\n');
+				entries_in_line = 0;
+			}
 		}
 
 		result
-    }
+	}
+
+
+	/// Formats the `word_bits` bits starting at `index`
+	/// as lowercase hex digits
+	fn format_word_hex(&self, index: usize, word_bits: usize) -> String
+	{
+		let mut result = String::new();
+
+		// The first digit takes the leftover bits
+		// when the word size isn't a multiple of 4
+		let mut digit_bits = word_bits % 4;
+		if digit_bits == 0
+			{ digit_bits = 4; }
+
+		let mut bit = 0;
+		while bit < word_bits
+		{
+			let mut digit = 0;
+			for _ in 0..digit_bits
+			{
+				digit <<= 1;
+				digit |= if self.read_bit(index + bit) { 1 } else { 0 };
+				bit += 1;
+			}
+
+			result.push(std::char::from_digit(digit, 16).unwrap());
+			digit_bits = 4;
+		}
 
+		result
+	}
 
+
+	/// Formats as a listing of every span next to its source
+	/// excerpt, with an extra column for the decimal value
+	/// of each span if `decimal` is set
 	pub fn format_annotated(
 		&self,
 		fileserver: &dyn util::FileServer,
 		base: usize,
-		digits_per_group: usize)
+		digits_per_group: usize,
+		decimal: bool)
 		-> String
 	{
 		let mut result = String::new();
@@ -444,16 +845,45 @@ impl util::BitVec
             }
 		}
 
+		let decimal_strs = sorted_spans
+			.iter()
+			.map(|span| {
+				match span.offset
+				{
+					Some(offset) if decimal && span.size > 0 =>
+						self.format_span_decimal(offset, span.size, span.signed),
+					_ => "".to_string(),
+				}
+			})
+			.collect::<Vec<_>>();
+
+		let decimal_width = decimal_strs
+			.iter()
+			.map(|s| s.len())
+			.max()
+			.unwrap_or(0);
+
 		result.push_str(&format!(" {:>1$} |", "outp", outp_width + outp_bit_width + 1));
 		result.push_str(&format!(" {:>1$} |", "addr", addr_width));
-		result.push_str(&format!(" data (base {})", base));
+
+		let data_header = format!("data (base {})", base);
+		if decimal
+		{
+			content_width = std::cmp::max(content_width, data_header.len());
+			result.push_str(&format!(" {:1$} | dec", data_header, content_width));
+		}
+		else
+		{
+			result.push_str(&format!(" {}", data_header));
+		}
+
 		result.push_str("\n");
 		result.push_str("\n");
 
 		let mut prev_file_handle = util::FileServerHandle::MAX;
         let mut prev_file_chars = "".to_string();
 
-        for span in &sorted_spans
+        for (span, decimal_str) in sorted_spans.iter().zip(decimal_strs.iter())
         {
             if let Some(offset) = span.offset
             {
@@ -505,6 +935,12 @@ impl util::BitVec
             let char_counter = util::CharCounter::new(&prev_file_chars);
 
             result.push_str(&format!("{:1$}", contents_str, content_width));
+
+            if decimal
+            {
+                result.push_str(&format!(" | {:>1$}", decimal_str, decimal_width));
+            }
+
             result.push_str(&format!(" ; {}", char_counter.get_excerpt(span_location.0, span_location.1)));
             result.push_str("\n");
 		}
@@ -513,6 +949,28 @@ impl util::BitVec
 	}
 	
 
+	fn format_span_decimal(
+		&self,
+		offset: usize,
+		size: usize,
+		signed: bool)
+		-> String
+	{
+		let mut value = util::BigInt::new(0, Some(size));
+		for i in 0..size
+		{
+			value.set_bit(size - 1 - i, self.read_bit(offset + i));
+		}
+
+		if signed
+		{
+			value = value.as_twos_complement(size);
+		}
+
+		format!("{}", value)
+	}
+	
+
 	// Turing Complete is a game in which you advance from nand gates to
 	// computer architecture. Its assembly editor uses `#` comments,
 	// `0b` and `0x` prefixes for binary and hex, and groups of 8 or 8x4 bytes.
@@ -647,8 +1105,165 @@ impl util::BitVec
 	}
 
 
-    pub fn format_addrspan(&self, fileserver: &dyn util::FileServer) -> String
+	/// Formats as a listing of every line of the source files
+	/// that produced output, in source order, next to the
+	/// address and data of the spans starting on that line.
+	/// Data longer than `bytes_per_line` continues on the
+	/// following lines, with the other columns left blank.
+	pub fn format_listing(
+		&self,
+		fileserver: &dyn util::FileServer,
+		bytes_per_line: usize)
+		-> String
+	{
+		let mut result = String::new();
+
+		let mut sorted_spans = self.spans
+			.iter()
+			.filter(|span| span.span.location().is_some())
+			.collect::<Vec<_>>();
+
+		sorted_spans.sort_by_key(|span|
+			(span.span.file_handle, span.span.location().unwrap().0));
+
+		let mut file_handles = sorted_spans
+			.iter()
+			.map(|span| span.span.file_handle)
+			.collect::<Vec<_>>();
+
+		file_handles.dedup();
+
+		let addr_width = sorted_spans
+			.iter()
+			.map(|span| format!("{:x}", span.addr).len())
+			.max()
+			.unwrap_or(0)
+			.max(4);
+
+		let data_width = std::cmp::max(4, bytes_per_line * 3 - 1);
+
+		let mut span_index = 0;
+
+		for file_handle in file_handles
+		{
+			let src = fileserver.get_str_unwrap(file_handle);
+
+			// Don't list the empty line after a trailing line break
+			let lines = src
+				.strip_suffix('\n')
+				.unwrap_or(&src)
+				.split('\n')
+				.collect::<Vec<_>>();
+
+			let line_num = lines.len();
+			let line_width = std::cmp::max(4, format!("{}", line_num).len());
+
+			result.push_str(&format!(
+				"; {}\n",
+				fileserver.get_filename(file_handle)));
+
+			result.push_str(&format!(
+				" {:>3$} | {:>4$} | {:5$} | source\n",
+				"line", "addr", "data",
+				line_width, addr_width, data_width));
+
+			let mut line_start = 0;
+
+			for (line_index, line) in lines.iter().enumerate()
+			{
+				let line_end = line_start + line.len() + 1;
+
+				let mut addr = None;
+				let mut groups = Vec::new();
+
+				while span_index < sorted_spans.len() &&
+					sorted_spans[span_index].span.file_handle == file_handle &&
+					sorted_spans[span_index].span.location().unwrap().0 < line_end
+				{
+					let span = sorted_spans[span_index];
+					addr = addr.or(Some(&span.addr));
+
+					if let Some(offset) = span.offset
+					{
+						groups.extend(self.format_listing_groups(offset, span.size));
+					}
+
+					span_index += 1;
+				}
+
+				let addr_str = match addr
+				{
+					Some(addr) => format!("{:x}", addr),
+					None => "".to_string(),
+				};
+
+				let mut data_lines = groups
+					.chunks(bytes_per_line)
+					.map(|chunk| chunk.join(" "));
+
+				let listing_line = format!(
+					" {:>4$} | {:>5$} | {:6$} | {}",
+					line_index + 1,
+					addr_str,
+					data_lines.next().unwrap_or_default(),
+					line,
+					line_width, addr_width, data_width);
+
+				result.push_str(listing_line.trim_end());
+				result.push('\n');
+
+				for data_line in data_lines
+				{
+					result.push_str(&format!(
+						" {:>3$} | {:>4$} | {}",
+						"", "",
+						data_line,
+						line_width, addr_width));
+
+					result.push('\n');
+				}
+
+				line_start = line_end;
+			}
+		}
+
+		result
+	}
+
+
+	/// Splits a span of output into groups of two hex digits,
+	/// with the last group shorter for sizes not a multiple of 8
+	fn format_listing_groups(&self, offset: usize, size: usize) -> Vec<String>
+	{
+		let mut groups = Vec::new();
+
+		let mut index = 0;
+		while index < size
+		{
+			let group_size = std::cmp::min(8, size - index);
+			groups.push(self.format_word_hex(offset + index, group_size));
+			index += group_size;
+		}
+
+		groups
+	}
+
+
+    /// Lists the source span of every piece of output, with
+    /// addresses in the given radix, zero-padded to `width`
+    /// digits.
+    pub fn format_addrspan(
+        &self,
+        fileserver: &dyn util::FileServer,
+        radix: usize,
+        width: usize)
+        -> String
     {
+        let format_number = |value: usize, width: usize|
+        {
+            util::BigInt::from(value).as_radix_string(radix, width, false)
+        };
+
         let mut result = String::new();
 
         let mut sorted_spans = self.spans.clone();
@@ -672,14 +1287,19 @@ impl util::BitVec
 
             if let Some(offset) = span.offset
             {
-                result.push_str(&format!("{:x}:{:x} | ", offset / 8, offset % 8));
+                result.push_str(&format!(
+                    "{}:{} | ",
+                    format_number(offset / 8, width),
+                    format_number(offset % 8, 0)));
             }
             else
             {
                 result.push_str(&format!("-:- | "));
             }
 
-            result.push_str(&format!("{:x} | ", span.addr));
+            result.push_str(&format!(
+                "{} | ",
+                span.addr.as_radix_string(radix, width, false)));
 
             if let Some((start, end)) = span.span.location()
             {
@@ -704,3 +1324,88 @@ impl util::BitVec
         result
     }
 }
+
+
+fn format_intelhex_record(
+	record_type: u8,
+	addr: u16,
+	data: &[u8])
+	-> String
+{
+	let mut result = String::new();
+
+	let length = data.len() as u8;
+	let addr_hi = (addr >> 8) as u8;
+	let addr_lo = addr as u8;
+
+	result.push(':');
+	result.push_str(&format!("{:02X}", length));
+	result.push_str(&format!("{:02X}", addr_hi));
+	result.push_str(&format!("{:02X}", addr_lo));
+	result.push_str(&format!("{:02X}", record_type));
+
+	let mut checksum = 0_u8;
+	checksum = checksum.wrapping_add(length);
+	checksum = checksum.wrapping_add(addr_hi);
+	checksum = checksum.wrapping_add(addr_lo);
+	checksum = checksum.wrapping_add(record_type);
+
+	for byte in data.iter().copied()
+	{
+		result.push_str(&format!("{:02X}", byte));
+		checksum = checksum.wrapping_add(byte);
+	}
+	
+	result.push_str(&format!("{:02X}", (!checksum).wrapping_add(1)));
+	result.push('\n');
+	result
+}
+
+
+/// The largest byte count of an S-record, which
+/// covers the address, the data, and the checksum
+const SREC_COUNT_MAX: usize = 0xff;
+
+
+/// The most data bytes an S0 record can hold,
+/// after its 2-byte address and the checksum
+pub const SREC_HEADER_MAX_LEN: usize = SREC_COUNT_MAX - 2 - 1;
+
+
+fn format_srec_record(
+	record_type: u8,
+	addr: usize,
+	addr_size: usize,
+	data: &[u8])
+	-> String
+{
+	let mut result = String::new();
+
+	// The byte count covers the address,
+	// the data, and the checksum
+	let count = u8::try_from(addr_size + data.len() + 1)
+		.expect("S-record header too long for its byte count");
+
+	result.push('S');
+	result.push_str(&format!("{}", record_type));
+	result.push_str(&format!("{:02X}", count));
+
+	let mut checksum = count;
+
+	for i in (0..addr_size).rev()
+	{
+		let addr_byte = (addr >> (i * 8)) as u8;
+		result.push_str(&format!("{:02X}", addr_byte));
+		checksum = checksum.wrapping_add(addr_byte);
+	}
+
+	for byte in data.iter().copied()
+	{
+		result.push_str(&format!("{:02X}", byte));
+		checksum = checksum.wrapping_add(byte);
+	}
+
+	result.push_str(&format!("{:02X}", !checksum));
+	result.push('\n');
+	result
+}
@@ -0,0 +1,68 @@
+use crate::*;
+
+
+/// How much of the output was emitted by one source file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitVecFileUsage
+{
+    pub filename: String,
+    pub bits: usize,
+}
+
+
+impl util::BitVec
+{
+    /// Tallies the output bits by the file of the span
+    /// which emitted them, in order of first appearance
+    /// in the output. Spans without an output position
+    /// aren't counted.
+    pub fn file_usage(
+        &self,
+        fileserver: &dyn util::FileServer)
+        -> Vec<BitVecFileUsage>
+    {
+        let mut sorted_spans: Vec<_> = self.spans
+            .iter()
+            .filter(|s| s.offset.is_some() && s.size > 0)
+            .collect();
+
+        sorted_spans.sort_by_key(|s| s.offset);
+
+        let mut result: Vec<BitVecFileUsage> = Vec::new();
+
+        for span in sorted_spans
+        {
+            let filename = fileserver.get_filename(span.span.file_handle);
+
+            match result.iter_mut().find(|u| u.filename == filename)
+            {
+                Some(usage) => usage.bits += span.size,
+                None => result.push(BitVecFileUsage {
+                    filename: filename.to_string(),
+                    bits: span.size,
+                }),
+            }
+        }
+
+        result
+    }
+
+
+    pub fn format_file_usage(&self, fileserver: &dyn util::FileServer) -> String
+    {
+        let mut result = String::new();
+
+        result.push_str("; bytes | bits | file\n");
+
+        for usage in self.file_usage(fileserver)
+        {
+            result.push_str(&format!(
+                "{} | {} | {}\n",
+                usage.bits / 8,
+                usage.bits,
+                usage.filename));
+        }
+
+        result
+    }
+}
@@ -0,0 +1,17 @@
+/// Computes the SHA-256 digest of the given bytes,
+/// formatted as lowercase hex.
+pub fn sha256_hex(
+    bytes: &[u8])
+    -> String
+{
+    use sha2::Digest;
+
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(bytes);
+
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
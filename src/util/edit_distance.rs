@@ -0,0 +1,66 @@
+/// Computes the Levenshtein distance between two strings,
+/// counting single-character insertions, deletions,
+/// and substitutions.
+pub fn edit_distance(
+    a: &str,
+    b: &str)
+    -> usize
+{
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    let mut prev_row = (0..=b.len()).collect::<Vec<_>>();
+    let mut cur_row = vec![0; b.len() + 1];
+
+    for i in 0..a.len()
+    {
+        cur_row[0] = i + 1;
+
+        for j in 0..b.len()
+        {
+            let substitution_cost = {
+                if a[i] == b[j] { 0 } else { 1 }
+            };
+
+            cur_row[j + 1] = (prev_row[j] + substitution_cost)
+                .min(prev_row[j + 1] + 1)
+                .min(cur_row[j] + 1);
+        }
+
+        std::mem::swap(&mut prev_row, &mut cur_row);
+    }
+
+    prev_row[b.len()]
+}
+
+
+/// Finds the candidate closest to `name`, if any is
+/// close enough to be considered a likely typo.
+pub fn find_closest_match<'a, I>(
+    name: &str,
+    candidates: I)
+    -> Option<&'a str>
+    where I: IntoIterator<Item = &'a str>
+{
+    let max_distance = std::cmp::max(1, name.chars().count() / 3);
+
+    let mut best: Option<(&'a str, usize)> = None;
+
+    for candidate in candidates
+    {
+        let distance = edit_distance(name, candidate);
+
+        if distance == 0 || distance > max_distance
+        {
+            continue;
+        }
+
+        match best
+        {
+            Some((_, best_distance)) if best_distance <= distance => {}
+            _ => best = Some((candidate, distance)),
+        }
+    }
+
+    best.map(|b| b.0)
+}
\ No newline at end of file
@@ -35,6 +35,38 @@ pub trait FileServer
 		file_handle: FileServerHandle)
 		-> &str;
 
+
+	/// The path from which filenames referenced inside
+	/// the given file are resolved.
+	fn get_navigation_path(
+		&self,
+		file_handle: FileServerHandle)
+		-> String
+	{
+		self.get_filename(file_handle).to_string()
+	}
+
+
+	/// Registers in-memory contents under a virtual filename,
+	/// such as `<stdin>`. Filenames referenced inside it are
+	/// resolved relative to `base_dir`. File servers that
+	/// don't support this report an error by default.
+	fn add_virtual(
+		&mut self,
+		report: &mut diagn::Report,
+		filename: &str,
+		_base_dir: &str,
+		_contents: Vec<u8>)
+		-> Result<(), ()>
+	{
+		report.error(
+			format!(
+				"cannot add virtual file `{}` to this file server",
+				filename));
+
+		Err(())
+	}
+
 	
 	fn get_bytes(
 		&self,
@@ -124,6 +156,7 @@ pub struct FileServerMock
 	handles: std::collections::HashMap<String, FileServerHandle>,
 	handles_to_filename: Vec<String>,
 	files: Vec<Vec<u8>>,
+	navigation_paths: std::collections::HashMap<FileServerHandle, String>,
 }
 
 
@@ -132,6 +165,8 @@ pub struct FileServerReal
 	handles: std::collections::HashMap<String, FileServerHandle>,
 	handles_to_filename: Vec<String>,
 	std_files: Vec<Option<&'static str>>,
+	virtual_files: std::collections::HashMap<FileServerHandle, Vec<u8>>,
+	navigation_paths: std::collections::HashMap<FileServerHandle, String>,
 }
 
 
@@ -143,6 +178,7 @@ impl FileServerMock
 			handles: std::collections::HashMap::new(),
 			handles_to_filename: Vec::new(),
 			files: Vec::new(),
+			navigation_paths: std::collections::HashMap::new(),
 		}
 	}
 
@@ -192,6 +228,8 @@ impl FileServerReal
 			handles: std::collections::HashMap::new(),
 			handles_to_filename: Vec::new(),
 			std_files: Vec::new(),
+			virtual_files: std::collections::HashMap::new(),
+			navigation_paths: std::collections::HashMap::new(),
 		}
 	}
 
@@ -279,6 +317,39 @@ impl FileServer for FileServerMock
 	}
 
 
+	fn get_navigation_path(
+		&self,
+		file_handle: FileServerHandle)
+		-> String
+	{
+		match self.navigation_paths.get(&file_handle)
+		{
+			Some(path) => path.clone(),
+			None => self.get_filename(file_handle).to_string(),
+		}
+	}
+
+
+	fn add_virtual(
+		&mut self,
+		_report: &mut diagn::Report,
+		filename: &str,
+		base_dir: &str,
+		contents: Vec<u8>)
+		-> Result<(), ()>
+	{
+		self.add(filename, contents);
+
+		let handle = *self.handles.get(filename).unwrap();
+
+		self.navigation_paths.insert(
+			handle,
+			make_navigation_path(filename, base_dir));
+
+		Ok(())
+	}
+
+
 	fn get_bytes(
 		&self,
 		_report: &mut diagn::Report,
@@ -393,6 +464,50 @@ impl FileServer for FileServerReal
 	}
 
 
+	fn get_navigation_path(
+		&self,
+		file_handle: FileServerHandle)
+		-> String
+	{
+		match self.navigation_paths.get(&file_handle)
+		{
+			Some(path) => path.clone(),
+			None => self.get_filename(file_handle).to_string(),
+		}
+	}
+
+
+	fn add_virtual(
+		&mut self,
+		_report: &mut diagn::Report,
+		filename: &str,
+		base_dir: &str,
+		contents: Vec<u8>)
+		-> Result<(), ()>
+	{
+		let next_index = self.handles.len();
+
+		let handle = *self.handles
+			.entry(filename.to_string())
+			.or_insert(next_index);
+
+		while handle >= self.std_files.len()
+		{
+			self.handles_to_filename.push("".to_string());
+			self.std_files.push(None);
+		}
+
+		self.handles_to_filename[handle] = filename.to_string();
+		self.virtual_files.insert(handle, contents);
+
+		self.navigation_paths.insert(
+			handle,
+			make_navigation_path(filename, base_dir));
+
+		Ok(())
+	}
+
+
 	fn get_bytes(
 		&self,
 		report: &mut diagn::Report,
@@ -404,6 +519,11 @@ impl FileServer for FileServerReal
 		{
 			return Ok(std_contents.as_bytes().iter().copied().collect());
 		}
+
+		if let Some(virtual_contents) = self.virtual_files.get(&file_handle)
+		{
+			return Ok(virtual_contents.clone());
+		}
 		
 		let filename = &self.handles_to_filename[file_handle];
 		let filename_path = &std::path::Path::new(filename);
@@ -498,6 +618,25 @@ impl FileServer for FileServerReal
 }
 
 
+/// Builds a path inside `base_dir`, so that navigating
+/// from it resolves relative to that directory.
+fn make_navigation_path(
+	filename: &str,
+	base_dir: &str)
+	-> String
+{
+	let file_stem = filename
+		.rsplit(|c| c == '/' || c == '\\')
+		.next()
+		.unwrap_or(filename);
+
+	std::path::Path::new(base_dir)
+		.join(file_stem)
+		.to_string_lossy()
+		.replace("\\", "/")
+}
+
+
 fn report_error<S>(
 	report: &mut diagn::Report,
 	span: Option<diagn::Span>,
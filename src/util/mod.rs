@@ -3,6 +3,7 @@ pub use self::char_counter::CharCounter;
 
 mod bigint;
 pub use self::bigint::BigInt;
+pub use self::bigint::BIGINT_MAX_BITS;
 
 mod bitvec;
 pub use self::bitvec::{
@@ -11,6 +12,10 @@ pub use self::bitvec::{
 };
 
 mod bitvec_format;
+pub use self::bitvec_format::SREC_HEADER_MAX_LEN;
+
+mod bitvec_stats;
+pub use self::bitvec_stats::BitVecFileUsage;
 
 mod overlap_checker;
 pub use self::overlap_checker::OverlapChecker;
@@ -24,6 +29,18 @@ pub use self::fileserver::{
     FILESERVER_MOCK_WRITE_FILENAME_SUFFIX,
 };
 
+mod digest;
+pub use self::digest::sha256_hex;
+
+mod tar;
+pub use self::tar::make_tar;
+
+mod edit_distance;
+pub use self::edit_distance::{
+    edit_distance,
+    find_closest_match,
+};
+
 mod filename;
 pub use self::filename::{
     STD_PATH_PREFIX,
@@ -40,6 +57,7 @@ pub use self::string_styler::StringStyler;
 
 mod symbol_manager;
 pub use self::symbol_manager::{
+    AnonymousDirection,
     SymbolContext,
     SymbolDecl,
     SymbolKind,
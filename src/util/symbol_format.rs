@@ -3,19 +3,28 @@ use crate::*;
 
 impl util::SymbolManager<asm::Symbol>
 {
+    /// Emits a `name = value` line for every symbol, with
+    /// the value in the given radix, zero-padded to `width`
+    /// digits.
     pub fn format_default(
         &self,
         decls: &asm::ItemDecls,
-        defs: &asm::ItemDefs)
+        defs: &asm::ItemDefs,
+        prefix: &str,
+        radix: usize,
+        width: usize)
         -> String
 	{
         self.format(
             decls,
             defs,
+            prefix,
             &mut |result, _symbol_decl, name, bigint|
             {
                 result.push_str(name);
-                result.push_str(&format!(" = 0x{:x}\n", bigint));
+                result.push_str(" = ");
+                result.push_str(&bigint.as_radix_string(radix, width, true));
+                result.push_str("\n");
             })
     }
 
@@ -23,12 +32,14 @@ impl util::SymbolManager<asm::Symbol>
     pub fn format_mesen_mlb(
         &self,
         decls: &asm::ItemDecls,
-        defs: &asm::ItemDefs)
+        defs: &asm::ItemDefs,
+        prefix: &str)
         -> String
 	{
         self.format(
             decls,
             defs,
+            prefix,
             &mut |result, symbol_decl, name, bigint|
             {
                 if let util::SymbolKind::Constant = symbol_decl.kind
@@ -73,10 +84,109 @@ impl util::SymbolManager<asm::Symbol>
     }
 
 
+    /// Emits a VICE monitor `al` command for every label,
+    /// with the dots in names replaced by `_`.
+    pub fn format_vice(
+        &self,
+        decls: &asm::ItemDecls,
+        defs: &asm::ItemDefs,
+        prefix: &str)
+        -> String
+	{
+        self.format(
+            decls,
+            defs,
+            prefix,
+            &mut |result, symbol_decl, name, bigint|
+            {
+                if let util::SymbolKind::Constant = symbol_decl.kind
+                {
+                    return;
+                }
+
+                if bigint.sign() < 0
+                {
+                    return;
+                }
+
+                result.push_str(&format!(
+                    "al C:{:04x} .{}\n",
+                    bigint,
+                    name.replace(".", "_")));
+            })
+    }
+
+
+    /// Emits a `#define` for every label, with names made
+    /// into valid C identifiers, and optionally wrapped in
+    /// an include guard. Values are given in decimal or
+    /// hex, and hex values are zero-padded to `width`
+    /// digits, since a leading zero would make a decimal
+    /// value octal in C.
+    pub fn format_c_header(
+        &self,
+        decls: &asm::ItemDecls,
+        defs: &asm::ItemDefs,
+        prefix: &str,
+        guard: Option<&str>,
+        radix: usize,
+        width: usize)
+        -> String
+	{
+        let defines = self.format(
+            decls,
+            defs,
+            prefix,
+            &mut |result, symbol_decl, name, bigint|
+            {
+                if let util::SymbolKind::Constant = symbol_decl.kind
+                {
+                    return;
+                }
+
+                result.push_str("#define ");
+                result.push_str(&make_c_identifier(name));
+
+                let value = match radix
+                {
+                    16 => bigint.as_radix_string(radix, width, true),
+                    _ => bigint.as_radix_string(radix, 0, true),
+                };
+
+                if bigint.sign() < 0
+                {
+                    result.push_str(&format!(" ({})\n", value));
+                }
+                else
+                {
+                    result.push_str(&format!(" {}\n", value));
+                }
+            });
+
+        match guard
+        {
+            None => defines,
+            Some(guard) =>
+            {
+                let guard = make_c_identifier(guard);
+
+                format!(
+                    "#ifndef {}\n#define {}\n\n{}\n#endif\n",
+                    guard,
+                    guard,
+                    defines)
+            }
+        }
+    }
+
+
+    /// Calls `formatter` for every emitted symbol, with its
+    /// full name, including the given `prefix`.
     pub fn format<FnFormat>(
         &self,
         decls: &asm::ItemDecls,
         defs: &asm::ItemDefs,
+        prefix: &str,
         formatter: &mut FnFormat)
         -> String
         where FnFormat: FnMut(
@@ -91,6 +201,7 @@ impl util::SymbolManager<asm::Symbol>
         self.format_recursive(
             decls,
             defs,
+            prefix,
             &mut result,
             &self.globals,
             &mut vec![],
@@ -104,6 +215,7 @@ impl util::SymbolManager<asm::Symbol>
         &self,
         decls: &asm::ItemDecls,
         defs: &asm::ItemDefs,
+        prefix: &str,
         result: &mut String,
         children: &std::collections::HashMap<String, util::ItemRef<asm::Symbol>>,
         hierarchy: &mut Vec<String>,
@@ -135,7 +247,7 @@ impl util::SymbolManager<asm::Symbol>
                 {
                     expr::Value::Integer(ref bigint) =>
                     {
-                        let mut name = String::new();
+                        let mut name = prefix.to_string();
 
                         for i in 0..hierarchy.len()
                         {
@@ -157,6 +269,7 @@ impl util::SymbolManager<asm::Symbol>
             self.format_recursive(
                 decls,
                 defs,
+                prefix,
                 result,
                 &symbol_decl.children,
                 hierarchy,
@@ -165,4 +278,33 @@ impl util::SymbolManager<asm::Symbol>
             hierarchy.pop();
         }
     }
-}
\ No newline at end of file
+}
+
+
+/// Replaces every character not allowed in a C identifier
+/// with an underscore, as for the `.` in local labels.
+fn make_c_identifier(
+    name: &str)
+    -> String
+{
+    let mut result = String::new();
+
+    if name.starts_with(|c: char| c.is_ascii_digit())
+    {
+        result.push('_');
+    }
+
+    for c in name.chars()
+    {
+        if c.is_ascii_alphanumeric() || c == '_'
+        {
+            result.push(c);
+        }
+        else
+        {
+            result.push('_');
+        }
+    }
+
+    result
+}
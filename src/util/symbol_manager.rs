@@ -7,6 +7,9 @@ pub struct SymbolManager<T>
     decls: Vec<SymbolDecl<T>>,
     pub(super) globals: std::collections::HashMap<String, util::ItemRef<T>>,
     span_refs: std::collections::HashMap<diagn::Span, util::ItemRef<T>>,
+    excluded: std::collections::HashMap<String, diagn::Span>,
+    anonymous_forward: Vec<util::ItemRef<T>>,
+    anonymous_backward: Vec<util::ItemRef<T>>,
     report_as: &'static str,
 }
 
@@ -24,6 +27,18 @@ pub struct SymbolDecl<T>
 }
 
 
+impl<T> SymbolDecl<T>
+{
+    /// Whether this is an anonymous `+` or `-` label,
+    /// declared under a generated name, which no
+    /// user symbol can clash with
+    pub fn is_anonymous(&self) -> bool
+    {
+        self.name.contains('#')
+    }
+}
+
+
 #[derive(Copy, Clone, Debug)]
 pub enum SymbolKind
 {
@@ -38,6 +53,22 @@ pub enum SymbolKind
 pub struct SymbolContext
 {
     hierarchy: Vec<String>,
+
+    /// How many anonymous `+` labels come up to this
+    /// point, including one declared right here
+    anonymous_forward: usize,
+
+    /// How many anonymous `-` labels come up to this
+    /// point, including one declared right here
+    anonymous_backward: usize,
+}
+
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AnonymousDirection
+{
+    Forward,
+    Backward,
 }
 
 
@@ -49,6 +80,9 @@ impl<T> SymbolManager<T>
             decls: Vec::new(),
             globals: std::collections::HashMap::new(),
             span_refs: std::collections::HashMap::new(),
+            excluded: std::collections::HashMap::new(),
+            anonymous_forward: Vec::new(),
+            anonymous_backward: Vec::new(),
             report_as,
         }
     }
@@ -137,6 +171,14 @@ impl<T> SymbolManager<T>
     }
 
 
+    /// Iterates over all declarations, in the order
+    /// they were declared.
+    pub fn iter(&self) -> std::slice::Iter<'_, util::SymbolDecl<T>>
+    {
+        self.decls.iter()
+    }
+
+
     pub fn get(
         &self,
         item_ref: util::ItemRef<T>)
@@ -179,7 +221,13 @@ impl<T> SymbolManager<T>
         -> Option<util::ItemRef<T>>
         where S: std::borrow::Borrow<str> + std::fmt::Debug
     {
-        if hierarchy_level > ctx.hierarchy.len()
+        if let Some((direction, nth)) = parse_anonymous_ref(
+            hierarchy_level,
+            hierarchy)
+        {
+            self.get_anonymous(ctx, direction, nth)
+        }
+        else if hierarchy_level > ctx.hierarchy.len()
         {
             None
         }
@@ -218,14 +266,62 @@ impl<T> SymbolManager<T>
                     .iter()
                     .map(|s| s.borrow().to_string())
                     .collect::<Vec<String>>();
+
+                let displayable_name = self.get_displayable_name(
+                    hierarchy_level,
+                    &hierarchy_string);
+
+                let maybe_excluded_span = self.get_excluded(
+                    ctx,
+                    hierarchy_level,
+                    &hierarchy_string);
                 
-                report.error_span(
-                    format!("unknown {} `{}`",
-                        self.report_as,
-                        self.get_displayable_name(
-                            hierarchy_level,
-                            &hierarchy_string)),
-                    span);
+                if let Some((direction, _)) = parse_anonymous_ref(
+                    hierarchy_level,
+                    &hierarchy_string)
+                {
+                    report.error_span(
+                        format!(
+                            "no anonymous label `{}` {} this point",
+                            displayable_name,
+                            match direction
+                            {
+                                AnonymousDirection::Forward => "after",
+                                AnonymousDirection::Backward => "before",
+                            }),
+                        span);
+                }
+                else if hierarchy_level > ctx.hierarchy.len()
+                {
+                    report.error_span(
+                        format!(
+                            "no enclosing label for `{}`",
+                            displayable_name),
+                        span);
+                }
+                else if let Some(excluded_span) = maybe_excluded_span
+                {
+                    report.push_parent(
+                        format!(
+                            "{} `{}` not defined (only in excluded `#if` branch)",
+                            self.report_as,
+                            displayable_name),
+                        span);
+
+                    report.note_span(
+                        "excluded definition here",
+                        excluded_span);
+
+                    report.pop_parent();
+                }
+                else
+                {
+                    report.error_span(
+                        format!("unknown {} `{}`",
+                            self.report_as,
+                            displayable_name),
+                        span);
+                }
 
                 Err(())
             }
@@ -327,6 +423,8 @@ impl<T> SymbolManager<T>
 
             SymbolContext {
                 hierarchy: new_hierarchy,
+                anonymous_forward: ctx.anonymous_forward,
+                anonymous_backward: ctx.anonymous_backward,
             }
         };
 
@@ -363,6 +461,138 @@ impl<T> SymbolManager<T>
     }
 
 
+    /// Records a declaration that was dropped along with an
+    /// excluded conditional branch, and advances `ctx` past it
+    /// as if it had been declared.
+    pub fn declare_excluded(
+        &mut self,
+        span: diagn::Span,
+        ctx: &mut SymbolContext,
+        name: String,
+        hierarchy_level: usize)
+    {
+        if hierarchy_level > ctx.hierarchy.len()
+        {
+            return;
+        }
+
+        ctx.hierarchy.truncate(hierarchy_level);
+        ctx.hierarchy.push(name);
+
+        self.excluded
+            .entry(ctx.hierarchy.join("."))
+            .or_insert(span);
+    }
+
+
+    fn get_excluded(
+        &self,
+        ctx: &SymbolContext,
+        hierarchy_level: usize,
+        hierarchy: &[String])
+        -> Option<diagn::Span>
+    {
+        if hierarchy_level > ctx.hierarchy.len()
+        {
+            return None;
+        }
+
+        let full_name = ctx.hierarchy[0..hierarchy_level]
+            .iter()
+            .chain(hierarchy.iter())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(".");
+
+        self.excluded
+            .get(&full_name)
+            .copied()
+    }
+
+
+    /// Declares an anonymous `+` or `-` label, which
+    /// takes the place of the symbol in `ctx` without
+    /// opening a new scope for local symbols.
+    pub fn declare_anonymous(
+        &mut self,
+        report: &mut diagn::Report,
+        span: diagn::Span,
+        ctx: &SymbolContext)
+        -> Result<util::ItemRef<T>, ()>
+    {
+        let item_ref = self.declare(
+            report,
+            span,
+            ctx,
+            self.generate_anonymous_name(),
+            ctx.hierarchy.len(),
+            SymbolKind::Label)?;
+
+        self.get_mut(item_ref).ctx = ctx.clone();
+
+        Ok(item_ref)
+    }
+
+
+    /// Forgets the order of anonymous labels, so that
+    /// it can be rebuilt with `place_symbol` after
+    /// nodes have been added to the AST.
+    pub fn clear_anonymous(&mut self)
+    {
+        self.anonymous_forward.clear();
+        self.anonymous_backward.clear();
+    }
+
+
+    /// Records the next symbol in source order, so that
+    /// references to anonymous labels from its context
+    /// find the ones surrounding it.
+    pub fn place_symbol(
+        &mut self,
+        item_ref: util::ItemRef<T>,
+        anonymous: Option<AnonymousDirection>)
+    {
+        match anonymous
+        {
+            Some(AnonymousDirection::Forward) =>
+                self.anonymous_forward.push(item_ref),
+            Some(AnonymousDirection::Backward) =>
+                self.anonymous_backward.push(item_ref),
+            None => {}
+        }
+
+        let anonymous_forward = self.anonymous_forward.len();
+        let anonymous_backward = self.anonymous_backward.len();
+
+        let ctx = &mut self.get_mut(item_ref).ctx;
+        ctx.anonymous_forward = anonymous_forward;
+        ctx.anonymous_backward = anonymous_backward;
+    }
+
+
+    fn get_anonymous(
+        &self,
+        ctx: &SymbolContext,
+        direction: AnonymousDirection,
+        nth: usize)
+        -> Option<util::ItemRef<T>>
+    {
+        match direction
+        {
+            AnonymousDirection::Forward =>
+                self.anonymous_forward
+                    .get(ctx.anonymous_forward + nth - 1)
+                    .copied(),
+
+            AnonymousDirection::Backward =>
+                ctx.anonymous_backward
+                    .checked_sub(nth)
+                    .and_then(|i| self.anonymous_backward.get(i))
+                    .copied(),
+        }
+    }
+
+
     pub fn add_span_ref(
         &mut self,
         span: diagn::Span,
@@ -379,6 +609,48 @@ impl SymbolContext
     {
         SymbolContext {
             hierarchy: Vec::new(),
+            anonymous_forward: 0,
+            anonymous_backward: 0,
+        }
+    }
+}
+
+
+impl AnonymousDirection
+{
+    pub fn from_name(name: &str) -> Option<AnonymousDirection>
+    {
+        match name
+        {
+            "+" => Some(AnonymousDirection::Forward),
+            "-" => Some(AnonymousDirection::Backward),
+            _ => None,
         }
     }
+}
+
+
+/// Recognizes references to anonymous labels, like `++`
+/// for the second following `+` label, returning their
+/// direction and distance.
+fn parse_anonymous_ref<S>(
+    hierarchy_level: usize,
+    hierarchy: &[S])
+    -> Option<(AnonymousDirection, usize)>
+    where S: std::borrow::Borrow<str>
+{
+    if hierarchy_level != 0 || hierarchy.len() != 1
+    {
+        return None;
+    }
+
+    let name = hierarchy[0].borrow();
+    let direction = AnonymousDirection::from_name(name.get(0..1)?)?;
+
+    if !name.bytes().all(|c| c == name.as_bytes()[0])
+    {
+        return None;
+    }
+
+    Some((direction, name.len()))
 }
\ No newline at end of file
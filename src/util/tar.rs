@@ -0,0 +1,62 @@
+const TAR_BLOCK_SIZE: usize = 512;
+
+
+/// Packs the given files into an uncompressed ustar archive.
+/// Timestamps and ownership are left at zero, so the
+/// archive only depends on the given names and contents.
+pub fn make_tar(
+    entries: &[(String, Vec<u8>)])
+    -> Vec<u8>
+{
+    let mut result = Vec::new();
+
+    for (name, contents) in entries
+    {
+        let mut header = [0u8; TAR_BLOCK_SIZE];
+
+        write_tar_field(&mut header[0..100], name.as_bytes());
+        write_tar_field(&mut header[100..108], b"0000644");
+        write_tar_field(&mut header[108..116], b"0000000");
+        write_tar_field(&mut header[116..124], b"0000000");
+        write_tar_field(
+            &mut header[124..136],
+            format!("{:011o}", contents.len()).as_bytes());
+        write_tar_field(&mut header[136..148], b"00000000000");
+        header[156] = b'0';
+        write_tar_field(&mut header[257..263], b"ustar");
+        write_tar_field(&mut header[263..265], b"00");
+
+        // The checksum is computed with its own
+        // field taken as filled with spaces
+        header[148..156].fill(b' ');
+
+        let checksum = header
+            .iter()
+            .map(|b| *b as usize)
+            .sum::<usize>();
+
+        write_tar_field(
+            &mut header[148..156],
+            format!("{:06o}\0 ", checksum).as_bytes());
+
+        result.extend_from_slice(&header);
+        result.extend_from_slice(contents);
+
+        let padding = (TAR_BLOCK_SIZE - contents.len() % TAR_BLOCK_SIZE) % TAR_BLOCK_SIZE;
+        result.resize(result.len() + padding, 0);
+    }
+
+    // Two zeroed blocks mark the end of the archive
+    result.resize(result.len() + TAR_BLOCK_SIZE * 2, 0);
+
+    result
+}
+
+
+fn write_tar_field(
+    field: &mut [u8],
+    value: &[u8])
+{
+    let len = std::cmp::min(field.len(), value.len());
+    field[..len].copy_from_slice(&value[..len]);
+}
@@ -51,6 +51,7 @@ pub unsafe extern fn wasm_assemble(
 	
 	let formatted = driver::format_output(
 		&fileserver,
+		&opts,
 		&assembly.decls.as_ref().unwrap(),
 		&assembly.defs.as_ref().unwrap(),
 		&output,
@@ -0,0 +1,7 @@
+#ruledef
+{
+    halt => 0x55
+}
+
+#alias stop => halt
+#alias stop => halt ; error: duplicate alias `stop` / note:_:6: first declared here
@@ -0,0 +1 @@
+#alias nop => ; error: expected instruction
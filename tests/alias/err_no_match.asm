@@ -0,0 +1,7 @@
+#ruledef
+{
+    halt => 0x55
+}
+
+#alias nop => mov r0, r0
+nop ; error: no match found / note:_:6: expanded from alias
@@ -0,0 +1,8 @@
+#ruledef
+{
+    halt => 0x55
+}
+
+#alias a => b
+#alias b => a
+a ; error: expands to itself / note:_:6: while expanding alias
@@ -0,0 +1,10 @@
+#ruledef
+{
+    ld {x: u8} => 0xaa @ x
+}
+
+#alias clr => zero
+#alias zero => ld 0
+
+clr ; = 0xaa00
+zero ; = 0xaa00
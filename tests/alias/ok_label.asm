@@ -0,0 +1,11 @@
+#ruledef
+{
+    jmp {addr: u8} => 0xee @ addr
+    halt => 0x55
+}
+
+#alias restart => jmp start
+
+start:
+halt ; = 0x55
+restart ; = 0xee00
@@ -0,0 +1,11 @@
+#ruledef
+{
+    mov {dst: r0..r3}, {src: r0..r3} => 0b1000 @ dst`2 @ src`2
+}
+
+#alias nop => mov r0, r0
+
+mov r0, r0 ; = 0x80
+nop ; = 0x80
+NOP ; = 0x80
+mov r1, r2 ; = 0x86
@@ -0,0 +1,2 @@
+#d4 0x1
+#align 32, 0xea ; error: alignment gap of 28 bits can't be filled with 8-bit units
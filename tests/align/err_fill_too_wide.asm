@@ -0,0 +1,2 @@
+#d8 0x12
+#align 32, 0x100 ; error: fill value doesn't fit in the bank's 8-bit `addr_unit`
@@ -0,0 +1,10 @@
+#ruledef test
+{
+    ld {x} => 0x55 @ x`8
+}
+
+
+ld $
+label:
+#align label * 8 ; error: boundary must be a constant / note: depends on an address
+ld $
@@ -0,0 +1,9 @@
+#ruledef test
+{
+    ld {x} => 0x55 @ x`8
+}
+
+
+ld $
+#align 8 * ($ + 1) ; error: boundary must be a constant / note: depends on an address
+ld $
@@ -0,0 +1,13 @@
+#ruledef test
+{
+    ld {x} => 0x55 @ x`8
+}
+
+
+#const CHUNK = 8 * 4
+
+ld $ ; = 0x5500
+#align CHUNK ; = 0x0000
+ld $ ; = 0x5504
+#align CHUNK * 2 ; = 0x0000
+ld $ ; = 0x5508
@@ -0,0 +1,11 @@
+#ruledef test
+{
+    ld {x} => 0x55 @ x`8
+}
+
+ld 0x12 ; = 0x5512
+#align 32, 0xea ; = 0xeaea
+ld 0x34 ; = 0x5534
+#align 32, 0xea ; = 0xeaea
+label:
+#d8 label ; = 0x08
@@ -0,0 +1,10 @@
+#bankdef a
+{
+    #addr 0
+    #bits 16
+    #outp 0
+}
+
+#d16 0x1234 ; = 0x1234
+#align 64, 0xabcd ; = 0xabcd_abcd_abcd
+#d16 $ ; = 0x0004
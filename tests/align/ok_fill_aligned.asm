@@ -0,0 +1,3 @@
+#d8 0x12, 0x34, 0x56, 0x78 ; = 0x12345678
+#align 32, 0xea
+#d8 0x9a ; = 0x9a
@@ -0,0 +1,13 @@
+#bankdef a
+{
+    #addr 0
+    #size 8
+    #outp 0
+    #fill "aa55"
+}
+
+#d8 0x12
+#align 32
+#d8 0x34
+#align 64, 0xea
+; = 0x1255aa55_34eaeaea
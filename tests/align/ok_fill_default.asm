@@ -0,0 +1,3 @@
+#d8 0x12 ; = 0x12
+#align 32 ; = 0x000000
+#d8 0x34 ; = 0x34
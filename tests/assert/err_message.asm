@@ -0,0 +1,4 @@
+start:
+#d 0x12, 0x34
+end:
+#assert end - start <= 1, "table too large" ; error:_:4: table too large
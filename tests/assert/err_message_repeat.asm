@@ -0,0 +1,4 @@
+#repeat i in 0..3
+{
+    #assert i < 2, "index too large" ; error: index too large
+}
@@ -0,0 +1 @@
+#assert 1 == 2, 0x1234 ; error: expected string / error: assertion failed
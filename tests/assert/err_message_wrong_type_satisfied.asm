@@ -0,0 +1 @@
+#assert 1 == 1, 0x1234 ; error: expected string
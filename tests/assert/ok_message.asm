@@ -0,0 +1,5 @@
+start:
+#d 0x12, 0x34 ; = 0x1234
+end:
+#assert end - start <= 0x100, "table too large"
+#assert end - start == 2, "table size mismatch"
@@ -0,0 +1,4 @@
+#d 0xab ; = 0xab
+#assert $ == 1, "address mismatch"
+after:
+#d8 after ; = 0x01
@@ -0,0 +1,2 @@
+#bankdef a { addr = 0x100, addr_end = 0x80, outp = 0 } ; error: `addr_end` is before `addr` (0x80 < 0x100)
+#d8 0
@@ -0,0 +1,2 @@
+#bankdef a { addr = 0x100, addr_end = 0xff, addr_end_inclusive, outp = 0 } ; error: `addr_end` is before `addr` (0xff < 0x100)
+#d8 0
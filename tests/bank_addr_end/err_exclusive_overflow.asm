@@ -0,0 +1,2 @@
+#bankdef a { addr = 0x10, addr_end = 0x14, outp = 0 }
+#d8 0x11, 0x22, 0x33, 0x44, 0x55 ; error: out of range for bank / note:_:1: defined here / note: size of 4 units by 1 unit / note: last output that fit
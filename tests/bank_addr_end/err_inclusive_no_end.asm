@@ -0,0 +1 @@
+#bankdef a { addr = 0x10, size = 0x4, addr_end_inclusive, outp = 0 } ; error: without `addr_end`
@@ -0,0 +1,9 @@
+#bankdef a ; error: bank size is out of supported range
+{
+    addr = 0
+    addr_end = 0xffffffffffffffff
+    addr_end_inclusive = true
+    outp = 0
+}
+
+#d8 0
@@ -0,0 +1,9 @@
+#bankdef a ; error: bank size is out of supported range
+{
+    bits = 256
+    addr = 0
+    addr_end = 0x100000000000000
+    outp = 0
+}
+
+#d8 0
@@ -0,0 +1,2 @@
+#bankdef a { addr = 0x100, addr_end = 0x100, addr_end_inclusive, outp = 0, fill }
+#d8 0x12 ; = 0x12
@@ -0,0 +1,3 @@
+#bankdef a { addr = 0x10, addr_end = 0x14, outp = 0, fill }
+#d8 0x11
+; = 0x11000000
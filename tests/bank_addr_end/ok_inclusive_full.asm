@@ -0,0 +1,2 @@
+#bankdef a { addr = 0x10, addr_end = 0x14, addr_end_inclusive, outp = 0 }
+#d8 0x11, 0x22, 0x33, 0x44, 0x55 ; = 0x1122334455
@@ -0,0 +1,11 @@
+#bankdef a
+{
+    bits = 12
+    addr = 0x10
+    outp = 0
+}
+
+#d12 0x123, 0x456
+#d12 $
+#d 0xa`4, 0xbc`8
+; = 0x123456_012_abc
@@ -0,0 +1,12 @@
+#bankdef words
+{
+    bits = 16
+    addr = 0x100
+    outp = 0
+}
+
+start:
+#d16 0x1234, start, after
+#d8 0xab, 0xcd
+after:
+; = 0x1234_0100_0104_abcd
@@ -0,0 +1,11 @@
+#bankdef a
+{
+    #bits 3
+    #outp 0
+}
+
+#d 0b101 ; = 0b101
+#d 0b101 ; = 0b101
+#d 0b101 ; = 0b101
+#d 0b010, 0b011 ; = 0b010_011
+#d 0b110 ; = 0b110
@@ -0,0 +1,8 @@
+#bankdef a
+{
+    bits = 16
+    outp = 0
+    value_endian = 1 ; error: expected string
+}
+
+#d16 0x1234
@@ -0,0 +1,8 @@
+#bankdef a
+{
+    bits = 12
+    outp = 0
+    unit_endian = "little" ; error: `unit_endian` requires `bits` to be a multiple of 8
+}
+
+#d12 0x123
@@ -0,0 +1,8 @@
+#bankdef a
+{
+    bits = 16
+    outp = 0
+    value_endian = "middle" ; error: unknown endianness
+}
+
+#d32 0x11223344
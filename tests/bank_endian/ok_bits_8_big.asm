@@ -0,0 +1,16 @@
+#ruledef
+{
+    jmp {addr: u16} => 0xaa01 @ addr
+}
+
+#bankdef a
+{
+    bits = 8
+    outp = 0
+    value_endian = "big"
+}
+
+jmp 0x1234 ; = 0xaa011234
+#d16 0x5678 ; = 0x5678
+label:
+#d16 label ; = 0x0006
@@ -0,0 +1,16 @@
+#ruledef
+{
+    jmp {addr: u16} => 0xaa01 @ addr
+}
+
+#bankdef a
+{
+    bits = 8
+    outp = 0
+    value_endian = "little"
+}
+
+jmp 0x1234 ; = 0x341201aa
+#d16 0x5678 ; = 0x7856
+label:
+#d16 label ; = 0x0600
@@ -0,0 +1,9 @@
+#bankdef a
+{
+    bits = 16
+    outp = 0
+    value_endian = "little"
+    unit_endian = "little"
+}
+
+#d32 0x11223344 ; = 0x44332211
@@ -0,0 +1,17 @@
+#ruledef
+{
+    ld {x: u16} => 0xabcd @ x
+}
+
+#bankdef a
+{
+    bits = 16
+    outp = 0
+    value_endian = "little"
+    unit_endian = "little"
+}
+
+ld 0x1234 ; = 0x3412cdab
+#d16 0x5678 ; = 0x7856
+label:
+#d16 label ; = 0x0300
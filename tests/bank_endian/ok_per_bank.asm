@@ -0,0 +1,27 @@
+#ruledef
+{
+    jmp {addr: u16} => 0xaa01 @ addr
+}
+
+#bankdef big
+{
+    bits = 8
+    addr = 0x0
+    size = 0x4
+    outp = 0
+}
+
+#bankdef little
+{
+    bits = 8
+    addr = 0x0
+    size = 0x4
+    outp = 8 * 0x4
+    value_endian = "little"
+}
+
+#bank big
+jmp 0x1234
+#bank little
+jmp 0x1234
+; = 0xaa011234_341201aa
@@ -0,0 +1,9 @@
+#bankdef a
+{
+    bits = 16
+    outp = 0
+    value_endian = "big"
+    unit_endian = "little"
+}
+
+#d32 0x11223344 ; = 0x22114433
@@ -0,0 +1,8 @@
+#bankdef a
+{
+    bits = 16
+    outp = 0
+    unit_endian = "little"
+}
+
+#d16 0x1234, 0xabcd ; = 0x3412_cdab
@@ -0,0 +1,9 @@
+#bankdef a
+{
+    bits = 16
+    outp = 0
+    value_endian = "little"
+    unit_endian = "big"
+}
+
+#d32 0x11223344 ; = 0x33441122
@@ -0,0 +1,8 @@
+#bankdef a
+{
+    bits = 16
+    outp = 0
+    value_endian = "little"
+}
+
+#d32 0x11223344, 0xaabbccdd ; = 0x33441122_ccddaabb
@@ -0,0 +1,10 @@
+#bankdef a
+{
+    #bits 8
+    #addr 0x0
+    #size 0x4
+    #outp 0x0
+    #fill 0x55 ; error: expected string
+}
+
+#d8 0xff
@@ -0,0 +1,11 @@
+#bankdef a
+{
+    #bits 8
+    #addr 0x0
+    #size 0x4
+    #outp 0x0
+    #fill "aa55"
+}
+
+#d8 0xff
+; = 0xff55aa55
@@ -0,0 +1,13 @@
+#bankdef a
+{
+    #bits 8
+    #addr 0x10
+    #size 0x8
+    #outp 0x0
+    #fill "address"
+}
+
+#d8 0xff
+#res 2
+#d8 0xee
+; = 0xff1112ee14151617
@@ -0,0 +1,13 @@
+#bankdef a
+{
+    #bits 8
+    #addr 0x0
+    #outp 0x0
+    #fill "address"
+}
+
+#res 2
+#d8 0xff
+#align 32
+#d8 0xee
+; = 0x0001ff03ee
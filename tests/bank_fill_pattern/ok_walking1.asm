@@ -0,0 +1,11 @@
+#bankdef a
+{
+    #bits 4
+    #addr 0x0
+    #size 0x6
+    #outp 0x0
+    #fill "walking1"
+}
+
+#d4 0xf
+; = 0xf24812
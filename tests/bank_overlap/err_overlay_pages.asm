@@ -0,0 +1,2 @@
+#bankdef page0 { #addr 0x8000, #size 0x4, #outp 8 * 0x0 } ; error: overlaps / note:_:2: defined here
+#bankdef page1 { #addr 0x8000, #size 0x4, #outp 8 * 0x2 }
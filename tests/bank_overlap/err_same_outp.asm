@@ -0,0 +1,2 @@
+#bankdef a { #addr 0x0000, #size 0x10, #outp 8 * 0x20 } ; error: output of bank `a` overlaps with bank `b` / note:_:2: bank `b` defined here
+#bankdef b { #addr 0x8000, #size 0x10, #outp 8 * 0x20 }
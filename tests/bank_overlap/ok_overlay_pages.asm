@@ -0,0 +1,25 @@
+#bankdef page0
+{
+    #addr 0x8000
+    #size 0x4
+    #outp 8 * 0x0
+    #fill
+}
+
+#bankdef page1
+{
+    #addr 0x8000
+    #size 0x4
+    #outp 8 * 0x4
+    #fill
+}
+
+#bank page0
+a:
+#d8 0x11, a[7:0]
+
+#bank page1
+b:
+#d8 0x22, b[7:0]
+
+; = 0x11000000_22000000
@@ -0,0 +1,11 @@
+#bankdef a { #addr 0x0000, #size 0x10, #outp 8 * 0x00 }
+#bankdef vars { #addr 0x0000, #size 0x10 }
+#bankdef b { #addr 0x0000, #size 0x10, #outp 8 * 0x10 }
+
+#bank a
+#d8 0x11
+#bank vars
+#res 4
+#bank b
+#d8 0x22
+; = 0x11000000000000000000000000000000_22
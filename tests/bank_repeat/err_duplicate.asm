@@ -0,0 +1,7 @@
+#repeat i in 0..2
+{
+    #bankdef page ; error: duplicate bank `page` / note:_:3: first declared here
+    {
+        #addr 0x100 * i
+    }
+}
@@ -0,0 +1,10 @@
+#repeat i in 0..2
+{
+    #bankdef page{i}
+    {
+        #addr 0x100 * i
+        #outp 8 * 0x100 * i
+    }
+}
+
+#bank page2 ; error: unknown bank `page2`
@@ -0,0 +1,4 @@
+#bankdef page{i} ; error: unknown `#repeat` variable `i` in bank name
+{
+    #addr 0x0
+}
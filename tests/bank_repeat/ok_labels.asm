@@ -0,0 +1,23 @@
+#repeat i in 0..3
+{
+    #bankdef page{i}
+    {
+        #addr 0x100 * (i + 1)
+        #size 0x1
+        #outp 8 * i
+    }
+}
+
+#bank page2
+c:
+#d8 a >> 8
+#bank page0
+a:
+#d8 b >> 8
+#bank page1
+b:
+#d8 c >> 8
+
+; = 0x02
+; = 0x03
+; = 0x01
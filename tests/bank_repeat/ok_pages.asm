@@ -0,0 +1,20 @@
+#repeat i in 0..3
+{
+    #bankdef page{i}
+    {
+        #addr 0x8000 + i * 0x100
+        #size 0x2
+        #outp 8 * 0x2 * i
+    }
+}
+
+#bank page1
+#d16 $
+#bank page0
+#d16 $
+#bank page2
+#d16 $
+
+; = 0x8000
+; = 0x8100
+; = 0x8200
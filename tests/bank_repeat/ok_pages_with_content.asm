@@ -0,0 +1,16 @@
+#repeat i in 0..3
+{
+    #bankdef page{i}
+    {
+        #addr 0x4000 * i
+        #size 0x1
+        #outp 8 * i
+    }
+
+    #bank page{i}
+    #d8 ($ >> 14) + 0x10
+}
+
+; = 0x10
+; = 0x11
+; = 0x12
@@ -0,0 +1,12 @@
+#bankdef a
+{
+    #bits 16
+    #addr 0x0
+    #size 0x4
+    #outp 0x0
+    #fill
+}
+
+#d16 0x1234
+#align 24
+#d8 0x56 ; error: partial 16-bit unit / note: round_units
@@ -0,0 +1,10 @@
+#bankdef a
+{
+    #bits 16
+    #addr 0x0
+    #outp 0x0
+    #fill
+}
+
+#d16 0x1234
+#d8 0x56 ; error: partial 16-bit unit / note: round_units
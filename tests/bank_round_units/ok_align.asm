@@ -0,0 +1,14 @@
+#bankdef a
+{
+    #bits 16
+    #addr 0x0
+    #size 0x4
+    #outp 0x0
+    #fill "aa55"
+    #round_units
+}
+
+#d16 0x1234
+#align 24
+#d8 0x56
+; = 0x1234_0056_aaaa_5555
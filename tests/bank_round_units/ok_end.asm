@@ -0,0 +1,12 @@
+#bankdef a
+{
+    #bits 16
+    #addr 0x0
+    #outp 0x0
+    #fill
+    #round_units
+}
+
+#d16 0x1234
+#d8 0x56
+; = 0x1234_5600
@@ -0,0 +1,13 @@
+#bankdef a
+{
+    #bits 16
+    #addr 0x0
+    #size 0x4
+    #outp 0x0
+    #fill
+}
+
+#d16 0x1234
+#align 32
+#d8 0x56, 0x78
+; = 0x1234_0000_5678_0000
@@ -0,0 +1,6 @@
+#bankdef rom { #addr 0x0000, #size 0x10, #outp 0 }
+#bankdef ram { #addr 0x8000, #size 0x10 }
+#bankdef rom { #addr 0x4000, #size 0x10, #outp 8 * 0x10 } ; error: duplicate bank `rom` / note:_:1: first declared here
+
+#bank rom
+#d8 0xff
@@ -13,5 +13,5 @@
 
 loop
 loop
-loop ; error: out of range for bank / note:_:7: defined here
+loop ; error: out of range for bank / note:_:7: defined here / note: size of 8 units by 4 units / note:_:15: last output that fit
 loop
\ No newline at end of file
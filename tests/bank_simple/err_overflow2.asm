@@ -29,4 +29,4 @@ loop
 #bank a
 loop
 loop
-loop ; error: out of range for bank / note:_:7: defined here
\ No newline at end of file
+loop ; error: out of range for bank / note:_:7: defined here / note: size of 16 units by 4 units / note:_:31: last output that fit
\ No newline at end of file
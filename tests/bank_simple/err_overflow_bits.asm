@@ -0,0 +1,11 @@
+#bankdef a
+{
+    bits = 16
+    addr = 0x0
+    size = 2
+    outp = 0
+}
+
+#d16 0x1234
+#d8 0x56
+#d16 0x789a ; error: out of range for bank `a` / note:_:1: defined here / note: size of 2 units by 8 bits / note:_:10: last output that fit
@@ -0,0 +1,17 @@
+#ruledef
+{
+    nop => 0x00
+    ld {x: u8} => 0x11 @ x
+}
+
+#bankdef a
+{
+    addr = 0x0
+    size = 4
+    outp = 0
+}
+
+nop
+ld 0x12
+#d8 0x1
+#d8 0x2 ; error: out of range for bank `a` / note:_:7: defined here / note: size of 4 units by 1 unit / note:_:16: last output that fit
@@ -0,0 +1 @@
+#bankdef a { #addr 0x8000, #outp 0 }
@@ -0,0 +1,2 @@
+#bankdef a { #addr 0x0000, #outp 0 }
+#include "banks.asm" ; error:banks.asm:1: duplicate bank `a` / note:_:1: first declared here
@@ -0,0 +1 @@
+#d8 'ab' ; error: exactly one character
@@ -0,0 +1,2 @@
+#d8 'H', 'i', '!', '\n', '\0' ; = 0x4869210a00
+#d8 '0' + 5 ; = 0x35
@@ -0,0 +1,2 @@
+#commentstyle ";"
+#d8 0x12 /* a */ ; error: expected expression
@@ -0,0 +1,3 @@
+#d8 0x12
+/* a /* b */ c ; error: unterminated block comment
+#d8 0x34
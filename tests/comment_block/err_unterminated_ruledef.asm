@@ -0,0 +1,5 @@
+#ruledef
+{
+    /* ld => 0x55 ; error: unterminated block comment
+    nop => 0x00
+}
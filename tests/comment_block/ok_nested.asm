@@ -0,0 +1,9 @@
+#d8 0x12 /* a /* b */ c */ ; = 0x12
+/*
+#ruledef
+{
+    ld {x} => 0x55 @ x`8
+}
+/* nested */
+*/
+#d8 8 /* inline */ / 2 ; = 0x04
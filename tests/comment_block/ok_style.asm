@@ -0,0 +1,3 @@
+#commentstyle ";", "/*"
+#d8 0x12 /* a */ ; b
+; = 0x12
@@ -0,0 +1,4 @@
+#ruledef
+{
+    ld {x: u8} => 0x10 @ x
+}
@@ -0,0 +1,3 @@
+#include "slash.asm"
+#include "semicolon.asm"
+; error: semicolon.asm:2: expected line break
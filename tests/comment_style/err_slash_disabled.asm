@@ -0,0 +1 @@
+#d8 0x01 // comment ; error: expected expression
@@ -0,0 +1 @@
+#commentstyle "#" ; error: unknown comment style `#` / note: valid styles are
@@ -0,0 +1,5 @@
+#commentstyle ";", "//"
+#d8 0x01 // slash comment
+#d8 0x02 ; semicolon comment
+#d8 0x03 ;* block *; // mixed
+; = 0x010203
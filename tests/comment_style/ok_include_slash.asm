@@ -0,0 +1,4 @@
+#include "cpu.asm"
+#include "slash.asm"
+ld 0x04 ; back to `;` comments in this file
+; = 0x010203_1004
@@ -0,0 +1,9 @@
+#commentstyle ";", "//"
+#ruledef
+{
+    ld {x: u8} => 0x10 @ x // inside a ruledef
+}
+
+ld 0x12 // after an instruction
+ld 8 / 2 // division in an argument
+; = 0x1012_1004
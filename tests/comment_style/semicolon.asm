@@ -0,0 +1,2 @@
+#commentstyle "//"
+#d8 0x01 ; not a comment
@@ -0,0 +1,5 @@
+#commentstyle "//"
+
+// A file that only accepts `//` comments
+#d8 0x01, 0x02 // trailing comment
+#d8 0x03 / 1 // division still works
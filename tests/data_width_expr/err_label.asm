@@ -0,0 +1,2 @@
+label:
+#d(label) 1 ; error: unresolved symbol `label`
@@ -0,0 +1,2 @@
+W = 16
+#d(W) 1, 2 ; = 0x00010002
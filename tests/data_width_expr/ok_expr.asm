@@ -0,0 +1,3 @@
+W = 4
+#d(W * 2) 0x12, 0x34 ; = 0x1234
+#d (0x12 + 0x34)`16 ; = 0x0046
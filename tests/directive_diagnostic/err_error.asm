@@ -0,0 +1,3 @@
+#d8 0x12
+#error "unsupported configuration" ; error: unsupported configuration
+#d8 0x34
@@ -0,0 +1 @@
+#warning 0x1234 ; error: expected string
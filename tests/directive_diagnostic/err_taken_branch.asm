@@ -0,0 +1,8 @@
+VERSION = 1
+
+#if VERSION < 2
+{
+    #error "version too old" ; error: version too old
+}
+
+#d8 VERSION
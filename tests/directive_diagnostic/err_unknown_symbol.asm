@@ -0,0 +1 @@
+#error MESSAGE ; error: unknown symbol `MESSAGE`
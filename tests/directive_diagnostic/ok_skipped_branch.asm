@@ -0,0 +1,12 @@
+VERSION = 2
+
+#if VERSION < 2
+{
+    #error "version too old"
+}
+#elif VERSION > 2
+{
+    #warning "version untested"
+}
+
+#d8 VERSION ; = 0x02
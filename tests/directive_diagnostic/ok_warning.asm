@@ -0,0 +1,3 @@
+#d8 0x12 ; = 0x12
+#warning "check this table" ; warning: check this table
+#d8 0x34 ; = 0x34
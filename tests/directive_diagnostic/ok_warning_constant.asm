@@ -0,0 +1,3 @@
+MESSAGE = "table is unused"
+#warning MESSAGE ; warning: table is unused
+#d8 0 ; = 0x00
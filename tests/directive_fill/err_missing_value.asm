@@ -0,0 +1 @@
+#fill 4 ; error: expected `,`
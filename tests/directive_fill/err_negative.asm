@@ -0,0 +1 @@
+#fill 4, -1 ; error: fill value doesn't fit in the bank's 8-bit `addr_unit`
@@ -0,0 +1,7 @@
+#bankdef a
+{
+    #addr 0
+    #size 4
+}
+
+#fill 2, 0xff ; error: output to non-writable bank `a` / note:_:1: no `outp`
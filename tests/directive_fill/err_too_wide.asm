@@ -0,0 +1 @@
+#fill 4, 0x1ff ; error: fill value doesn't fit in the bank's 8-bit `addr_unit`
@@ -0,0 +1,9 @@
+#bankdef a
+{
+    #addr 0
+    #bits 16
+    #outp 0
+}
+
+#fill 2, 0x1234 ; = 0x1234_1234
+#d16 $ ; = 0x0002
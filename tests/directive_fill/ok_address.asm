@@ -0,0 +1,3 @@
+#fill 3, 0
+after:
+#d8 after ; = 0x000000_03
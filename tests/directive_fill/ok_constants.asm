@@ -0,0 +1,4 @@
+#fill COUNT, VALUE ; = 0x5555
+#d8 $ ; = 0x02
+COUNT = 2
+VALUE = 0x55
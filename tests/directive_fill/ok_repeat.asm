@@ -0,0 +1,6 @@
+#repeat i in 1..3
+{
+    #fill i, i
+}
+; = 0x01
+; = 0x0202
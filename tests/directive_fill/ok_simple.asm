@@ -0,0 +1,3 @@
+#d8 0x12 ; = 0x12
+#fill 4, 0xEA ; = 0xeaeaeaea
+#d8 0x34 ; = 0x34
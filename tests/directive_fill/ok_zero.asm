@@ -0,0 +1,2 @@
+#fill 0, 0xff
+#d8 0x12 ; = 0x12
@@ -0,0 +1 @@
+#xyzzy ; error: unknown directive `#xyzzy`
\ No newline at end of file
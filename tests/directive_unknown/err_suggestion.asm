@@ -0,0 +1 @@
+#bankdf test { #addr 0, #outp 0 } ; error: unknown directive `#bankdf`; did you mean `#bankdef`?
\ No newline at end of file
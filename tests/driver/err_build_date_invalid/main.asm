@@ -0,0 +1,4 @@
+#d builddate()
+
+; command: main.asm -o out.bin --build-date=yesterday
+; error: invalid argument for `--build-date`
@@ -0,0 +1,4 @@
+#d8 0x12
+
+; command: main.asm -f annotated,dec:1 -o out.txt
+; error: invalid format argument `annotated,dec
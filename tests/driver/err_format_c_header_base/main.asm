@@ -0,0 +1,2 @@
+; command: main.asm -f c-header,base:2 -o out.txt
+; error: invalid format argument `c-header,base
@@ -0,0 +1,2 @@
+; command: main.asm -f c-header,guard -o out.txt
+; error: missing value for format argument `c-header,guard`
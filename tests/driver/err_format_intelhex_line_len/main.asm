@@ -0,0 +1,4 @@
+#d8 0x01
+
+; command: main.asm -f intelhex,line_len:256 -o out.txt
+; error: invalid format argument `intelhex,line_len
@@ -0,0 +1,4 @@
+#d8 0x12
+
+; command: main.asm -f readmemh,addr_unit:0 -o out.txt
+; error: invalid format argument `readmemh,addr_unit
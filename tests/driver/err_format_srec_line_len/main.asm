@@ -0,0 +1,4 @@
+#d8 0x01
+
+; command: main.asm -f srec,line_len:251 -o out.txt
+; error: invalid format argument `srec,line_len
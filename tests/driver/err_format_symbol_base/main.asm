@@ -0,0 +1,2 @@
+; command: main.asm -f symbols,base:3 -o out.txt
+; error: invalid format argument `symbols,base
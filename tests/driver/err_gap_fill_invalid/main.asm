@@ -0,0 +1,2 @@
+; command: main.asm --gap-fill=0x100
+; error: invalid argument for `--gap-fill`
@@ -0,0 +1,4 @@
+#include "nest1.asm"
+
+; command: main.asm --max-include-depth=2
+; error: nest2.asm:1: include nesting too deep / note: maximum depth is 2, included through nest1.asm -> nest2.asm -> nest3.asm
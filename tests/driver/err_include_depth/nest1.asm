@@ -0,0 +1 @@
+#include "nest2.asm"
@@ -0,0 +1 @@
+#include "nest3.asm"
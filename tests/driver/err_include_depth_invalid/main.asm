@@ -0,0 +1,4 @@
+#d8 0x12
+
+; command: main.asm --max-include-depth=abc
+; error: invalid argument for `--max-include-depth`
@@ -0,0 +1,5 @@
+#d8 0x12
+label:
+
+; command: main.asm other.asm -o out.bin
+; error: other.asm:2: duplicate symbol `label` / note:main.asm:2: first declared here
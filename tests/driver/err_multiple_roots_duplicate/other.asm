@@ -0,0 +1,2 @@
+#d8 0x34
+label:
@@ -0,0 +1,4 @@
+#d8 0x12
+#warning "check this table" ; error: check this table
+
+; command: main.asm --warnings-as-errors -f hexstr -o out.txt
@@ -0,0 +1,10 @@
+#bankdef a
+{
+    #bits 12
+    #outp 0
+}
+
+#d12 0x414, 0x243, 0x444
+
+; command: main.asm -f binary -o out.bin
+; output: out.bin
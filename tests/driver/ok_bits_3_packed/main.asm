@@ -0,0 +1,12 @@
+#bankdef a
+{
+    #bits 3
+    #outp 0
+}
+
+#d 0b011
+#d 0b010
+#d 0b110
+
+; command: main.asm -o out.bin
+; output: out.bin
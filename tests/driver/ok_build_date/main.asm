@@ -0,0 +1,4 @@
+#d builddate()[31:0]
+
+; command: main.asm -o out.bin --build-date=1698898176
+; output: out.bin
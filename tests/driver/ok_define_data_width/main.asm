@@ -0,0 +1,7 @@
+WIDTH = {}
+
+table:
+#d(WIDTH) 0x01, 0x02, 0x03, 0x04
+
+; command: main.asm -fhexstr -o out.txt -dWIDTH=16
+; output: out.txt
@@ -0,0 +1,29 @@
+#subruledef reg
+{
+    {r: u4} => r
+    {r: u4} => (r + 1)`4
+}
+
+#ruledef test
+{
+    ld {x} =>
+    {
+        assert(x < 0x10)
+        0x11 @ x`8
+    }
+
+    ld {x} =>
+    {
+        assert(x < 0x80)
+        0x22 @ x`8
+    }
+
+    push {r: reg} => 0x3 @ r
+}
+
+ld 0x40
+ld 0x05
+push 0x2
+
+; command: main.asm --first-rule-wins -f hexstr -o out.txt
+; output: out.txt
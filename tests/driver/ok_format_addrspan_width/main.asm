@@ -0,0 +1,5 @@
+#d 0x12
+#d 0x34
+
+; command: main.asm -f addrspan,base:10,width:4 -o out.txt
+; output: out.txt
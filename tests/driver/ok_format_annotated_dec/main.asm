@@ -0,0 +1,12 @@
+#ruledef test
+{
+    ld {x: i8} => 0x55 @ x
+}
+
+#d8 -5
+#d8 0xfb
+#d16 -300
+ld -1
+
+; command: main.asm -f annotated,dec -o out.txt
+; output: out.txt
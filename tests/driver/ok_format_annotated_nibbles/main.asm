@@ -0,0 +1,5 @@
+#d4 0xa, 0xb
+#d8 0xcd
+
+; command: main.asm -f annotatedbin -o out.txt
+; output: out.txt
@@ -0,0 +1,16 @@
+#ruledef test
+{
+    halt => 0x55
+}
+
+start:
+halt
+loop:
+halt
+.inner:
+halt
+end:
+value = 0x1234
+
+; command: main.asm -f c-header -o out.txt
+; output: out.txt
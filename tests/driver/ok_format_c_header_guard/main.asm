@@ -0,0 +1,11 @@
+#ruledef test
+{
+    halt => 0x55
+}
+
+loop:
+halt
+end:
+
+; command: main.asm -f c-header,guard:LABELS_H --symbol-prefix=asm. -o out.txt
+; output: out.txt
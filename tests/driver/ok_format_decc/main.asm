@@ -0,0 +1,4 @@
+#d8 0x01, 0xff
+
+; command: main.asm -f decc -o out.txt
+; output: out.txt
@@ -0,0 +1,27 @@
+#subruledef reg
+{
+    a => 0x0
+    b => 0x1
+}
+
+#ruledef cpu
+{
+    lda {r: reg}, {value: u8} => 0x1 @ r`4 @ value
+    jmp {addr: u16} => 0x20 @ addr
+    hlt => 0xff
+}
+
+#bankdef rom
+{
+    #addr 0x8000
+    #outp 0
+}
+
+start:
+    lda a, 0x12
+.loop:
+    jmp .loop
+    hlt
+
+; command: main.asm -f decls-json -o out.json
+; output: out.json
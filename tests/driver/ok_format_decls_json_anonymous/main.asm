@@ -0,0 +1,15 @@
+#ruledef test
+{
+    nop => 0x00
+    jmp {addr: u8} => 0xee @ addr
+}
+
+start:
+-
+    jmp +
+    nop
++
+    jmp -
+
+; command: main.asm -f decls-json -o out.json
+; output: out.json
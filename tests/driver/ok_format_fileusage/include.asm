@@ -0,0 +1,3 @@
+ld 0x22
+ld 0x33
+#d8 0xcc
@@ -0,0 +1,11 @@
+#ruledef test
+{
+    ld {x: u8} => 0x55 @ x
+}
+
+ld 0x11
+#include "include.asm"
+#d 0xaabb
+
+; command: main.asm -f fileusage -o out.txt
+; output: out.txt
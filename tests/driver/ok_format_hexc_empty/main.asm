@@ -0,0 +1,2 @@
+; command: main.asm -f hexc -o out.txt
+; output: out.txt
@@ -0,0 +1,6 @@
+#d8 0x11, 0x22, 0x33
+#addr 0x18000
+#d8 0x44, 0x55
+
+; command: main.asm -f intelhex -o out.txt
+; output: out.txt
@@ -0,0 +1,14 @@
+#ruledef test
+{
+    halt => 0x55
+}
+
+halt
+halt
+#d "hello, world!"
+#d "hello, world!"
+#d "hello, world!"
+#d "hello, world!"
+
+; command: main.asm -f intelhex,line_len:16 -o out.txt
+; output: out.txt
\ No newline at end of file
@@ -0,0 +1,14 @@
+#ruledef
+{
+    halt => 0x55
+    ld {x: u16} => 0x11 @ x @ 0x2233
+}
+
+; comment only
+start:
+    halt
+    ld 0xabcd
+#d4 0xa, 0xb
+
+; command: main.asm -f listing,line_len:4 -o out.txt
+; output: out.txt
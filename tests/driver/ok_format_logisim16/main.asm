@@ -0,0 +1,4 @@
+#d16 0x1234, 0xabcd, 0xabcd
+
+; command: main.asm -f logisim16 -o out.txt
+; output: out.txt
@@ -0,0 +1,4 @@
+#d8 0x12, 0x00, 0x00, 0x00, 0x34, 0x56, 0x56
+
+; command: main.asm -f logisim8,rle -o out.txt
+; output: out.txt
@@ -0,0 +1,10 @@
+#bankdef test
+{
+    bits = 12
+    outp = 0
+}
+
+#d12 0xabc, 0x001, 0x001
+
+; command: main.asm -f logisim,rle -o out.txt
+; output: out.txt
@@ -0,0 +1,4 @@
+#d8 0x01, 0x02, 0x03, 0x03, 0x04, 0x05, 0x06
+
+; command: main.asm -f mif,addr_unit:8,line_len:4 -o out.txt
+; output: out.txt
@@ -0,0 +1,12 @@
+#bankdef rom
+{
+    bits = 16
+    addr = 0
+    size = 0x10
+    outp = 0
+}
+
+#d16 0x1234, 0xabcd, 0xabcd, 0xabcd, 0x0001, 0x0002
+
+; command: main.asm -f mif -o out.txt
+; output: out.txt
@@ -0,0 +1,16 @@
+#ruledef test
+{
+    halt => 0x55
+    jmp {addr: u8} => 0xee @ addr
+}
+
+start:
+    halt
+loop:
+    jmp loop
+.inner:
+    halt
+value = 0x1234
+
+; command: main.asm -f object-json -o out.txt
+; output: out.txt
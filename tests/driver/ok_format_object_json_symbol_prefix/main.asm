@@ -0,0 +1,15 @@
+#ruledef test
+{
+    halt => 0x55
+    jmp {addr: u8} => 0xee @ addr
+}
+
+start:
+    halt
+loop:
+    jmp loop
+.inner:
+    halt
+
+; command: main.asm -f object-json -o out.txt --symbol-prefix=mod1_
+; output: out.txt
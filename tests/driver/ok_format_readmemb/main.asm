@@ -0,0 +1,13 @@
+#bankdef test
+{
+    #addr 0x0
+    #bits 4
+    #outp 0
+}
+
+#d4 0x5, 0xa
+#addr 0x4
+#d4 0x0
+
+; command: main.asm -f readmemb -o out.txt
+; output: out.txt
@@ -0,0 +1,7 @@
+#d8 0x12, 0x34, 0x56
+#d16 0x0000
+#addr 0x8
+#d8 0xff
+
+; command: main.asm -f readmemh,addr_unit:12 -o out.txt
+; output: out.txt
@@ -0,0 +1,12 @@
+#ruledef test
+{
+    halt => 0x55
+}
+
+halt
+#d8 0x00, 0xab
+#addr 0x10
+#d8 0x12, 0x34
+
+; command: main.asm -f readmemh,line_len:4 -o out.txt
+; output: out.txt
@@ -0,0 +1,6 @@
+#d8 0x01, 0x02, 0x03
+#addr 0x10
+#d8 0x04, 0x05, 0x06, 0x07
+
+; command: main.asm -f srec,header:HDR,line_len:2 -o out.txt
+; output: out.txt
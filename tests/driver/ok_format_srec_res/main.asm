@@ -0,0 +1,6 @@
+#d8 0x11, 0x22
+#res 4
+#d8 0x33, 0x00, 0x44
+
+; command: main.asm -f srec -o out.txt
+; output: out.txt
@@ -0,0 +1,5 @@
+#addr 0xfffffe
+#d8 0xaa, 0xbb, 0xcc, 0xdd
+
+; command: main.asm -f srec -o out.txt
+; output: out.txt
@@ -0,0 +1,23 @@
+#ruledef test
+{
+    halt => 0x55
+}
+
+#bankdef a
+{
+    bits = 8
+    addr = 0xc000
+    outp = 0
+}
+
+start:
+halt
+loop:
+halt
+.inner:
+halt
+end:
+value = 0x1234
+
+; command: main.asm -f symbols -o out.txt
+; output: out.txt
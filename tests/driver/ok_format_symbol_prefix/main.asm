@@ -0,0 +1,17 @@
+#ruledef test
+{
+    halt => 0x55
+    jmp {addr: u8} => 0x6e @ addr
+}
+
+start:
+halt
+loop:
+halt
+.inner:
+jmp loop.inner
+jmp start
+
+; command: main.asm -f symbols -o out.txt --symbol-prefix=mod1_ -- -f binary -o out.bin
+; output: out.txt
+; output: out.bin
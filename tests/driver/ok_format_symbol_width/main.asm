@@ -0,0 +1,16 @@
+#ruledef test
+{
+    halt => 0x55
+}
+
+#d 0`80
+start:
+halt
+loop:
+halt
+.inner:
+halt
+value = -12
+
+; command: main.asm -f symbols,width:4 -o out.txt
+; output: out.txt
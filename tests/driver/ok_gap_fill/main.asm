@@ -0,0 +1,13 @@
+#bankdef a { addr = 0x00, size = 0x2, outp = 8 * 0x0, fill }
+#bankdef b { addr = 0x10, size = 0x4, outp = 8 * 0x4 }
+
+#bank a
+#d8 0x11
+
+#bank b
+#d8 0x22
+#res 1
+#d8 0x33
+
+; command: main.asm -f hexstr --gap-fill=0xff -o out.txt
+; output: out.txt
@@ -0,0 +1,11 @@
+#bankdef a { addr = 0x00, size = 0x2, outp = 8 * 0x0 }
+#bankdef b { addr = 0x10, size = 0x2, outp = 8 * 0x4 }
+
+#bank a
+#d8 0x11
+
+#bank b
+#d8 0x22, 0x33
+
+; command: main.asm -f hexstr -o out.txt
+; output: out.txt
@@ -0,0 +1,4 @@
+#include "nest1.asm"
+
+; command: main.asm --max-include-depth=3
+; output: main.bin
@@ -0,0 +1 @@
+#d8 0x12
@@ -0,0 +1,18 @@
+#bankdef main
+{
+    #addr 0x00
+    #size 0x10
+    #outp 0
+}
+
+#ruledef test
+{
+    jmp {addr: u8} => 0x11 @ addr
+    halt => 0x55
+}
+
+start:
+    jmp finish
+
+; command: main.asm other.asm -f hexstr -o out.txt
+; output: out.txt
@@ -0,0 +1,3 @@
+    halt
+finish:
+    jmp start
@@ -0,0 +1 @@
+#expect_digest "md5:d41d8cd98f00b204e9800998ecf8427e" ; error: unsupported digest algorithm `md5`
@@ -0,0 +1 @@
+#expect_digest "sha256:1234" ; error: invalid sha256 digest
@@ -0,0 +1,3 @@
+#expect_digest "sha256:3a103a4e5729ad68c02a678ae39accfbc0ae208096437401b7ceab63cca0622f"
+
+#d8 0x12, 0x34 ; = 0x1234
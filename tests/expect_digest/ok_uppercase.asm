@@ -0,0 +1,3 @@
+#d8 0x12, 0x34 ; = 0x1234
+
+#expect_digest "sha256:3A103A4E5729AD68C02A678AE39ACCFBC0AE208096437401B7CEAB63CCA0622F"
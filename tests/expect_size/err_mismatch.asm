@@ -0,0 +1,6 @@
+#ruledef
+{
+    jmp {addr: u16} => 0x10 @ addr
+}
+
+#expect_size jmp 0x10 == 2 ; error: instruction size mismatch / note: expected 2 bytes, got 3 bytes
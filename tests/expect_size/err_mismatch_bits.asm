@@ -0,0 +1,6 @@
+#ruledef
+{
+    halt => 0x5`4
+}
+
+#expect_size halt == 1 ; error: instruction size mismatch / note: expected 1 byte, got 4 bits
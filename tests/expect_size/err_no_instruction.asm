@@ -0,0 +1,6 @@
+#ruledef
+{
+    nop => 0x00
+}
+
+#expect_size == 2 ; error: expected instruction
@@ -0,0 +1,6 @@
+#ruledef
+{
+    nop => 0x00
+}
+
+#expect_size jmp 0x10 == 2 ; error: no match found for instruction
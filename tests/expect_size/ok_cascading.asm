@@ -0,0 +1,21 @@
+#ruledef
+{
+    jmp {addr} =>
+    {
+        assert(addr < 0x100)
+        0x10 @ addr`8
+    }
+
+    jmp {addr} =>
+    {
+        assert(addr >= 0x100)
+        0x11 @ addr`16
+    }
+}
+
+#expect_size jmp 0x10 == 2
+#expect_size jmp 0x1234 == 3
+#expect_size jmp label == 2
+
+label:
+jmp label ; = 0x1000
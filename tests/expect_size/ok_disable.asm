@@ -0,0 +1,12 @@
+#ruledef
+{
+    @short: ld {x: u8} => 0x01 @ x
+    @long: ld {x: u16} => 0x02 @ x
+}
+
+#expect_size ld 5 == 2
+#disable short
+#expect_size ld 5 == 3
+ld 5 ; = 0x020005
+#enable short
+#expect_size ld 5 == 2
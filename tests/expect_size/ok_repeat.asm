@@ -0,0 +1,11 @@
+#ruledef
+{
+    ld r{r: u2}, {x: u8} => 0b111111 @ r @ x
+}
+
+#repeat i in 0..4
+{
+    #expect_size ld r3, i == 2
+}
+
+ld r3, 0x12 ; = 0xff12
@@ -0,0 +1,12 @@
+#ruledef
+{
+    nop => 0x00
+    jmp {addr: u16} => 0x10 @ addr
+    ld {x: u8} => 0x20 @ x
+}
+
+#expect_size nop == 1
+#expect_size jmp 0x10 == 3
+#expect_size ld 0xff == 2
+
+nop ; = 0x00
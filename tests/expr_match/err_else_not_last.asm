@@ -0,0 +1,4 @@
+x = match 1 {
+    else => 0x10
+    1 => 0x20 ; error: `else` arm must be the last one
+}
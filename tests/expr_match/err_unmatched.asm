@@ -0,0 +1,9 @@
+#ruledef test
+{
+    ld {x} => match x {
+        0 => 0x10
+        1 => 0x20
+    }
+}
+
+ld 2 ; error: failed / note:_:3: within / error:_:3: value not covered by any `match` arm
@@ -0,0 +1,14 @@
+#ruledef test
+{
+    nop => 0x00
+    halt => 0xff
+    run {x} => match x {
+        0 => asm { nop }
+        1 => asm { halt }
+        else => asm { nop } @ asm { halt }
+    }
+}
+
+run 0 ; = 0x00
+run 1 ; = 0xff
+run 2 ; = 0x00ff
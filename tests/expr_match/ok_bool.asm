@@ -0,0 +1,10 @@
+#ruledef test
+{
+    ld {x} => match x > 3 {
+        true => 0xaa
+        false => 0xbb
+    }
+}
+
+ld 5 ; = 0xaa
+ld 2 ; = 0xbb
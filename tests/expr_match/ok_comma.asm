@@ -0,0 +1,8 @@
+#ruledef test
+{
+    ld {x} => 0x55 @ match x { 1 => 0xaa, 2 => 0xbb, else => 0xcc }
+}
+
+ld 1 ; = 0x55aa
+ld 2 ; = 0x55bb
+ld 3 ; = 0x55cc
@@ -0,0 +1,6 @@
+mode = 2
+x = match mode {
+    1 => 0x11
+    2 => 0x22
+}
+#d8 x ; = 0x22
@@ -0,0 +1,3 @@
+#fn match(x) => x + 1
+#d8 match(4) ; = 0x05
+#d8 match (4) ; = 0x05
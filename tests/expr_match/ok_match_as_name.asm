@@ -0,0 +1,2 @@
+match = 0x12
+#d8 match ; = 0x12
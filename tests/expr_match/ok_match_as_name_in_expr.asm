@@ -0,0 +1,4 @@
+match = 5
+#d8 match - 1 ; = 0x04
+#d8 -match + 6 ; = 0x01
+#d8 (match) ; = 0x05
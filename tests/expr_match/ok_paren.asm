@@ -0,0 +1,10 @@
+#ruledef test
+{
+    ld {x} => match (x) {
+        0 => 0x10
+        else => 0x20
+    }
+}
+
+ld 0 ; = 0x10
+ld 3 ; = 0x20
@@ -0,0 +1,12 @@
+#ruledef test
+{
+    ld {x} => match x {
+        0 => 0x10
+        1 => 0x2233
+        else => 0x44 @ x`8
+    }
+}
+
+ld 0 ; = 0x10
+ld 1 ; = 0x2233
+ld 7 ; = 0x4407
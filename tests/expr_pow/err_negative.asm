@@ -0,0 +1 @@
+#d8 2 ** -1 ; error: failed / error: negative exponent
@@ -0,0 +1,4 @@
+BITS = 4
+SIZE = 2 ** BITS
+
+#d8 SIZE, 2 ** 3 ** 2 / 0x100 ; = 0x1002
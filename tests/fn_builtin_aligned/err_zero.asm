@@ -0,0 +1 @@
+#d8 aligned(0) ? 1 : 0 ; error: failed / error: out of supported range
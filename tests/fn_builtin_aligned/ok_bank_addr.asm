@@ -0,0 +1,11 @@
+#bankdef a
+{
+    #bits 4
+    #addr 0x1
+    #outp 0
+}
+
+#assert aligned(4)
+#assert !aligned(8)
+#d4 0x1 ; = 0x1
+#assert aligned(8)
@@ -0,0 +1,10 @@
+#ruledef
+{
+    op3 {x: u3} => x
+    sync => asm { op3 0 } @ (aligned(8) ? 0b00 : 0b11)
+}
+
+op3 1 ; = 0b001
+op3 2 ; = 0b010
+sync  ; = 0b000_11
+#d8 0xff ; = 0xff
@@ -0,0 +1,9 @@
+#assert aligned(8)
+#d 0b101 ; = 0b101
+#assert !aligned(8)
+#d aligned(8) ? 0b111 : 0b000 ; = 0b000
+#d 0b00 ; = 0b00
+#assert aligned(8)
+#d aligned(8) ? 0x01 : 0x00 ; = 0x01
+#assert aligned(16)
+#assert !aligned(32)
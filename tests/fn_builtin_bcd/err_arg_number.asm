@@ -0,0 +1 @@
+#d bcd(12) ; error: failed / error: expected 2 arguments
@@ -0,0 +1 @@
+#d bcd(1, 0x4000000000000001) ; error: failed / error: out of supported range
@@ -0,0 +1 @@
+#d bcd(-1, 2) ; error: failed / error: must not be negative
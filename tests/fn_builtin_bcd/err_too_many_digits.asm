@@ -0,0 +1 @@
+#d bcd(123, 2) ; error: failed / error: too many decimal digits / note: digit count is 2, got 3
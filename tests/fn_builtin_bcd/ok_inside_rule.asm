@@ -0,0 +1,7 @@
+#ruledef
+{
+    time {h: u8}, {m: u8} => 0x7e @ bcd(h, 2) @ bcd(m, 2)
+}
+
+time 12, 34 ; = 0x7e1234
+time 9, 5 ; = 0x7e0905
@@ -0,0 +1,6 @@
+#d bcd(1234, 4) ; = 0x1234
+#d bcd(5, 2) ; = 0x05
+#d bcd(0, 1) ; = 0x0
+#d bcd(99, 2) ; = 0x99
+#d bcd(59, 3) ; = 0x059
+#d bcd(123456789, 10) ; = 0x0123456789
@@ -0,0 +1 @@
+#d bswap(0x123) ; error: failed / error: `bswap` must have a size multiple of 8 / note: size 12
@@ -0,0 +1 @@
+#d bswap(0x12, 0x34) ; error: failed / error: expected 1 argument
@@ -0,0 +1 @@
+#d bswap(0x1234 + 1) ; error: failed / error: definite size
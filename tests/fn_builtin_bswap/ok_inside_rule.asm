@@ -0,0 +1,9 @@
+#ruledef
+{
+    ld {addr: u16} => 0xaa @ bswap(addr)
+    ld32 {addr: u32} => 0xbb @ bswap(addr)
+}
+
+ld 0x1234 ; = 0xaa3412
+ld 0x12 ; = 0xaa1200
+ld32 0x12345678 ; = 0xbb78563412
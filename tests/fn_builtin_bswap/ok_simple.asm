@@ -0,0 +1,7 @@
+#d bswap(0x12) ; = 0x12
+#d bswap(0x1234) ; = 0x3412
+#d bswap(0x123456) ; = 0x563412
+#d bswap(0x12345678) ; = 0x78563412
+#d bswap(0x0012) ; = 0x1200
+#d bswap(bswap(0x1234)) ; = 0x1234
+#d bswap(-1`16) ; = 0xffff
@@ -0,0 +1 @@
+#d builddate(1) ; error: failed / error: expected 0 arguments
@@ -0,0 +1,8 @@
+#ruledef
+{
+    stamp => 0xaa @ builddate()[7:0]
+}
+
+BUILD = builddate()
+stamp ; = 0xaa00
+#d BUILD ; = 0x0000000000000000
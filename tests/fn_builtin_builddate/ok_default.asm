@@ -0,0 +1 @@
+#d builddate() ; = 0x0000000000000000
@@ -0,0 +1,2 @@
+#d builddate()[31:0] ; = 0x00000000
+#d le(builddate()[15:0]) ; = 0x0000
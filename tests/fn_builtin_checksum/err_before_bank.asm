@@ -0,0 +1,2 @@
+#bankdef a { addr = 0x10, size = 0x10, outp = 0 }
+#d8 checksum_excluding(0x0, 0x12, 0x10, 1) ; error: failed / error: before the start of the current bank
@@ -0,0 +1,4 @@
+start:
+#d8 0x01
+end:
+#d8 checksum_excluding(end, start, end, 1) ; error: failed / error: before start address
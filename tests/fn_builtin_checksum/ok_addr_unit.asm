@@ -0,0 +1,9 @@
+#bankdef words { bits = 16, addr = 0x0, size = 0x10, outp = 0 }
+
+start:
+#d16 0x0102
+chk:
+#d16 0x55 @ checksum_excluding(start, end, chk, 1)`8
+#d16 0x0304
+end:
+; = 0x0102_550a_0304
@@ -0,0 +1,14 @@
+#ruledef
+{
+    jmp {x: u8} => 0x11 @ x
+    jmp {x: u16} => 0x22 @ x
+}
+
+start:
+#d16 checksum_excluding(start, end, chk, 2)
+chk = start
+jmp far
+end:
+#addr 0x100
+far:
+; = 0x0023_220100
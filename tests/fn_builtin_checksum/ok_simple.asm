@@ -0,0 +1,7 @@
+start:
+#d8 0x01, 0x02
+chk:
+#d8 checksum_excluding(start, end, chk, 1)
+#d8 0x03, 0x04
+end:
+; = 0x01020a0304
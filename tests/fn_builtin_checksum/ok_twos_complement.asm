@@ -0,0 +1,12 @@
+#ruledef
+{
+    ld {x: u8} => 0x10 @ x
+}
+
+start:
+    ld 0x20
+chk:
+    #d8 -checksum_excluding(start, end, chk, 1)
+    ld end
+end:
+; = 0x1020_bb_1005
@@ -0,0 +1 @@
+#d hamming(-1, 4) ; error: failed / error: must not be negative
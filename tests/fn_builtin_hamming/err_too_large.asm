@@ -0,0 +1 @@
+#d hamming(0x1f, 4) ; error: failed / error: data width / note: got size 5
@@ -0,0 +1 @@
+#d hamming(0, 0) ; error: failed / error: out of supported range
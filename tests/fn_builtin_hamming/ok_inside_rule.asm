@@ -0,0 +1,8 @@
+#ruledef
+{
+    st {d: u4} => parity(d) @ hamming(d, 4) @ d
+}
+
+st 0b0000 ; = 0b0_000_0000
+st 0b1011 ; = 0b1_001_1011
+st 0b1000 ; = 0b1_111_1000
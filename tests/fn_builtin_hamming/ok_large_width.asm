@@ -0,0 +1 @@
+#d hamming(1, 1000000) ; = 0x00003
@@ -0,0 +1,8 @@
+#d 0b0 @ hamming(0b0000, 4) ; = 0x0
+#d 0b0 @ hamming(0b0001, 4) ; = 0x3
+#d 0b0 @ hamming(0b0100, 4) ; = 0x6
+#d 0b0 @ hamming(0b1000, 4) ; = 0x7
+#d 0b0 @ hamming(0b1011, 4) ; = 0x1
+#d hamming(0xff, 8) ; = 0x3
+#d hamming(0x01, 8) ; = 0x3
+#d hamming(0x80, 8) ; = 0xc
@@ -0,0 +1 @@
+#d8 here(1) ; error: failed / error: expected 0 arguments
@@ -0,0 +1,9 @@
+#bankdef a
+{
+    #bits 16
+    #addr 0x8000
+    #outp 0
+}
+
+#d16 here() ; = 0x8000
+#d16 here() ; = 0x8001
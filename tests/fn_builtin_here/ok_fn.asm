@@ -0,0 +1,8 @@
+#fn offset_from_here(target) => target - here()
+
+#d8 0xff                     ; = 0xff
+#d8 offset_from_here(0x10)   ; = 0x0f
+#d8 offset_from_here(label)  ; = 0x02
+#d8 0xff                     ; = 0xff
+label:
+#d8 here()                   ; = 0x04
@@ -0,0 +1,11 @@
+#fn rel(target) => (target - here() - 2)`8
+
+#ruledef
+{
+    jr {addr} => 0x18 @ rel(addr)
+}
+
+loop:
+jr loop ; = 0x18fe
+jr end  ; = 0x1800
+end:
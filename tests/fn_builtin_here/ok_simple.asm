@@ -0,0 +1,5 @@
+#d8 here() ; = 0x00
+#d8 here() ; = 0x01
+#res 2
+#d8 here() ; = 0x0000_04
+#assert here() == $
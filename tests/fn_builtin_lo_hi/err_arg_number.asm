@@ -0,0 +1 @@
+#d hi() ; error: failed / error: expected 1 to 2 arguments
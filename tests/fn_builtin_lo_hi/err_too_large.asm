@@ -0,0 +1 @@
+#d hi(0x12345) ; error: failed / error: doesn't fit / note: got size 17
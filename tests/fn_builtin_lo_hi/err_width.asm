@@ -0,0 +1 @@
+#d lo(0x12, 4) ; error: failed / error: at least 8 bits
@@ -0,0 +1,8 @@
+#ruledef
+{
+    lda {addr: u16} => 0xad @ lo(addr) @ hi(addr)
+}
+
+lda 0x1234 ; = 0xad3412
+lda label ; = 0xad0600
+label:
@@ -0,0 +1,2 @@
+#fn hi(x) => (x + 1)`16
+#d hi(0x1234) ; = 0x1235
@@ -0,0 +1,6 @@
+#ruledef
+{
+    ld {hi: u8}, {lo: u8} => hi @ lo
+}
+
+ld 0x12, 0x34 ; = 0x1234
@@ -0,0 +1,4 @@
+hi = 5
+lo = 0x34
+#d8 hi ; = 0x05
+#d8 lo ; = 0x34
@@ -0,0 +1,6 @@
+#d lo(0x1234) ; = 0x34
+#d hi(0x1234) ; = 0x12
+#d lo(0x12) ; = 0x12
+#d hi(0x12) ; = 0x00
+#d lo(-1) ; = 0xff
+#d hi(-2) ; = 0xff
@@ -0,0 +1,3 @@
+#d hi(0x123456, 24) ; = 0x12
+#d lo(0x123456, 24) ; = 0x56
+#d hi(0xab, 8) ; = 0xab
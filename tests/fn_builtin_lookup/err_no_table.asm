@@ -0,0 +1 @@
+#d8 lookup(0x10, 0) ; error: failed / error: no data table
@@ -0,0 +1,3 @@
+table:
+#d8 0x10, 0x20, 0x30
+#d8 lookup(table, 3) ; error: failed / error: index 3 is out of range for a table of 3 elements
@@ -0,0 +1,4 @@
+table:
+#d8 0x10, 0x20
+#d8 0x30
+#d8 lookup(table, 2) ; error: failed / error: out of range for a table of 2 elements
@@ -0,0 +1,11 @@
+#ruledef
+{
+    seg {x: u4} => 0xe0 @ lookup(segments, x)`8
+}
+
+seg 0
+seg 9
+seg 0xf
+segments:
+#d8 0x3f, 0x06, 0x5b, 0x4f, 0x66, 0x6d, 0x7d, 0x07, 0x7f, 0x6f, 0x77, 0x7c, 0x39, 0x5e, 0x79, 0x71
+; = 0xe03f_e06f_e071_3f065b4f666d7d077f6f777c395e7971
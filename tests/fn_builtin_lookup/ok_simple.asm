@@ -0,0 +1,4 @@
+table:
+#d8 0x10, 0x20, 0x30
+#d8 lookup(table, 2) ; = 0x102030_30
+#d8 lookup(table + 1, 0) ; = 0x20
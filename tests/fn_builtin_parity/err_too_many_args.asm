@@ -0,0 +1 @@
+#d parity(0b1, 0b1) ; error: failed / error: expected 1 argument
@@ -0,0 +1 @@
+#d parity(3) ; error: failed / error: definite size
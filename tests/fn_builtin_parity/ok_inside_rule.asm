@@ -0,0 +1,9 @@
+#ruledef
+{
+    st {d: u7} => parity(d) @ d
+}
+
+st 0x00 ; = 0x00
+st 0x01 ; = 0x81
+st 0x03 ; = 0x03
+st 0x7f ; = 0xff
@@ -0,0 +1,6 @@
+#d 0b000 @ parity(0b1011) ; = 0b0001
+#d 0b000 @ parity(0b1001) ; = 0b0000
+#d 0b000 @ parity(0b0)    ; = 0b0000
+#d 0b000 @ parity(0x00ff) ; = 0b0000
+#d 0b000 @ parity(0x0100) ; = 0b0001
+#d 0b000 @ parity(-1`3)   ; = 0b0001
@@ -1,8 +1,8 @@
-x = y ; error: unknown symbol `y`
+x = y ; error: symbol `y` not defined (only in excluded `#if` branch) / note:_:5: excluded definition here
 
 #if false
 {
     y = 0x12
 }
 
-#d x
\ No newline at end of file
+#d x
@@ -0,0 +1,16 @@
+#ruledef
+{
+    jmp {addr: u8} => 0xee @ addr
+}
+
+#if 1 > 2
+{
+    target:
+    #d 0x00
+}
+#else
+{
+    #d 0xff
+}
+
+jmp target ; error: failed / note:_:3: within / error: symbol `target` not defined (only in excluded `#if` branch) / note:_:8: excluded definition here
@@ -0,0 +1,12 @@
+#ruledef
+{
+    jmp {addr: u8} => 0xee @ addr
+}
+
+main:
+#if false
+{
+    .skip:
+}
+
+jmp .skip ; error: failed / note:_:3: within / error: symbol `.skip` not defined (only in excluded `#if` branch) / note:_:9: excluded definition here
@@ -0,0 +1,12 @@
+#ruledef
+{
+    nop => 0x00
+}
+
+#if later > 1 ; error: unresolved condition / error: unresolved symbol `later`
+{
+    nop
+}
+
+nop
+later:
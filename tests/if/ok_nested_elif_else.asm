@@ -0,0 +1,26 @@
+A = 2
+B = 1
+
+#if A == 1
+{
+    #d 0x11
+}
+#elif A == 2
+{
+    #if B == 0
+    {
+        #d 0x20
+    }
+    #elif B == 1
+    {
+        #d 0x21 ; = 0x21
+    }
+    #else
+    {
+        #d 0x22
+    }
+}
+#else
+{
+    #d 0x33
+}
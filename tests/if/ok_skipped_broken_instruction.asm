@@ -0,0 +1,17 @@
+#ruledef
+{
+    nop => 0x00
+    ld {x: u8} => 0x11 @ x
+}
+
+#if false
+{
+    ld 0x1234
+    bogus_mnemonic 1, 2, 3
+}
+#elif 1 == 1
+{
+    nop ; = 0x00
+}
+
+#d 0xff ; = 0xff
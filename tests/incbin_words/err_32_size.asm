@@ -0,0 +1 @@
+#d incbin32le("data4.bin", 0, 2) ; error: failed / error: `incbin32le` data size is not a multiple of 4 bytes (got 2)
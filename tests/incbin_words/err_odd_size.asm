@@ -0,0 +1 @@
+#d incbin16be("data3.bin") ; error: failed / error: `incbin16be` data size is not a multiple of 2 bytes (got 3)
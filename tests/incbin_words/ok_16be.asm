@@ -0,0 +1 @@
+#d incbin16be("data4.bin") ; = 0x42414443
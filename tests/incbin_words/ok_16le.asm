@@ -0,0 +1 @@
+#d incbin16le("data4.bin") ; = 0x41424344
@@ -0,0 +1 @@
+#d incbin32be("data8.bin") ; = 0x4443424148474645
@@ -0,0 +1 @@
+#d incbin32le("data8.bin") ; = 0x4142434445464748
@@ -0,0 +1,4 @@
+#d incbin16be("data4.bin") ; = 0x42414443
+#d incbin16le("data4.bin") ; = 0x41424344
+#d incbin32be("data4.bin") ; = 0x44434241
+#d incbin32le("data4.bin") ; = 0x41424344
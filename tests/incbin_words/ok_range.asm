@@ -0,0 +1 @@
+#d incbin16be("data8.bin", 2, 4) ; = 0x44434645
@@ -0,0 +1,3 @@
+#bankdef a { #addr 0x8000, #outp 0 }
+#d8 0
+#patch 0x7fff, 0xff`8 ; error: address was not emitted
@@ -0,0 +1,3 @@
+#d8 0, 0
+#patch 0x0, 0x12`8
+#patch 0x0, 0x1334`16 ; error: conflicts with an earlier patch / note:_:2: earlier patch here
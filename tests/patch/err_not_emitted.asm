@@ -0,0 +1,2 @@
+#d8 0, 0
+#patch 0x1, 0x1234`16 ; error: address was not emitted
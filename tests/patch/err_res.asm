@@ -0,0 +1,4 @@
+#d8 0
+#res 1
+#d8 0
+#patch 0x1, 0xff`8 ; error: address was not emitted
@@ -0,0 +1,2 @@
+#d8 0
+#patch 0x0, 0xff + 1 ; error: definite size
@@ -0,0 +1,12 @@
+#bankdef a { #addr 0x8000, #size 0x4, #outp 8 * 0x0 }
+#bankdef b { #addr 0x8000, #size 0x4, #outp 8 * 0x4 }
+
+#bank a
+#d8 1, 2, 3, 4
+#patch 0x8002, 0xaa`8
+
+#bank b
+#d8 5, 6, 7, 8
+#patch 0x8000, 0xbbcc`16
+
+; = 0x0102aa04_bbcc0708
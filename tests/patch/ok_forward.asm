@@ -0,0 +1,3 @@
+#patch 0x1, 0xff`8
+#patch 0x2, 0xee`8
+#d8 0, 0, 0, 0 ; = 0x00ffee00
@@ -0,0 +1,19 @@
+#ruledef
+{
+    nop => 0x00
+    ld {x: u8} => 0x11 @ x
+}
+
+header:
+#d8 0xaa
+checksum:
+#d16 0x0000 ; placeholder
+body:
+ld 0x12
+ld 0x34
+nop
+body_end:
+
+#patch checksum, (body_end - body)`16
+
+; = 0xaa_0005_1112_1134_00
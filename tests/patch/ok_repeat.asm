@@ -0,0 +1,6 @@
+#d8 0, 0, 0, 0
+#repeat i in 0..4
+{
+    #patch i, (i * 0x11)`8
+}
+; = 0x00112233
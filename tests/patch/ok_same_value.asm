@@ -0,0 +1,4 @@
+#d8 0, 0
+#patch 0x0, 0x12`8
+#patch 0x0, 0x1234`16
+; = 0x1234
@@ -0,0 +1,8 @@
+#region data
+{
+    #d8 0x01
+}
+#region data ; error: duplicate symbol `data` / note:_:1: first declared here
+{
+    #d8 0x02
+}
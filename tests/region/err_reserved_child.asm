@@ -0,0 +1,4 @@
+#region data ; error: duplicate symbol `len` / note:_:3: first declared here
+{
+.len:
+}
@@ -0,0 +1,6 @@
+#region data
+{
+    #d8 0x01, 0x02, 0x03
+csum:
+    #d8 checksum_excluding(data.start, data.end, csum, 1) ; = 0x010203_06
+}
@@ -0,0 +1,6 @@
+#d8 code.len, code.start ; = 0x04_02
+
+#region code
+{
+    #d16 0x1234, 0x5678 ; = 0x1234_5678
+}
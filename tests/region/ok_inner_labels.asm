@@ -0,0 +1,18 @@
+#ruledef
+{
+    jmp {x: u8} => 0xee @ x
+}
+
+#region code
+{
+main:
+    jmp .loop ; = 0xee02
+.loop:
+    jmp main.loop ; = 0xee02
+other:
+    jmp .loop ; = 0xee06
+.loop:
+}
+
+#d8 code.end, code.len ; = 0x06_06
+#d8 main, other ; = 0x00_04
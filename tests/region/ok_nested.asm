@@ -0,0 +1,10 @@
+#region outer
+{
+    #d8 0x01
+    #region inner
+    {
+        #d8 0x02, 0x03
+    }
+}
+
+#d8 outer.len, inner.start, inner.len ; = 0x010203_03_01_02
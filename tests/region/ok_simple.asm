@@ -0,0 +1,7 @@
+#region header
+{
+    #d8 0xaa, 0xbb, 0xcc
+}
+
+#d8 header.len ; = 0xaabbcc_03
+#d8 header.start, header.end, header ; = 0x00_03_00
@@ -0,0 +1,2 @@
+table:
+#reltable16 table ; error: expected `,`
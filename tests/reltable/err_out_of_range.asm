@@ -0,0 +1,2 @@
+table:
+#reltable8 table, table + 0x100 ; error: value out of range for directive / note: data directive has size 8, got size 9
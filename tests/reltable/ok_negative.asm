@@ -0,0 +1,11 @@
+#ruledef
+{
+    nop => 0x00
+}
+
+a:
+nop ; = 0x00
+nop ; = 0x00
+table:
+#reltable8 table, a, table, b ; = 0xfe_00_03
+b:
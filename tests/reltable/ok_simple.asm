@@ -0,0 +1,13 @@
+#ruledef
+{
+    nop => 0x00
+}
+
+table:
+#reltable16 table, a, b, c ; = 0x0006_0007_0009
+a:
+nop ; = 0x00
+b:
+nop ; = 0x00
+nop ; = 0x00
+c:
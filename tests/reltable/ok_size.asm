@@ -0,0 +1,2 @@
+table:
+#reltable16 table, table + 0xffff ; = 0xffff
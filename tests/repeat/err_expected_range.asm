@@ -0,0 +1,4 @@
+#repeat i in 10 ; error: expected `..`
+{
+    #d8 i
+}
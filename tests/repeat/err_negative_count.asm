@@ -0,0 +1,4 @@
+#repeat 2 - 3 ; error: `#repeat` count cannot be negative
+{
+    #d8 0
+}
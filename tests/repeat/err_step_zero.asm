@@ -0,0 +1,4 @@
+#repeat i in 0..10 step 0 ; error: `#repeat` step cannot be zero
+{
+    #d8 i
+}
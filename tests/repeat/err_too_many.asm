@@ -0,0 +1,4 @@
+#repeat 0x1_0000_0000 ; error: `#repeat` of 4294967296 iterations exceeds the limit of 1048576
+{
+    #d8 0
+}
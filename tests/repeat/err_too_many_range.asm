@@ -0,0 +1,4 @@
+#repeat i in -0x8000_0000..0x8000_0000 step 2 ; error: `#repeat` of 2147483648 iterations exceeds the limit of 1048576
+{
+    #d8 i
+}
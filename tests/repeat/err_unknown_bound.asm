@@ -0,0 +1,4 @@
+#repeat i in 0..COUNT ; error: unresolved `#repeat` range / error: unknown symbol `COUNT`
+{
+    #d8 i
+}
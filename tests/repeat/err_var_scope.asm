@@ -0,0 +1,6 @@
+#repeat i in 0..2
+{
+    #d8 i
+}
+
+#d8 i ; error: failed / error: unknown symbol `i`
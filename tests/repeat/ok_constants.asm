@@ -0,0 +1,10 @@
+START = 2
+END = START + 3
+
+#repeat i in START..END
+{
+    #d8 i
+}
+; = 0x02
+; = 0x03
+; = 0x04
@@ -0,0 +1,7 @@
+#repeat 3
+{
+    #d8 0xab
+}
+; = 0xab
+; = 0xab
+; = 0xab
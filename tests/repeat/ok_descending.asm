@@ -0,0 +1,8 @@
+#repeat i in 10..0 step -3
+{
+    #d8 i
+}
+; = 0x0a
+; = 0x07
+; = 0x04
+; = 0x01
@@ -0,0 +1,7 @@
+#repeat i in 9..0 step -3
+{
+    #d8 i
+}
+; = 0x09
+; = 0x06
+; = 0x03
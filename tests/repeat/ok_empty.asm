@@ -0,0 +1,13 @@
+#d8 0xaa ; = 0xaa
+
+#repeat i in 5..5
+{
+    #d8 i
+}
+
+#repeat i in 0..5 step -1
+{
+    #d8 i
+}
+
+#d8 0xbb ; = 0xbb
@@ -0,0 +1,13 @@
+#repeat i in 0..2
+{
+    #repeat j in 0..3
+    {
+        #d8 (i << 4) | j
+    }
+}
+; = 0x00
+; = 0x01
+; = 0x02
+; = 0x10
+; = 0x11
+; = 0x12
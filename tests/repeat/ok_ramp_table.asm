@@ -0,0 +1,12 @@
+#repeat i in 0..8
+{
+    #d8 i * 2
+}
+; = 0x00
+; = 0x02
+; = 0x04
+; = 0x06
+; = 0x08
+; = 0x0a
+; = 0x0c
+; = 0x0e
@@ -0,0 +1,12 @@
+#ruledef
+{
+    ld {x: u8} => 0x55 @ x
+}
+
+#repeat i in 1..4
+{
+    ld i * 0x10
+}
+; = 0x5510
+; = 0x5520
+; = 0x5530
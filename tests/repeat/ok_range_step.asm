@@ -0,0 +1,9 @@
+#repeat i in 0..10 step 2
+{
+    #d8 i
+}
+; = 0x00
+; = 0x02
+; = 0x04
+; = 0x06
+; = 0x08
@@ -0,0 +1,4 @@
+#ruledef
+{
+    ld {x}?, a => 0x12 @ x`8 ; error: optional parameter must be at the end of the pattern
+}
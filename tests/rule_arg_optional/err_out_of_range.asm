@@ -0,0 +1,6 @@
+#ruledef
+{
+    ret {n: u8}? => 0xc9 @ n
+}
+
+ret 0x100 ; error: failed / note:_:3: within / error: out of range
@@ -0,0 +1,9 @@
+#subruledef reg
+{
+    a => 0x0
+}
+
+#ruledef
+{
+    ld {r: reg}? => 0x12 @ r`8 ; error: optional parameter cannot be of a ruledef type
+}
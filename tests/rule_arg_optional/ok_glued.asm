@@ -0,0 +1,7 @@
+#ruledef
+{
+    inc r{n: u2}? => 0b111111 @ n
+}
+
+inc r  ; = 0xfc
+inc r3 ; = 0xff
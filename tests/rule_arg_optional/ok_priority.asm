@@ -0,0 +1,8 @@
+#ruledef
+{
+    ret => 0xc9
+    ret {n: u8}? => 0xc2 @ n
+}
+
+ret   ; = 0xc9
+ret 4 ; = 0xc204
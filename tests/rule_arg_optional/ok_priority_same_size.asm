@@ -0,0 +1,8 @@
+#ruledef
+{
+    ret {n: u8}? => 0xc2 @ n
+    ret => 0xc900
+}
+
+ret   ; = 0xc900
+ret 0 ; = 0xc200
@@ -0,0 +1,7 @@
+#ruledef
+{
+    ret {n: u8}? => 0xc9 @ n
+}
+
+ret   ; = 0xc900
+ret 4 ; = 0xc904
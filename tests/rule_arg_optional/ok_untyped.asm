@@ -0,0 +1,8 @@
+#ruledef
+{
+    ret {n}? => 0xc9 @ n`8
+}
+
+ret          ; = 0xc900
+ret 1 + 2    ; = 0xc903
+ret (0x10)   ; = 0xc910
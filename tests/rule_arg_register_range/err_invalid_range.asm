@@ -0,0 +1,4 @@
+#ruledef
+{
+    mov {r: r7..x0} => 0b11000 @ r ; error: invalid register range
+}
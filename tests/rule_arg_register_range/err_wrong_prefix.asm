@@ -0,0 +1,6 @@
+#ruledef
+{
+    mov {r: r0..r7} => 0b11000 @ r
+}
+
+mov x3 ; error: no match found for instruction
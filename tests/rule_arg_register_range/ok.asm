@@ -0,0 +1,8 @@
+#ruledef
+{
+    mov {r: r0..r7} => 0b11000 @ r
+}
+
+mov r0 ; = 0b11000_000
+mov r3 ; = 0b11000_011
+mov R7 ; = 0b11000_111
@@ -0,0 +1,7 @@
+#ruledef
+{
+    ld {dst: x8..x15}, {src: x8..x15} => 0x4 @ dst @ src
+}
+
+ld x8, x15 ; = 0x4_8_f
+ld x10, x9 ; = 0x4_a_9
@@ -0,0 +1,8 @@
+#ruledef
+{
+    add {r: r0..r3}, {imm: u8} => 0b111111 @ r @ imm
+    add {val: u8} => 0xaa @ val
+}
+
+add r2, 0x12 ; = 0xfe12
+add 0x34     ; = 0xaa34
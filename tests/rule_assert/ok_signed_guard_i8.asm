@@ -0,0 +1,18 @@
+#ruledef test
+{
+    ld {x: i8} =>
+    {
+        assert(x < 0)
+        0x11 @ x
+    }
+
+    ld {x: i8} =>
+    {
+        assert(x >= 0)
+        0x22 @ x
+    }
+}
+
+ld -1 ; = 0x11ff
+ld 0xff ; = 0x22ff
+ld 0 ; = 0x2200
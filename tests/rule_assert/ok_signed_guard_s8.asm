@@ -0,0 +1,19 @@
+#ruledef test
+{
+    ld {x: s8} =>
+    {
+        assert(x < 0)
+        0x11 @ x
+    }
+
+    ld {x: s8} =>
+    {
+        assert(x >= 0)
+        0x22 @ x
+    }
+}
+
+ld -5 ; = 0x11fb
+ld 5 ; = 0x2205
+ld -128 ; = 0x1180
+ld 127 ; = 0x227f
@@ -0,0 +1,16 @@
+#ruledef test
+{
+    ld {x: s8} =>
+    {
+        assert(x`8 < 0)
+        0x11 @ x
+    }
+
+    ld {x: s8} =>
+    {
+        assert(x`8 >= 0)
+        0x22 @ x
+    }
+}
+
+ld -1 ; = 0x22ff
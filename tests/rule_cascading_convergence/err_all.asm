@@ -16,4 +16,4 @@
     ld label ; error: converge
     ld label ; error: converge
     ld label ; error: converge
-label: ; error: converge
\ No newline at end of file
+label: ; error: converge / error: resolution did not converge / note: `label` changed from 0x9 to 0x6
\ No newline at end of file
@@ -0,0 +1,14 @@
+#ruledef test
+{
+    jmp {x: u4} => 0x1 @ x
+    jmp {x: u8} => 0x22 @ x
+}
+
+; The size of the first `jmp` moves `a`, and `b` depends
+; on the sizes of every `jmp`, including the one to `a`
+    jmp b ; = 0x2212
+    #res 13 ; = 0x00000000_00000000_00000000_00
+a:
+    jmp a ; = 0x1f
+    jmp b ; = 0x2212
+b:
@@ -0,0 +1,14 @@
+#ruledef cpu1
+{
+    @op: nop => 0x11
+    halt => 0x33
+}
+
+#ruledef cpu2
+{
+    @op: nop => 0x22
+}
+
+#disable op
+halt
+nop ; error: no match
@@ -0,0 +1,5 @@
+#ruledef
+{
+    @op: nop => 0x00
+    @op: halt => 0xff ; error: duplicate rule name `op` / note:_:3: first declared here
+}
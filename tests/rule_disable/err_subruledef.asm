@@ -0,0 +1,16 @@
+#subruledef reg
+{
+    @ra: a => 0x1
+    b => 0x2
+}
+
+#ruledef
+{
+    ld {r: reg} => 0x5 @ r`4
+}
+
+ld a
+ld b
+#disable ra
+ld b
+ld a ; error: no match
@@ -0,0 +1,6 @@
+#ruledef
+{
+    @short: ld {x: u8} => 0x11 @ x
+}
+
+#disable long ; error: unknown rule `long`
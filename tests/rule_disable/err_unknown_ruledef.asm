@@ -0,0 +1,6 @@
+#ruledef cpu
+{
+    @short: ld {x: u8} => 0x11 @ x
+}
+
+#disable gpu.short ; error: unknown
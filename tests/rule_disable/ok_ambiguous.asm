@@ -0,0 +1,11 @@
+#ruledef
+{
+    @a: nop => 0x00
+    @b: nop => 0xff
+}
+
+#disable a
+nop ; = 0xff
+#enable a
+#disable b
+nop ; = 0x00
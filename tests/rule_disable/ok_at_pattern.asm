@@ -0,0 +1,10 @@
+#ruledef
+{
+    @x => 0x11
+    @ld {x: u8} => 0x22 @ x
+    @store: @st {x: u8} => 0x33 @ x
+}
+
+@x ; = 0x11
+@ld 0x34 ; = 0x2234
+@st 0x56 ; = 0x3356
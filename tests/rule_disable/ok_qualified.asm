@@ -0,0 +1,12 @@
+#ruledef cpu1
+{
+    @op: nop => 0x11
+}
+
+#ruledef cpu2
+{
+    @op: nop => 0x22
+}
+
+#disable cpu1.op
+nop ; = 0x22
@@ -0,0 +1,11 @@
+#ruledef
+{
+    @short: ld {x: u8} => 0x11 @ x
+    @long: ld {x: u16} => 0x22 @ x
+}
+
+ld 0x12 ; = 0x1112
+#disable short
+ld 0x12 ; = 0x220012
+#enable short
+ld 0x12 ; = 0x1112
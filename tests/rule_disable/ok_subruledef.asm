@@ -0,0 +1,16 @@
+#subruledef reg
+{
+    @ra: a => 0x1
+    b => 0x2
+}
+
+#ruledef
+{
+    ld {r: reg} => 0x5 @ r`4
+}
+
+ld a ; = 0x51
+#disable ra
+ld b ; = 0x52
+#enable ra
+ld a ; = 0x51
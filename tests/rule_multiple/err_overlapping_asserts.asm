@@ -0,0 +1,17 @@
+#ruledef test
+{
+    ld {x} =>
+    {
+        assert(x < 0x10)
+        0x11 @ x`8
+    }
+
+    ld {x} =>
+    {
+        assert(x < 0x80)
+        0x22 @ x`8
+    }
+}
+
+ld 0x40
+ld 0x05 ; error: multiple / note:_:3: match / note:_:9: match
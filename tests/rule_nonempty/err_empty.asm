@@ -0,0 +1,8 @@
+#ruledef(nonempty) cpu
+{
+    nop => 0`0 ; note:_:3: `cpu` is declared `nonempty`, but this rule emitted zero bits
+    ld {x: u8} => 0x55 @ x
+}
+
+ld 0x11
+nop ; error: instruction produced no output
@@ -0,0 +1,7 @@
+#ruledef(nonempty) cpu
+{
+    ld {x: u8} => 0x55 @ x
+    emit {x} => x`0 ; note:_:4: `cpu` is declared `nonempty`, but this rule emitted zero bits
+}
+
+emit 0x22 ; error: instruction produced no output
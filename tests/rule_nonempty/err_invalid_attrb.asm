@@ -0,0 +1,4 @@
+#ruledef(nonzero) ; error: invalid attribute `nonzero`
+{
+    nop => 0x00
+}
@@ -0,0 +1,4 @@
+#subruledef(nonempty) reg ; error: invalid attribute `nonempty`
+{
+    a => 0xaa
+}
@@ -0,0 +1,8 @@
+#ruledef(nonempty)
+{
+    nop => 0x00
+    ld {x: u8} => 0x55 @ x
+}
+
+ld 0x11 ; = 0x5511
+nop     ; = 0x00
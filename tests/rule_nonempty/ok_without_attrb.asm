@@ -0,0 +1,9 @@
+#ruledef
+{
+    nop => 0`0
+    ld {x: u8} => 0x55 @ x
+}
+
+ld 0x11 ; = 0x5511
+nop     ; = 0x
+ld 0x22 ; = 0x5522
@@ -0,0 +1 @@
+#d ascii("ok 😀") ; error: failed / error: character `😀` (U+1F600) is not in ASCII
@@ -0,0 +1 @@
+#d ascii("àB") ; error: failed / error: character `à` (U+00E0) is not in ASCII
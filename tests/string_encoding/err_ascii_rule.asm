@@ -0,0 +1,6 @@
+#ruledef
+{
+    msg {s: i64} => 0x02 @ ascii("é") @ 0x00 ; error: character `é` (U+00E9) is not in ASCII
+}
+
+msg 0 ; error: failed to resolve instruction / note:_:3: within
@@ -1,6 +1,7 @@
 #d "abc" ; = 0x61_62_63
 #d utf8("abc") ; = 0x61_62_63
 #d ascii("abc") ; = 0x61_62_63
+#d ascii("\0\x7f~") ; = 0x00_7f_7e
 #d utf16be("abc") ; = 0x0061_0062_0063
 #d utf16le("abc") ; = 0x6100_6200_6300
 #d utf32be("abc") ; = 0x00000061_00000062_00000063
@@ -8,7 +9,6 @@
 
 #d "àÿĀ" ; = 0xc3a0_c3bf_c480
 #d utf8("àÿĀ") ; = 0xc3a0_c3bf_c480
-#d ascii("àÿĀ") ; = 0xe0_ff_00
 #d utf16be("àÿĀ") ; = 0x00e0_00ff_0100
 #d utf16le("àÿĀ") ; = 0xe000_ff00_0001
 #d utf32be("àÿĀ") ; = 0x000000e0_000000ff_00000100
@@ -16,7 +16,6 @@
 
 #d "😀" ; = 0xf09f9880
 #d utf8("😀") ; = 0xf09f9880
-#d ascii("😀") ; = 0x00
 #d utf16be("😀") ; = 0xd83d_de00
 #d utf16le("😀") ; = 0x3dd8_00de
 #d utf32be("😀") ; = 0x0001f600
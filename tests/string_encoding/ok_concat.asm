@@ -0,0 +1,14 @@
+#ruledef
+{
+    msg => 0x02 @ "AB" @ 0x00
+    msg16 => 0x02 @ utf16be("AB") @ 0x00
+    msg_ascii => 0x02 @ ascii("AB") @ 0x00
+    empty => 0x02 @ "" @ 0x00
+}
+
+msg ; = 0x02_4142_00
+msg16 ; = 0x02_00410042_00
+msg_ascii ; = 0x02_4142_00
+empty ; = 0x02_00
+#d 0x02 @ "AB" @ 0x00 ; = 0x02_4142_00
+#d "AB" @ 0x02 @ "C" ; = 0x4142_02_43
@@ -0,0 +1,2 @@
+#d utf8("é€😀") ; = 0xc3a9_e282ac_f09f9880
+#d8 strlen(utf8("é")) ; = 0x02
@@ -0,0 +1 @@
+#d "ab\x4g" ; error: invalid escape sequence
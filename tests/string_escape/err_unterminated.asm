@@ -0,0 +1 @@
+#d "ab\u{41" ; error: invalid escape sequence
@@ -0,0 +1,3 @@
+#d "a\nb\r\t\0\\\"\x41\u{42}" ; = 0x610a620d09005c224142
+#d "\"" ; = 0x22
+#d "\\" ; = 0x5c
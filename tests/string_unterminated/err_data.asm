@@ -0,0 +1 @@
+#d "abc ; error: unterminated string literal
@@ -0,0 +1 @@
+x = "ab\"c ; error: unterminated string literal
@@ -0,0 +1,7 @@
+#ruledef
+{
+    ld {x} => 0x11 @ x`8
+}
+
+ld 0x22
+ld "abc ; error: unterminated string literal
@@ -0,0 +1,8 @@
+#ruledef test
+{
+    nop => 0x00
+    jmp {addr: u8} => 0xee @ addr
+}
+
+jmp - ; error: failed / note:_:4: within / error: no anonymous label `-` before this point
++
@@ -0,0 +1,9 @@
+#ruledef test
+{
+    nop => 0x00
+    jmp {addr: u8} => 0xee @ addr
+}
+
+jmp +
++
+jmp + ; error: failed / note:_:4: within / error: no anonymous label `+` after this point
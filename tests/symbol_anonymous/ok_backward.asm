@@ -0,0 +1,12 @@
+#ruledef test
+{
+    nop => 0x00
+    jmp {addr: u8} => 0xee @ addr
+}
+
+-
+nop
+-
+jmp -
+jmp --
+nop ; = 0x00_ee01_ee00_00
@@ -0,0 +1,9 @@
+#ruledef test
+{
+    nop => 0x00
+    jmp {addr: u8} => 0xee @ addr
+}
+
+-
+#d8 +, -, (+) + 1
++ ; = 0x03_00_04
@@ -0,0 +1,13 @@
+#ruledef test
+{
+    nop => 0x00
+    jmp {addr: u8} => 0xee @ addr
+}
+
+jmp +
+jmp ++
+nop
++
+jmp +
++
+nop ; = 0xee05_ee07_00_ee07_00
@@ -0,0 +1,13 @@
+#ruledef test
+{
+    nop => 0x00
+    jmp {addr: u8} => 0xee @ addr
+}
+
+main:
+.loop:
+    jmp +
+-
+    jmp .loop
++
+    jmp - ; = 0xee04_ee00_ee02
@@ -0,0 +1,19 @@
+#ruledef test
+{
+    nop => 0x00
+    jmp {addr: u8} => 0xee @ addr
+}
+
+-
+    nop
+    jmp +
+    jmp ++
++
+    jmp -
++
+    nop
+-
+    jmp --
+    jmp -
+    jmp +
++ ; = 0x00_ee05_ee07_ee00_00_ee00_ee08_ee0e
@@ -0,0 +1,10 @@
+#ruledef test
+{
+    nop => 0x00
+    jmp {addr: u8} => 0xee @ addr
+}
+
+x = 5
+nop
+jmp x - 1
+#d8 -x, 1 - -x, (x) - 1 ; = 0x00_ee04_fb_06_04
@@ -0,0 +1,13 @@
+#ruledef test
+{
+    nop => 0x00
+    jmp {addr: u8} => 0xee @ addr
+}
+
+#repeat 3
+{
+    -
+    jmp -
+}
+jmp +
++ ; = 0xee00_ee02_ee04_ee08
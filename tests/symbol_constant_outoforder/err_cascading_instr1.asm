@@ -13,7 +13,7 @@
     }
 }
 
-x = y ; error: converge
+x = y ; error: converge / error: resolution did not converge / note: `x` changed from 0x7 to 0x9 / note:_:19: `label` changed from 0x6 to 0x4 / note:_:20: `y` changed from 0x9 to 0x7
     ld x ; error: converge
     ld x ; error: converge
 label: ; error: converge
@@ -15,6 +15,6 @@
 
     ld x
     ld x
-x = y ; error: converge
+x = y ; error: converge / error: resolution did not converge / note: `x` changed from 0x7 to 0x9
 label:
 y = label + 3
\ No newline at end of file
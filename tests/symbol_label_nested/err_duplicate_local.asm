@@ -0,0 +1,11 @@
+#ruledef test
+{
+    ld {x} => 0x55 @ x`8
+}
+
+
+global1:
+.loop:
+    ld .loop
+.loop: ; error: duplicate symbol `loop` / note:_:8: first declared here
+    ld .loop
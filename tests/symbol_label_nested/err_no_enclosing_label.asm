@@ -0,0 +1,9 @@
+#ruledef test
+{
+    ld {x} => 0x55 @ x`8
+}
+
+
+    ld .loop ; error: failed / note:_:3: within / error: no enclosing label for `.loop`
+global1:
+.loop:
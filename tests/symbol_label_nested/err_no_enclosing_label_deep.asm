@@ -0,0 +1,8 @@
+#ruledef test
+{
+    ld {x} => 0x55 @ x`8
+}
+
+
+global1:
+    ld ..inner ; error: failed / note:_:3: within / error: no enclosing label for `..inner`
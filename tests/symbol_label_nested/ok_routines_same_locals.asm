@@ -0,0 +1,25 @@
+#ruledef test
+{
+    jmp {x: u8} => 0xee @ x
+    jz {x: u8} => 0xcc @ x
+    nop => 0x00
+}
+
+
+first:
+.loop:
+    nop
+    jz .done
+    jmp .loop
+.done:
+    nop
+
+second:
+.loop:
+    jz .done
+    nop
+    jmp .loop
+.done:
+    jmp first.loop
+
+; = 0x00_cc05_ee00_00_cc0b_00_ee06_ee00
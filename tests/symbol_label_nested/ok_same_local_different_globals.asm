@@ -0,0 +1,13 @@
+#ruledef test
+{
+    ld {x} => 0x55 @ x`8
+}
+
+
+global1:
+.loop:
+    ld .loop ; = 0x5500
+global2:
+.loop:
+    ld .loop ; = 0x5502
+    ld global1.loop ; = 0x5500
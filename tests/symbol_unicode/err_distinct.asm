@@ -0,0 +1,2 @@
+café = 1
+#d8 cafe ; error: failed / error: unknown symbol `cafe`
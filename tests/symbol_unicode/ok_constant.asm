@@ -0,0 +1,2 @@
+größe = 0x12
+#d8 größe ; = 0x12
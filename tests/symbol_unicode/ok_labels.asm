@@ -0,0 +1,11 @@
+#ruledef
+{
+    jmp {addr: u8} => 0xee @ addr
+}
+
+café:
+    jmp метка
+метка:
+    jmp café
+.локальная:
+    jmp метка.локальная ; = 0xee02_ee00_ee04
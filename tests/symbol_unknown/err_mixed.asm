@@ -0,0 +1,4 @@
+x = foo + 1 ; error: unknown symbol `foo`
+#d8 x
+#d8 bar ; error: failed / error: unknown symbol `bar`
+#addr baz ; error: unknown symbol `baz`
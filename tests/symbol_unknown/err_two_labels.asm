@@ -0,0 +1,9 @@
+#ruledef
+{
+    jmp {addr: u8} => 0xee @ addr
+}
+
+jmp start
+jmp loop ; error: failed / note:_:3: within / error: unknown symbol `loop`
+jmp done ; error: failed / note:_:3: within / error: unknown symbol `done`
+start:
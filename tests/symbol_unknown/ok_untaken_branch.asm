@@ -0,0 +1,2 @@
+#d8 true ? 0x12 : undefined ; = 0x12
+#d8 (false && undefined) ? 0x34 : 0x56 ; = 0x56
@@ -0,0 +1,7 @@
+#ruledef arm
+{
+    nop => 0x11
+}
+
+#using arm ; error: expected `{`
+nop
@@ -0,0 +1,15 @@
+#ruledef arm
+{
+    nop => 0x11
+    mov => 0x22
+}
+
+#ruledef thumb
+{
+    nop => 0x33
+}
+
+#using thumb
+{
+    mov ; error: no match found for instruction
+}
@@ -0,0 +1,10 @@
+#ruledef arm
+{
+    nop => 0x11
+}
+
+#using thumb ; error: unknown ruledef `thumb`
+{
+    nop
+    nop
+}
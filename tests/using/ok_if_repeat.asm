@@ -0,0 +1,21 @@
+#ruledef arm
+{
+    nop => 0x11
+}
+
+#ruledef thumb
+{
+    nop => 0x33
+}
+
+#using thumb
+{
+    #if 1 == 1
+    {
+        nop ; = 0x33
+    }
+    #repeat 2
+    {
+        nop ; = 0x3333
+    }
+}
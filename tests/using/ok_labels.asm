@@ -0,0 +1,20 @@
+#ruledef arm
+{
+    jmp {addr: u8} => 0x11 @ addr
+}
+
+#ruledef thumb
+{
+    jmp {addr: u8} => 0x22 @ addr
+}
+
+#using thumb
+{
+    start:
+    jmp after ; = 0x2204
+}
+#using arm
+{
+    jmp start ; = 0x1100
+}
+after:
@@ -0,0 +1,21 @@
+#ruledef a
+{
+    x => 0x11
+}
+
+#ruledef b
+{
+    y => 0x22
+}
+
+#ruledef c
+{
+    x => 0x33
+    y => 0x44
+}
+
+#using a, b
+{
+    x ; = 0x11
+    y ; = 0x22
+}
@@ -0,0 +1,21 @@
+#ruledef arm
+{
+    nop => 0x11
+    mov => 0x22
+}
+
+#ruledef thumb
+{
+    nop => 0x33
+}
+
+#using arm
+{
+    nop ; = 0x11
+    #using thumb
+    {
+        nop ; = 0x33
+    }
+    nop ; = 0x11
+    mov ; = 0x22
+}
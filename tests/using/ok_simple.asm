@@ -0,0 +1,20 @@
+#ruledef arm
+{
+    nop => 0x11
+    mov => 0x22
+}
+
+#ruledef thumb
+{
+    nop => 0x33
+}
+
+mov ; = 0x22
+#using thumb
+{
+    nop ; = 0x33
+}
+#using arm
+{
+    nop ; = 0x11
+}